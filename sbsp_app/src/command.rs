@@ -7,6 +7,8 @@ use sbsp_backend::{
     FullShowState,
     event::BackendEvent,
     helper::{SupportedHardware, get_supported_hardware},
+    journal::{JournalEntry, JournalQuery},
+    manager::{CueSheetFormat, InsertPosition, bundle::BUNDLE_EXTENSION},
 };
 use tauri::{
     Manager as _, WebviewWindow,
@@ -52,6 +54,19 @@ pub async fn get_full_state(state: tauri::State<'_, AppState>) -> Result<FullSho
     handle.get_full_state().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn query_journal(
+    state: tauri::State<'_, AppState>,
+    query: JournalQuery,
+) -> Result<Vec<JournalEntry>, String> {
+    let handle = state.get_handle();
+    handle
+        .journal_handle
+        .query(query)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn get_third_party_notices(app_handle: tauri::AppHandle) -> Result<String, String> {
     let resource_path = app_handle
@@ -73,6 +88,13 @@ pub async fn process_asset(state: tauri::State<'_, AppState>, path: PathBuf) ->
     Ok(())
 }
 
+#[tauri::command]
+pub async fn run_preflight(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle.asset_processor_handle.request_preflight().await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn file_open(app_handle: tauri::AppHandle, window: WebviewWindow) -> Result<(), String> {
     let model_handle = app_handle.state::<AppState>().get_handle().model_handle;
@@ -94,12 +116,118 @@ pub async fn file_open(app_handle: tauri::AppHandle, window: WebviewWindow) -> R
     Ok(())
 }
 
+#[tauri::command]
+pub async fn file_import(
+    app_handle: tauri::AppHandle,
+    window: WebviewWindow,
+) -> Result<(), String> {
+    let model_handle = app_handle.state::<AppState>().get_handle().model_handle;
+    let (result_tx, result_rx) = oneshot::channel();
+    app_handle
+        .dialog()
+        .file()
+        .set_parent(&window)
+        .add_filter("Cue Sheet", &["csv", "tsv", "txt", "json"])
+        .pick_file(|file_path_option| {
+            result_tx.send(file_path_option).unwrap();
+        });
+    if let Ok(Some(file_path)) = result_rx.await {
+        model_handle
+            .import_cue_sheet(
+                file_path.into_path().map_err(|e| e.to_string())?,
+                InsertPosition::LAST,
+            )
+            .await
+            .map_err(|e| e.to_string())?
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn open_bundle(
+    app_handle: tauri::AppHandle,
+    window: WebviewWindow,
+) -> Result<(), String> {
+    let model_handle = app_handle.state::<AppState>().get_handle().model_handle;
+    let (result_tx, result_rx) = oneshot::channel();
+    app_handle
+        .dialog()
+        .file()
+        .set_parent(&window)
+        .add_filter("Show Bundle", &[BUNDLE_EXTENSION])
+        .pick_file(|file_path_option| {
+            result_tx.send(file_path_option).unwrap();
+        });
+    if let Ok(Some(file_path)) = result_rx.await {
+        model_handle
+            .open_bundle(file_path.into_path().map_err(|e| e.to_string())?, None)
+            .await
+            .map_err(|e| e.to_string())?
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn relink_assets(
+    app_handle: tauri::AppHandle,
+    window: WebviewWindow,
+) -> Result<(), String> {
+    let model_handle = app_handle.state::<AppState>().get_handle().model_handle;
+    let mut file_dialog_builder = app_handle.dialog().file().set_parent(&window);
+    if let Some(current_path) = model_handle.get_current_file_path().await
+        && let Some(parent) = current_path.parent()
+    {
+        file_dialog_builder = file_dialog_builder.set_directory(parent);
+    }
+    let (result_tx, result_rx) = oneshot::channel();
+    file_dialog_builder.pick_folder(move |file_path_option| {
+        result_tx.send(file_path_option).unwrap();
+    });
+    if let Ok(Some(folder)) = result_rx.await {
+        model_handle
+            .relink_assets(folder.into_path().map_err(|e| e.to_string())?)
+            .await
+            .map_err(|e| e.to_string())?
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn file_new(handle: tauri::AppHandle) -> Result<(), String> {
     let handle = handle.state::<AppState>().get_handle();
     handle.model_handle.reset().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn check_recovery(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .check_recovery()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn recover(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .recover()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn discard_recovery(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .discard_recovery()
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn file_save(
     app_handle: tauri::AppHandle,
@@ -234,6 +362,134 @@ pub async fn export_to_folder(
     }
 }
 
+#[tauri::command]
+pub async fn export_bundle(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    window: WebviewWindow,
+) -> Result<bool, String> {
+    let handle = state.get_handle();
+    let mut file_dialog_builder = app_handle
+        .dialog()
+        .file()
+        .set_parent(&window)
+        .add_filter("Show Bundle", &[BUNDLE_EXTENSION]);
+    if let Some(current_path) = handle.model_handle.get_current_file_path().await.as_ref() {
+        file_dialog_builder = file_dialog_builder
+            .set_directory(current_path.parent().unwrap())
+            .set_file_name(
+                current_path
+                    .with_extension(BUNDLE_EXTENSION)
+                    .file_name()
+                    .unwrap()
+                    .to_str()
+                    .unwrap(),
+            );
+    }
+    let (result_tx, result_rx) = oneshot::channel();
+    file_dialog_builder.save_file(move |file_path_option| {
+        result_tx.send(file_path_option).unwrap();
+    });
+    if let Ok(Some(file_path)) = result_rx.await {
+        let file_pathbuf = file_path.into_path().map_err(|e| e.to_string())?;
+        handle
+            .model_handle
+            .export_bundle(file_pathbuf)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+#[tauri::command]
+pub async fn export_cue_sheet(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    window: WebviewWindow,
+) -> Result<bool, String> {
+    let handle = state.get_handle();
+    let mut file_dialog_builder = app_handle
+        .dialog()
+        .file()
+        .set_parent(&window)
+        .add_filter("HTML", &["html"])
+        .add_filter("Markdown", &["md"])
+        .add_filter("CSV", &["csv"]);
+    if let Some(current_path) = handle.model_handle.get_current_file_path().await.as_ref() {
+        file_dialog_builder = file_dialog_builder
+            .set_directory(current_path.parent().unwrap())
+            .set_file_name(
+                current_path
+                    .with_extension("html")
+                    .file_name()
+                    .unwrap()
+                    .to_str()
+                    .unwrap(),
+            );
+    }
+    let (result_tx, result_rx) = oneshot::channel();
+    file_dialog_builder.save_file(move |file_path_option| {
+        result_tx.send(file_path_option).unwrap();
+    });
+    if let Ok(Some(file_path)) = result_rx.await {
+        let file_pathbuf = file_path.into_path().map_err(|e| e.to_string())?;
+        let format = CueSheetFormat::from_extension(&file_pathbuf)
+            .ok_or_else(|| "Unknown cue sheet format.".to_string())?;
+        handle
+            .model_handle
+            .export_cue_sheet(file_pathbuf, format)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+#[tauri::command]
+pub async fn export_journal(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    window: WebviewWindow,
+) -> Result<bool, String> {
+    let handle = state.get_handle();
+    let mut file_dialog_builder = app_handle
+        .dialog()
+        .file()
+        .set_parent(&window)
+        .add_filter("CSV", &["csv"])
+        .add_filter("JSON Lines", &["jsonl"]);
+    if let Some(current_path) = handle.model_handle.get_current_file_path().await.as_ref() {
+        file_dialog_builder = file_dialog_builder
+            .set_directory(current_path.parent().unwrap())
+            .set_file_name(
+                current_path
+                    .with_extension("journal.csv")
+                    .file_name()
+                    .unwrap()
+                    .to_str()
+                    .unwrap(),
+            );
+    }
+    let (result_tx, result_rx) = oneshot::channel();
+    file_dialog_builder.save_file(move |file_path_option| {
+        result_tx.send(file_path_option).unwrap();
+    });
+    if let Ok(Some(file_path)) = result_rx.await {
+        let file_pathbuf = file_path.into_path().map_err(|e| e.to_string())?;
+        handle
+            .journal_handle
+            .export(&file_pathbuf)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
 #[tauri::command]
 pub fn listen_level_meter(state: tauri::State<'_, AppState>, level_listener: Channel<Response>) {
     state.level_meter_tx.send_modify(|channel| {
@@ -3,7 +3,10 @@
 
 use sbsp_backend::{
     action::{AudioAction, CueAction},
-    model::cue::{Uuid, audio::Decibels},
+    model::cue::{
+        Uuid,
+        audio::{Decibels, EnvelopeSegment, FadeParam},
+    },
 };
 
 use crate::AppState;
@@ -18,6 +21,65 @@ pub async fn go(state: tauri::State<'_, AppState>) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn go_list(state: tauri::State<'_, AppState>, list_id: Uuid) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .go_list(list_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn go_from(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .go_from(cue_id.into())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn cursor_next(
+    state: tauri::State<'_, AppState>,
+    list_id: Option<Uuid>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .cursor_next(list_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn cursor_previous(
+    state: tauri::State<'_, AppState>,
+    list_id: Option<Uuid>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .cursor_previous(list_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn cursor_to_first(
+    state: tauri::State<'_, AppState>,
+    list_id: Option<Uuid>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .cursor_to_first(list_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn load(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
     let handle = state.get_handle();
@@ -88,6 +150,16 @@ pub async fn stop_all(state: tauri::State<'_, AppState>) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn panic(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .panic()
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn seek_to(
     state: tauri::State<'_, AppState>,
@@ -129,6 +201,53 @@ pub async fn set_playback_cursor(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn set_list_cursor(
+    state: tauri::State<'_, AppState>,
+    list_id: Uuid,
+    cue_id: Option<Uuid>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .set_list_cursor(list_id, cue_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn toggle_armed(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .toggle_armed(cue_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn store_live_state(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .store_live_state(cue_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn fire_cart_slot(state: tauri::State<'_, AppState>, index: usize) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .fire_cart_slot(index)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn toggle_repeat(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
     let handle = state.get_handle();
@@ -152,3 +271,100 @@ pub async fn set_volume(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn set_pan(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+    pan: f32,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .perform_action(cue_id, CueAction::Audio(AudioAction::SetPan(pan)))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_rate(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+    rate: f64,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .perform_action(cue_id, CueAction::Audio(AudioAction::SetRate(rate)))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_start_end(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .perform_action(
+            cue_id,
+            CueAction::Audio(AudioAction::SetStartEnd {
+                start_time,
+                end_time,
+            }),
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_fade_out(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+    fade_param: Option<FadeParam>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .perform_action(
+            cue_id,
+            CueAction::Audio(AudioAction::SetFadeOut(fade_param)),
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn replace_envelope(
+    state: tauri::State<'_, AppState>,
+    cue_id: Uuid,
+    envelope: Vec<EnvelopeSegment>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .perform_action(
+            cue_id,
+            CueAction::Audio(AudioAction::ReplaceEnvelope(envelope)),
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_master_volume(
+    state: tauri::State<'_, AppState>,
+    volume: Decibels,
+    fade_param: Option<FadeParam>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .controller_handle
+        .set_master_volume(volume, fade_param)
+        .await
+        .map_err(|e| e.to_string())
+}
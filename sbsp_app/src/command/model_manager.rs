@@ -7,6 +7,7 @@ use sbsp_backend::{
     manager::InsertPosition,
     model::{
         ShowModel,
+        cart::CartSlot,
         cue::{Cue, Uuid},
         settings::ShowSettings,
     },
@@ -41,6 +42,7 @@ pub async fn add_cue(
     cue: Cue,
     target_id: Option<Uuid>,
     to_before: bool,
+    list_id: Option<Uuid>,
 ) -> Result<(), String> {
     let handle = state.get_handle();
     if let Some(target) = target_id {
@@ -60,7 +62,13 @@ pub async fn add_cue(
     } else {
         handle
             .model_handle
-            .add_cue(cue, InsertPosition::LAST)
+            .add_cue(
+                cue,
+                list_id.map_or(InsertPosition::LAST, |list_id| InsertPosition::List {
+                    list_id,
+                    index: None,
+                }),
+            )
             .await
             .map_err(|e| e.to_string())
     }
@@ -72,6 +80,7 @@ pub async fn add_cues(
     cues: Vec<Cue>,
     target_id: Option<Uuid>,
     to_before: bool,
+    list_id: Option<Uuid>,
 ) -> Result<(), String> {
     let handle = state.get_handle();
     if let Some(target) = target_id {
@@ -91,12 +100,69 @@ pub async fn add_cues(
     } else {
         handle
             .model_handle
-            .add_cues(cues, InsertPosition::LAST)
+            .add_cues(
+                cues,
+                list_id.map_or(InsertPosition::LAST, |list_id| InsertPosition::List {
+                    list_id,
+                    index: None,
+                }),
+            )
             .await
             .map_err(|e| e.to_string())
     }
 }
 
+#[tauri::command]
+pub async fn add_cue_list(state: tauri::State<'_, AppState>, name: String) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .add_cue_list(name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn rename_cue_list(
+    state: tauri::State<'_, AppState>,
+    list_id: Uuid,
+    name: String,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .rename_cue_list(list_id, name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_cue_list(
+    state: tauri::State<'_, AppState>,
+    list_id: Uuid,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .remove_cue_list(list_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_cart_slot(
+    state: tauri::State<'_, AppState>,
+    index: usize,
+    slot: Option<CartSlot>,
+) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle
+        .model_handle
+        .set_cart_slot(index, slot)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn remove_cue(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
     let handle = state.get_handle();
@@ -165,6 +231,18 @@ pub async fn renumber_cues(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn undo(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle.model_handle.undo().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn redo(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    let handle = state.get_handle();
+    handle.model_handle.redo().await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn update_model_name(
     state: tauri::State<'_, AppState>,
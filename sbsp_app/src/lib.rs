@@ -285,39 +285,72 @@ pub fn run() {
             command::unlisten_backend_event,
             command::request_state_sync,
             command::get_full_state,
+            command::query_journal,
             command::get_third_party_notices,
             command::process_asset,
+            command::run_preflight,
             command::file_new,
             command::file_open,
+            command::file_import,
+            command::open_bundle,
+            command::relink_assets,
+            command::check_recovery,
+            command::recover,
+            command::discard_recovery,
             command::file_save,
             command::file_save_as,
             command::export_to_folder,
+            command::export_bundle,
+            command::export_cue_sheet,
+            command::export_journal,
             command::listen_level_meter,
             command::unlisten_level_meter,
             command::get_hardware,
             command::controller::go,
+            command::controller::go_list,
+            command::controller::go_from,
+            command::controller::cursor_next,
+            command::controller::cursor_previous,
+            command::controller::cursor_to_first,
             command::controller::pause,
             command::controller::resume,
             command::controller::stop,
             command::controller::pause_all,
             command::controller::resume_all,
             command::controller::stop_all,
+            command::controller::panic,
             command::controller::load,
             command::controller::seek_to,
             command::controller::seek_by,
             command::controller::set_playback_cursor,
+            command::controller::set_list_cursor,
+            command::controller::toggle_armed,
+            command::controller::store_live_state,
+            command::controller::fire_cart_slot,
             command::controller::toggle_repeat,
             command::controller::set_volume,
+            command::controller::set_pan,
+            command::controller::set_rate,
+            command::controller::set_start_end,
+            command::controller::set_fade_out,
+            command::controller::replace_envelope,
+            command::controller::set_master_volume,
             command::model_manager::get_show_model,
             command::model_manager::is_modified,
             command::model_manager::update_cue,
             command::model_manager::add_cue,
             command::model_manager::add_cues,
+            command::model_manager::add_cue_list,
+            command::model_manager::rename_cue_list,
+            command::model_manager::remove_cue_list,
+            command::model_manager::set_cart_slot,
             command::model_manager::remove_cue,
             command::model_manager::remove_cues,
             command::model_manager::move_cue,
             command::model_manager::move_cues,
             command::model_manager::renumber_cues,
+            command::model_manager::undo,
+            command::model_manager::redo,
             command::model_manager::update_model_name,
             command::model_manager::update_show_settings,
             command::server::is_server_running,
@@ -37,6 +37,8 @@ impl From<&GlobalHostSettings> for BackendSettings {
         BackendSettings {
             advance_cursor_when_go: from.general.advance_cursor_when_go,
             copy_assets_when_add: from.general.copy_assets_when_add,
+            backup_count: from.general.backup_count,
+            autosave_interval: from.general.autosave_interval.into(),
             audio: BackendAudioSettings {
                 device_id: from.audio.device_id.clone(),
                 channel_count: from.audio.channel_count,
@@ -51,6 +53,8 @@ impl PartialEq<BackendSettings> for GlobalHostSettings {
     fn eq(&self, other: &BackendSettings) -> bool {
         if self.general.advance_cursor_when_go == other.advance_cursor_when_go
             && self.general.copy_assets_when_add == other.copy_assets_when_add
+            && self.general.backup_count == other.backup_count
+            && u64::from(self.general.autosave_interval) == other.autosave_interval
         {
             return true;
         }
@@ -65,6 +69,8 @@ pub struct GeneralSettings {
     pub lock_cursor_to_selection: bool,
     pub copy_assets_when_add: bool,
     pub seek_amount: f64,
+    pub backup_count: usize,
+    pub autosave_interval: u32,
 }
 
 impl Default for GeneralSettings {
@@ -74,6 +80,8 @@ impl Default for GeneralSettings {
             lock_cursor_to_selection: true,
             copy_assets_when_add: false,
             seek_amount: 5.0,
+            backup_count: 5,
+            autosave_interval: 60,
         }
     }
 }
@@ -126,7 +134,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Audio(AudioCueParam {
@@ -140,6 +150,7 @@ impl Default for TemplateSettings {
                     repeat: false,
                     sound_type: SoundType::Streaming,
                     envelope: Vec::new(),
+                    original_path: None,
                 }),
             },
             wait: Cue {
@@ -148,7 +159,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -159,7 +172,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Fade(FadeCueParam {
@@ -177,7 +192,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Start(StartCueParam {
@@ -190,7 +207,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Stop(StopCueParam {
@@ -204,7 +223,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Pause(PauseCueParam {
@@ -217,7 +238,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Load(LoadCueParam {
@@ -230,12 +253,15 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Group {
                     base: GroupCueParamBase {
                         mode: GroupMode::Playlist { repeat: true },
+                        volume: Decibels::IDENTITY,
                     },
                     children: Vec::new(),
                 },
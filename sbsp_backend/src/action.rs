@@ -3,9 +3,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::model::cue::audio::Decibels;
+use crate::model::cue::audio::{Decibels, EnvelopeSegment, FadeParam};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
     tag = "type",
@@ -16,7 +16,7 @@ pub enum CueAction {
     Audio(AudioAction),
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
     tag = "action",
@@ -27,4 +27,15 @@ pub enum CueAction {
 pub enum AudioAction {
     ToggleRepeat,
     SetVolume(Decibels),
+    /// -1.0 is hard left, 1.0 hard right.
+    SetPan(f32),
+    /// Playback speed where 1.0 is normal. Pitch follows the speed.
+    SetRate(f64),
+    SetStartEnd {
+        start_time: Option<f64>,
+        end_time: Option<f64>,
+    },
+    /// Fade used when the cue is stopped softly. `None` cuts it with a short declick.
+    SetFadeOut(Option<FadeParam>),
+    ReplaceEnvelope(Vec<EnvelopeSegment>),
 }
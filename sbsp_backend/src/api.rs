@@ -2,8 +2,12 @@
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
 use crate::{
-    FullShowState, asset_processor::AssetProcessorCommand, controller::ControllerCommand,
-    event::BackendEvent, manager::ModelCommand,
+    FullShowState,
+    asset_processor::AssetProcessorCommand,
+    controller::ControllerCommand,
+    event::BackendEvent,
+    journal::{JournalEntry, JournalQuery},
+    manager::ModelCommand,
 };
 
 #[cfg(any(feature = "apiserver", feature = "apiclient"))]
@@ -74,6 +78,7 @@ pub enum WsFeedback {
     Event(Box<BackendEvent>),
     AssetList(Vec<FileList>),
     FullShowState(Box<FullShowState>),
+    Journal(Vec<JournalEntry>),
     Error(WsError),
 }
 
@@ -88,6 +93,7 @@ pub enum WsCommand {
     RequestAssetList,
     RequestFullShowState,
     RequestSyncState,
+    RequestJournal(JournalQuery),
 }
 
 #[derive(Serialize, Deserialize)]
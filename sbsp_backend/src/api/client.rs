@@ -19,9 +19,10 @@ use crate::{
         auth::{generate_authentication_string, generate_secret},
     },
     asset_processor::{AssetProcessorCommand, AssetProcessorHandle},
-    controller::{ControllerCommand, CueControllerHandle},
+    controller::{CommandRecorder, ControllerCommand, CueControllerHandle},
     event::{BackendError, BackendEvent},
-    manager::{ModelCommand, ShowModelHandle, project::ProjectStatus},
+    journal::{JournalCommand, JournalEntry, ShowJournalHandle},
+    manager::{ModelCommand, ShowModelHandle, history::HistoryStatus, project::ProjectStatus},
     model::ShowModel,
 };
 pub use file_list_handler::FileListHandle;
@@ -41,10 +42,12 @@ pub async fn create_remote_backend(
 ) -> anyhow::Result<ConnectionHandles> {
     let model = Arc::new(RwLock::new(ShowModel::default()));
     let project_status = Arc::new(RwLock::new(ProjectStatus::Unsaved));
+    let history_status = Arc::new(RwLock::new(HistoryStatus::default()));
     let (event_tx, _) = broadcast::channel::<BackendEvent>(32);
     let (model_tx, mut model_rx) = mpsc::channel::<ModelCommand>(32);
     let (controller_tx, mut controller_rx) = mpsc::channel::<ControllerCommand>(32);
     let (asset_tx, mut asset_rx) = mpsc::channel::<AssetProcessorCommand>(32);
+    let (journal_tx, mut journal_rx) = mpsc::channel::<JournalCommand>(8);
 
     let (asset_list_tx, asset_list_rx) = watch::channel(Vec::new());
     let (asset_list_command_tx, mut asset_list_command_rx) = mpsc::channel(8);
@@ -54,11 +57,13 @@ pub async fn create_remote_backend(
     let (request_full_state_tx, mut request_full_state_rx) = mpsc::channel(8);
 
     let mut full_state_responder: Option<oneshot::Sender<FullShowState>> = None;
+    let mut journal_responder: Option<oneshot::Sender<Vec<JournalEntry>>> = None;
 
     let (shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
 
     let model_clone = model.clone();
     let project_status_clone = project_status.clone();
+    let history_status_clone = history_status.clone();
     let event_tx_clone = event_tx.clone();
 
     let (mut websocket, _) = connect_async(format!("ws://{}/ws", address)).await?;
@@ -147,6 +152,9 @@ pub async fn create_remote_backend(
                                                 let mut project_status = project_status_clone.write().await;
                                                 *project_status = ProjectStatus::Unsaved;
                                             }
+                                        } else if let BackendEvent::HistoryUpdated { status } = &*ui_event {
+                                            let mut history_status = history_status_clone.write().await;
+                                            *history_status = *status;
                                         }
                                         if event_tx_clone.send(*ui_event).is_err() {
                                             log::error!("Failed to send BackendEvent to channel.");
@@ -172,6 +180,16 @@ pub async fn create_remote_backend(
                                             let mut project_status = project_status_clone.write().await;
                                             *project_status = full_state.project_status;
                                         }
+                                        {
+                                            let mut history_status = history_status_clone.write().await;
+                                            *history_status = full_state.history_status;
+                                        }
+                                    }
+                                    WsFeedback::Journal(entries) => {
+                                        if let Some(responder) = journal_responder.take()
+                                        && responder.send(entries).is_err() {
+                                            log::error!("Error while responding journal request.");
+                                        }
                                     }
                                     WsFeedback::Error(error) => {
                                         match error {
@@ -260,6 +278,13 @@ pub async fn create_remote_backend(
                         break;
                     }
                 }
+                Some(JournalCommand::Query { query, responder }) = journal_rx.recv() => {
+                    journal_responder = Some(responder);
+                    if let Ok(payload) = serde_json::to_string(&WsCommand::RequestJournal(query)) && websocket.send(Message::Text(payload.into())).await.is_err() {
+                        log::info!("WebSocket client disconnected (send error).");
+                        break;
+                    }
+                }
                 _ = shutdown_rx.recv() => {
                     if let Err(e) = websocket.send(Message::Close(None)).await {
                         log::warn!("Failed to send Close message to client: {}", e);
@@ -277,12 +302,17 @@ pub async fn create_remote_backend(
                 model_tx,
                 project_status,
                 Arc::new(AtomicBool::new(false)), // Behave as saved in client
+                history_status,
             ),
             asset_processor_handle: AssetProcessorHandle {
                 command_tx: asset_tx,
             },
             controller_handle: CueControllerHandle {
                 command_tx: controller_tx,
+                recorder: CommandRecorder::default(),
+            },
+            journal_handle: ShowJournalHandle {
+                command_tx: journal_tx,
             },
             level_meter: None,
             request_state_sync_tx,
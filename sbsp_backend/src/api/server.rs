@@ -32,7 +32,7 @@ use crate::{
         auth::{check_authentication_string, generate_salt, generate_secret},
     },
     asset_processor::AssetProcessorCommand,
-    controller::state::ShowState,
+    controller::{ControllerCommand, state::ShowState},
     event::{BackendEvent, CueState, SyncData},
     manager::project::{ProjectStatus, ProjectType},
 };
@@ -270,7 +270,12 @@ async fn handle_socket(mut socket: WebSocket, state: ApiState) {
                         if let Ok(command_request) = serde_json::from_str::<WsCommand>(&text) {
                             match command_request {
                                 WsCommand::Control(controller_command) => {
-                                    if permission.contains(Permissions::CONTROL) {
+                                    // storing the live state writes it back into the show
+                                    let required = match controller_command {
+                                        ControllerCommand::StoreLiveState(_) => Permissions::CONTROL | Permissions::EDIT,
+                                        _ => Permissions::CONTROL,
+                                    };
+                                    if permission.contains(required) {
                                         if state.backend_handle.controller_handle.send_command(controller_command).await.is_err() {
                                             log::error!("Failed to send Go command to CueController.");
                                             break;
@@ -286,11 +291,19 @@ async fn handle_socket(mut socket: WebSocket, state: ApiState) {
                                 },
                                 WsCommand::Model(model_command) => {
                                     match model_command.as_ref() {
+                                        ModelCommand::CheckRecovery |
+                                        ModelCommand::Recover |
+                                        ModelCommand::DiscardRecovery |
                                         ModelCommand::Reset |
                                         ModelCommand::Save |
                                         ModelCommand::SaveToFile(_)|
                                         ModelCommand::ExportToFolder(_) |
-                                        ModelCommand::LoadFromFile(_) => {
+                                        ModelCommand::ExportCueSheet { .. } |
+                                        ModelCommand::LoadFromFile(_) |
+                                        ModelCommand::ExportBundle(_) |
+                                        ModelCommand::OpenBundle { .. } |
+                                        ModelCommand::ImportCueSheet { .. } |
+                                        ModelCommand::RelinkAssets { .. } => {
                                             log::warn!("File related operation not permitted.");
                                         },
                                         _ => {
@@ -324,6 +337,18 @@ async fn handle_socket(mut socket: WebSocket, state: ApiState) {
                                                 log::warn!("Permission denied.");
                                             }
                                         },
+                                        AssetProcessorCommand::RequestPreflight => {
+                                            if permission.contains(Permissions::READ) {
+                                                state.backend_handle.asset_processor_handle.request_preflight().await;
+                                            } else {
+                                                if let Ok(payload) = serde_json::to_string(&WsFeedback::Error(WsError::PermissionDenied))
+                                                && let Err(e) = socket.send(Message::Text(payload.into())).await {
+                                                    log::error!("Error on responding error. e={}", e);
+                                                    return;
+                                                }
+                                                log::warn!("Permission denied.");
+                                            }
+                                        },
                                     }
                                 },
                                 WsCommand::RequestAssetList => {
@@ -351,11 +376,13 @@ async fn handle_socket(mut socket: WebSocket, state: ApiState) {
                                 WsCommand::RequestFullShowState => {
                                     if permission.contains(Permissions::READ) {
                                         let project_status = state.backend_handle.model_handle.get_project_state().await.clone();
+                                        let history_status = state.backend_handle.model_handle.get_history_status().await;
                                         let show_model = state.backend_handle.model_handle.read().await.clone();
                                         let show_state = state.state_rx.borrow().clone();
 
                                         let full_state = FullShowState {
                                             project_status,
+                                            history_status,
                                             show_model,
                                             show_state,
                                         };
@@ -398,6 +425,27 @@ async fn handle_socket(mut socket: WebSocket, state: ApiState) {
                                         log::warn!("Permission denied.");
                                     }
                                 },
+                                WsCommand::RequestJournal(query) => {
+                                    if permission.contains(Permissions::READ) {
+                                        match state.backend_handle.journal_handle.query(query).await {
+                                            Ok(entries) => {
+                                                let ws_message = WsFeedback::Journal(entries);
+                                                if let Ok(payload) = serde_json::to_string(&ws_message) && socket.send(Message::Text(payload.into())).await.is_err() {
+                                                    log::info!("WebSocket client disconnected (send error).");
+                                                    break;
+                                                }
+                                            }
+                                            Err(e) => log::error!("Failed to query show journal. e={}", e),
+                                        }
+                                    } else {
+                                        if let Ok(payload) = serde_json::to_string(&WsFeedback::Error(WsError::PermissionDenied))
+                                        && let Err(e) = socket.send(Message::Text(payload.into())).await {
+                                            log::error!("Error on responding error. e={}", e);
+                                            return;
+                                        }
+                                        log::warn!("Permission denied.");
+                                    }
+                                },
                                 WsCommand::Authenticate { response } => {
                                     let mut reauth = false;
                                     for PermissionInfo { password, permission: perm } in &state.options.auth_map {
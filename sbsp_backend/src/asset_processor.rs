@@ -10,15 +10,19 @@ pub use data::{AssetData, AssetMetadata};
 pub use handle::AssetProcessorHandle;
 use symphonia::core::codecs::CODEC_TYPE_NULL;
 
-use std::path::PathBuf;
-use std::{collections::HashMap, sync::Arc, time::SystemTime};
+use std::path::{Path, PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::SystemTime,
+};
 
 use ebur128::EbuR128;
 use serde::{Deserialize, Serialize};
 use symphonia::core::{
     audio::SampleBuffer,
-    codecs::{DecoderOptions, FinalizeResult},
-    formats::FormatOptions,
+    codecs::{CodecParameters, DecoderOptions, FinalizeResult},
+    formats::{FormatOptions, FormatReader},
     io::MediaSourceStream,
     meta::MetadataOptions,
     probe::Hint,
@@ -27,6 +31,9 @@ use tokio::sync::{RwLock, Semaphore, broadcast, mpsc};
 
 use crate::event::BackendEvent;
 use crate::manager::ShowModelHandle;
+use crate::manager::asset_import::find_missing_assets;
+use crate::model::cue::CueParam;
+use crate::preflight;
 
 const WAVEFORM_THRESHOLD: usize = 2000;
 const AUDIO_THRESHOLD: f32 = 0.001_f32;
@@ -49,6 +56,19 @@ struct AssetCache {
     entries: HashMap<PathBuf, CacheEntry>,
 }
 
+impl CacheEntry {
+    async fn new(data: AssetData) -> Self {
+        let last_modified = match tokio::fs::metadata(&data.metadata.path).await {
+            Ok(metadata) => metadata.modified().unwrap_or_else(|_| SystemTime::now()),
+            Err(_) => SystemTime::now(),
+        };
+        Self {
+            last_modified,
+            data,
+        }
+    }
+}
+
 impl AssetCache {
     fn new() -> Self {
         Self {
@@ -105,18 +125,18 @@ impl AssetProcessor {
                             log::info!("Asset Process requested. file={:?}", path);
                             self.handle_process_file(path).await;
                         }
+                        AssetProcessorCommand::RequestPreflight => {
+                            log::info!("Preflight requested.");
+                            self.handle_preflight();
+                        }
                     }
                 },
                 result = result_rx.recv() => {
                     match result {
                         Ok(result) => {
-                            let mut cache = self.cache.write().await;
                             if let Ok(data) = &result.data {
-                                if let Ok(metadata) = tokio::fs::metadata(data.metadata.path.clone()).await && let Ok(last_modified) = metadata.modified() {
-                                    cache.entries.insert(data.metadata.path.clone(), CacheEntry { last_modified, data: data.clone() });
-                                } else {
-                                    cache.entries.insert(data.metadata.path.clone(), CacheEntry { last_modified: SystemTime::now(), data: data.clone() });
-                                }
+                                let entry = CacheEntry::new(data.clone()).await;
+                                self.cache.write().await.entries.insert(data.metadata.path.clone(), entry);
                             }
                             self.processing.write().await.retain(|value| *value != result.actual_path);
                             if let Err(e) = self.event_tx.send(BackendEvent::AssetResult { path: result.path, data: result.data }) {
@@ -191,6 +211,83 @@ impl AssetProcessor {
         log::info!("Asset Process started. file={:?}", actual_path);
     }
 
+    /// Analyzes every audio file of the show, reusing cached results, and
+    /// reports the preflight warnings. Runs in the background so that asset
+    /// requests keep being served meanwhile.
+    fn handle_preflight(&self) {
+        let model_handle = self.model_handle.clone();
+        let cache = self.cache.clone();
+        let semaphore = self.semaphore.clone();
+        let event_tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let model = model_handle.read().await.clone();
+            let model_dir = model_handle
+                .get_current_file_path()
+                .await
+                .and_then(|path| path.parent().map(Path::to_path_buf));
+            let missing = find_missing_assets(&model, model_dir.as_deref());
+            let targets: HashSet<PathBuf> = model
+                .cue_list
+                .cues
+                .values()
+                .filter_map(|cue| match &cue.params {
+                    CueParam::Audio(param) => Some(param.target.clone()),
+                    _ => None,
+                })
+                .filter(|target| !missing.iter().any(|asset| asset.target == *target))
+                .collect();
+
+            let mut assets = HashMap::new();
+            for target in targets {
+                let Ok(actual_path) = model_handle.get_asset_standard_path(&target).await else {
+                    continue;
+                };
+                let cached = cache
+                    .read()
+                    .await
+                    .entries
+                    .get(&actual_path)
+                    .map(|entry| entry.data.clone());
+                let data = match cached {
+                    Some(data) => data,
+                    None => {
+                        let permit = semaphore.clone().acquire_owned().await.unwrap();
+                        let path = actual_path.clone();
+                        let orig_path = target.clone();
+                        let event_tx = event_tx.clone();
+                        let result = tokio::task::spawn_blocking(move || {
+                            let result = Self::process_asset(path, orig_path, event_tx);
+                            drop(permit);
+                            result
+                        })
+                        .await;
+                        match result {
+                            Ok(Ok(data)) => {
+                                let entry = CacheEntry::new(data.clone()).await;
+                                cache.write().await.entries.insert(actual_path, entry);
+                                data
+                            }
+                            Ok(Err(e)) => {
+                                log::warn!("Preflight could not analyze {:?}, {}", target, e);
+                                continue;
+                            }
+                            Err(e) => {
+                                log::error!("Preflight analysis task failed, {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+                assets.insert(target, data);
+            }
+
+            let warnings = preflight::analyze(&model, &missing, &assets);
+            if let Err(e) = event_tx.send(BackendEvent::PreflightReport { warnings }) {
+                log::warn!("Failed to send event, {}", e);
+            }
+        });
+    }
+
     async fn filter_current_assets(&self) {
         let active_paths = self.model_handle.get_all_asset_paths().await;
 
@@ -207,12 +304,15 @@ impl AssetProcessor {
         );
     }
 
-    fn process_asset(
-        path: PathBuf,
-        orig_path: PathBuf,
-        event_tx: broadcast::Sender<BackendEvent>,
-    ) -> anyhow::Result<AssetData> {
-        let src: std::fs::File = std::fs::File::open(&path)?;
+    /// Reads the container headers only, without decoding any audio.
+    pub(crate) fn read_metadata(path: &Path) -> anyhow::Result<AssetMetadata> {
+        Self::probe(path).map(|(_, _, _, metadata)| metadata)
+    }
+
+    fn probe(
+        path: &Path,
+    ) -> anyhow::Result<(Box<dyn FormatReader>, u32, CodecParameters, AssetMetadata)> {
+        let src: std::fs::File = std::fs::File::open(path)?;
         let mss = MediaSourceStream::new(Box::new(src), Default::default());
 
         let mut hint = Hint::new();
@@ -224,12 +324,11 @@ impl AssetProcessor {
 
         let format_opts: FormatOptions = Default::default();
         let metadata_opts: MetadataOptions = Default::default();
-        let decoder_opts: DecoderOptions = Default::default();
 
         let probed =
             symphonia::default::get_probe().format(&hint, mss, &format_opts, &metadata_opts)?;
 
-        let mut format = probed.format;
+        let format = probed.format;
 
         let track = format
             .tracks()
@@ -251,14 +350,26 @@ impl AssetProcessor {
         let sample_rate = codec_params
             .sample_rate
             .ok_or_else(|| anyhow::anyhow!("Sample rate not found."))?;
-        let mut channel_count = codec_params.channels.map(|channels| channels.count());
+        let channel_count = codec_params.channels.map(|channels| channels.count());
 
-        let mut metadata = AssetMetadata {
-            path: path.clone(),
+        let metadata = AssetMetadata {
+            path: path.to_path_buf(),
             duration,
             channel_count: channel_count.map(|c| c as u16),
             sample_rate,
         };
+        Ok((format, track_id, codec_params, metadata))
+    }
+
+    fn process_asset(
+        path: PathBuf,
+        orig_path: PathBuf,
+        event_tx: broadcast::Sender<BackendEvent>,
+    ) -> anyhow::Result<AssetData> {
+        let (mut format, track_id, codec_params, mut metadata) = Self::probe(&path)?;
+        let sample_rate = metadata.sample_rate;
+        let mut channel_count = codec_params.channels.map(|channels| channels.count());
+        let decoder_opts: DecoderOptions = Default::default();
 
         let _ = event_tx.send(BackendEvent::AssetMetadata {
             path: orig_path,
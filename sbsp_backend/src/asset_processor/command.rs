@@ -14,4 +14,5 @@ use serde::{Deserialize, Serialize};
 )]
 pub enum AssetProcessorCommand {
     RequestFileAssetData { path: PathBuf },
+    RequestPreflight,
 }
@@ -19,4 +19,11 @@ impl AssetProcessorHandle {
             .await
             .unwrap();
     }
+
+    pub async fn request_preflight(&self) {
+        self.command_tx
+            .send(AssetProcessorCommand::RequestPreflight)
+            .await
+            .unwrap();
+    }
 }
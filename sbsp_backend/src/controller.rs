@@ -3,22 +3,37 @@
 
 mod command;
 mod handle;
+mod recorder;
 pub mod state;
 
-pub use command::ControllerCommand;
+pub use command::{ControllerCommand, CueRef};
 pub use handle::CueControllerHandle;
+pub(crate) use recorder::CommandRecorder;
+pub use recorder::{CommandRecording, MIN_REPLAY_SPEED, RecordedCommand, ReplayHandle};
+
+use std::{collections::HashMap, time::Duration};
 
 use anyhow::Result;
-use tokio::sync::{broadcast, mpsc, watch};
+use tokio::{
+    sync::{broadcast, mpsc, watch},
+    time::Instant,
+};
 use uuid::Uuid;
 
 use crate::{
     BackendSettings,
     controller::state::{ActiveCue, PlaybackStatus, ShowState, StateParam},
-    event::BackendEvent,
+    event::{BackendEvent, GoSuppressedReason},
     executor::{ExecutorCommand, ExecutorEvent, StopMode},
     manager::ShowModelHandle,
-    model::cue::{CueParam, group::GroupMode},
+    model::{
+        cart::CartSlotMode,
+        cue::{
+            CueParam, MAIN_CUE_LIST_ID,
+            audio::{Easing, FadeParam},
+            group::GroupMode,
+        },
+    },
 };
 
 pub struct CueController {
@@ -33,8 +48,15 @@ pub struct CueController {
     event_rx: broadcast::Receiver<BackendEvent>,
 
     advance_cursor_when_go: bool,
+    /// Time of the last GO, per cue list.
+    last_go: HashMap<Uuid, Instant>,
+    /// Cue waiting for a second GO to restart it, with the time of the first press, per cue list.
+    pending_go_confirm: HashMap<Uuid, (Uuid, Instant)>,
 }
 
+/// How long a suppressed repeat GO waits for the confirming press.
+const GO_CONFIRM_WINDOW: Duration = Duration::from_secs(3);
+
 impl CueController {
     pub fn new(
         model_handle: ShowModelHandle,
@@ -58,8 +80,13 @@ impl CueController {
                 event_tx,
                 event_rx,
                 advance_cursor_when_go,
+                last_go: HashMap::new(),
+                pending_go_confirm: HashMap::new(),
+            },
+            CueControllerHandle {
+                command_tx,
+                recorder: CommandRecorder::default(),
             },
-            CueControllerHandle { command_tx },
         )
     }
 
@@ -87,15 +114,15 @@ impl CueController {
                                 BackendEvent::ShowModelLoaded{..} => {
                                     {
                                         let model = self.model_handle.read().await;
-                                        if let Some(first_id) = model.cue_list.root_ids.first() {
-                                            self.state_tx.send_modify(|state| {
-                                                state.playback_cursor = Some(*first_id);
-                                            });
-                                        } else {
-                                            self.state_tx.send_modify(|state| {
-                                                state.playback_cursor = None;
-                                            });
-                                        }
+                                        self.state_tx.send_modify(|state| {
+                                            state.playback_cursor = model.cue_list.root_ids.first().copied();
+                                            state.list_cursors = model
+                                                .cue_list
+                                                .lists
+                                                .iter()
+                                                .filter_map(|list| Some((list.id, *list.root_ids.first()?)))
+                                                .collect();
+                                        });
                                     }
                                     if let Err(e) = self.hard_stop_all().await {
                                         log::error!("Failed to stop active cues before reset. {}", e);
@@ -104,24 +131,33 @@ impl CueController {
                                 BackendEvent::ShowModelReset{..} => {
                                     self.state_tx.send_modify(|state| {
                                         state.playback_cursor = None;
+                                        state.list_cursors.clear();
                                     });
                                     if let Err(e) = self.hard_stop_all().await {
                                         log::error!("Failed to stop active cues before reset. {}", e);
                                     }
                                 },
-                                BackendEvent::CueRemoved{cue_ids} => {
+                                BackendEvent::CueRemoved{cue_ids, ..} => {
                                     let state = self.state_tx.borrow().clone();
                                     if let Some(cursor) = state.playback_cursor && cue_ids.contains(&cursor) {
                                         let model = self.model_handle.read().await;
-                                        if let Some(first_id) = model.cue_list.root_ids.first() {
-                                            self.state_tx.send_modify(|state| {
-                                                state.playback_cursor = Some(*first_id);
-                                            });
-                                        } else {
-                                            self.state_tx.send_modify(|state| {
-                                                state.playback_cursor = None;
-                                            });
-                                        }
+                                        self.state_tx.send_modify(|state| {
+                                            state.playback_cursor = model.cue_list.root_ids.first().copied();
+                                        });
+                                    }
+                                    if state.list_cursors.values().any(|cursor| cue_ids.contains(cursor)) {
+                                        let model = self.model_handle.read().await;
+                                        self.state_tx.send_modify(|state| {
+                                            for (list_id, cursor) in &state.list_cursors.clone() {
+                                                if !cue_ids.contains(cursor) {
+                                                    continue;
+                                                }
+                                                match model.cue_list.root_ids_of(list_id).and_then(|root_ids| root_ids.first()) {
+                                                    Some(first_id) => state.list_cursors.insert(*list_id, *first_id),
+                                                    None => state.list_cursors.remove(list_id),
+                                                };
+                                            }
+                                        });
                                     }
                                     for rm_id in cue_ids {
                                         if state.active_cues.contains_key(&rm_id)
@@ -130,6 +166,13 @@ impl CueController {
                                         }
                                     }
                                 }
+                                BackendEvent::CueListUpdated{ cue_list } => {
+                                    self.state_tx.send_if_modified(|state| {
+                                        let count = state.list_cursors.len();
+                                        state.list_cursors.retain(|list_id, _| cue_list.root_ids_of(list_id).is_some());
+                                        state.list_cursors.len() != count
+                                    });
+                                }
                                 BackendEvent::SettingsUpdated{ new_settings } => {
                                     if let Err(e) = self.executor_tx.send(ExecutorCommand::ReconfigureEngines(new_settings)).await {
                                         log::error!("{}", e);
@@ -150,23 +193,13 @@ impl CueController {
         log::info!("CueController run loop finished.");
     }
 
-    async fn handle_command(&self, command: ControllerCommand) -> Result<(), anyhow::Error> {
+    async fn handle_command(&mut self, command: ControllerCommand) -> Result<(), anyhow::Error> {
         let state = self.state_tx.borrow().clone();
         match command {
-            ControllerCommand::Go => {
-                let cue_id = if let Some(cursor) = state.playback_cursor {
-                    cursor
-                } else {
-                    anyhow::bail!("GO: playback_cursor is unavailable.");
-                };
-                self.handle_go(cue_id).await?;
-
-                if self.advance_cursor_when_go {
-                    self.update_playback_cursor().await?;
-                }
-                Ok(())
-            }
-            ControllerCommand::Load(cue_id) => {
+            ControllerCommand::Go => self.go_list(&state, MAIN_CUE_LIST_ID).await,
+            ControllerCommand::GoList { list_id } => self.go_list(&state, list_id).await,
+            ControllerCommand::Load(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if !state.active_cues.contains_key(&cue_id) {
                         self.executor_tx.send(ExecutorCommand::Load(cue_id)).await?;
@@ -178,7 +211,8 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::SeekTo(cue_id, position) => {
+            ControllerCommand::SeekTo(cue_ref, position) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if state.active_cues.contains_key(&cue_id) {
                         self.executor_tx
@@ -192,7 +226,8 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::SeekBy(cue_id, amount) => {
+            ControllerCommand::SeekBy(cue_ref, amount) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if state.active_cues.contains_key(&cue_id) {
                         self.executor_tx
@@ -206,11 +241,16 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::Pause(cue_id) => {
+            ControllerCommand::Pause(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if let Some(active_cue) = state.active_cues.get(&cue_id)
-                        && (active_cue.status == PlaybackStatus::PreWaiting
-                            || active_cue.status == PlaybackStatus::Playing)
+                        && matches!(
+                            active_cue.status,
+                            PlaybackStatus::PreWaiting
+                                | PlaybackStatus::Playing
+                                | PlaybackStatus::PostWaiting
+                        )
                     {
                         self.executor_tx
                             .send(ExecutorCommand::Pause(cue_id))
@@ -223,11 +263,17 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::Resume(cue_id) => {
+            ControllerCommand::Resume(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if let Some(active_cue) = state.active_cues.get(&cue_id)
-                        && (active_cue.status == PlaybackStatus::PreWaitPaused
-                            || active_cue.status == PlaybackStatus::Paused)
+                        // a paused post-wait keeps its status, the executor skips running ones
+                        && matches!(
+                            active_cue.status,
+                            PlaybackStatus::PreWaitPaused
+                                | PlaybackStatus::Paused
+                                | PlaybackStatus::PostWaiting
+                        )
                     {
                         self.executor_tx
                             .send(ExecutorCommand::Resume(cue_id))
@@ -240,7 +286,8 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::Stop(cue_id) => {
+            ControllerCommand::Stop(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if let Some(active_cue) = state.active_cues.get(&cue_id) {
                         let stop_mode = if active_cue.status == PlaybackStatus::Stopping {
@@ -257,7 +304,8 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::PerformAction(cue_id, cue_action) => {
+            ControllerCommand::PerformAction(cue_ref, cue_action) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
                 if self.model_handle.is_cue_exists(&cue_id).await {
                     if state.active_cues.contains_key(&cue_id) {
                         self.executor_tx
@@ -271,6 +319,39 @@ impl CueController {
                 }
                 Ok(())
             }
+            ControllerCommand::FireCartSlot(index) => {
+                let Some(slot) = self.model_handle.get_cart_slot(index).await else {
+                    anyhow::bail!("FireCartSlot: slot is empty. index={}", index);
+                };
+                let status = state
+                    .active_cues
+                    .get(&slot.cue_id)
+                    .map(|active_cue| active_cue.status)
+                    .filter(|status| *status != PlaybackStatus::Loaded);
+                match (slot.mode, status) {
+                    (_, None) => self.handle_go(slot.cue_id).await?,
+                    (CartSlotMode::Toggle, Some(status)) => {
+                        let stop_mode = if status == PlaybackStatus::Stopping {
+                            StopMode::Hard
+                        } else {
+                            StopMode::Soft
+                        };
+                        self.executor_tx
+                            .send(ExecutorCommand::Stop(slot.cue_id, stop_mode))
+                            .await?;
+                    }
+                    (CartSlotMode::Restart, Some(status)) => {
+                        self.restart_cue(slot.cue_id, status).await?
+                    }
+                    (CartSlotMode::Latch, Some(_)) => {
+                        log::info!(
+                            "FireCartSlot: latched cue is still running. index={}",
+                            index
+                        );
+                    }
+                }
+                Ok(())
+            }
             ControllerCommand::PauseAll
             | ControllerCommand::ResumeAll
             | ControllerCommand::StopAll => {
@@ -283,15 +364,15 @@ impl CueController {
                     if !is_group {
                         let executor_command = match command {
                             ControllerCommand::PauseAll => match active_cue.status {
-                                PlaybackStatus::PreWaiting | PlaybackStatus::Playing => {
-                                    ExecutorCommand::Pause(*cue_id)
-                                }
+                                PlaybackStatus::PreWaiting
+                                | PlaybackStatus::Playing
+                                | PlaybackStatus::PostWaiting => ExecutorCommand::Pause(*cue_id),
                                 _ => continue,
                             },
                             ControllerCommand::ResumeAll => match active_cue.status {
-                                PlaybackStatus::PreWaitPaused | PlaybackStatus::Paused => {
-                                    ExecutorCommand::Resume(*cue_id)
-                                }
+                                PlaybackStatus::PreWaitPaused
+                                | PlaybackStatus::Paused
+                                | PlaybackStatus::PostWaiting => ExecutorCommand::Resume(*cue_id),
                                 _ => continue,
                             },
                             ControllerCommand::StopAll => {
@@ -308,34 +389,273 @@ impl CueController {
                 }
                 Ok(())
             }
-            ControllerCommand::SetPlaybackCursor { cue_id } => {
-                if let Some(cursor_cue_id) = cue_id
-                    && self
+            ControllerCommand::Panic => {
+                let fade_param = FadeParam {
+                    duration: self
                         .model_handle
-                        .get_cue_by_id(&cursor_cue_id)
+                        .read()
                         .await
-                        .is_none()
-                {
+                        .settings
+                        .audio
+                        .panic_fade_duration,
+                    easing: Easing::Linear,
+                };
+                for (cue_id, active_cue) in &state.active_cues {
+                    let is_group = self
+                        .model_handle
+                        .get_cue_by_id(cue_id)
+                        .await
+                        .is_some_and(|cue| matches!(cue.params, CueParam::Group { .. }));
+                    // a running group stops with its children, but one still in its
+                    // pre-wait has none running yet and would fire afterwards
+                    if is_group
+                        && !matches!(
+                            active_cue.status,
+                            PlaybackStatus::PreWaiting | PlaybackStatus::PreWaitPaused
+                        )
+                    {
+                        continue;
+                    }
+                    // pre-waits and post-waits are cut by any stop, which also halts their chains
+                    let stop_mode = if active_cue.status == PlaybackStatus::Stopping {
+                        StopMode::Hard
+                    } else {
+                        StopMode::Fade(fade_param)
+                    };
+                    self.executor_tx
+                        .send(ExecutorCommand::Stop(*cue_id, stop_mode))
+                        .await?;
+                }
+                Ok(())
+            }
+            ControllerCommand::SetPlaybackCursor { cue_id: cue_ref } => {
+                self.move_list_cursor(MAIN_CUE_LIST_ID, cue_ref).await
+            }
+            ControllerCommand::SetListCursor {
+                list_id,
+                cue_id: cue_ref,
+            } => self.move_list_cursor(list_id, cue_ref).await,
+            ControllerCommand::SetArmed {
+                cue_id: cue_ref,
+                armed,
+            } => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
+                let Some(mut cue) = self.model_handle.get_cue_by_id(&cue_id).await else {
+                    anyhow::bail!("SetArmed: cue not found. cue_id={}", cue_id);
+                };
+                if cue.armed != armed {
+                    cue.armed = armed;
+                    self.model_handle.update_cue(cue).await?;
+                }
+                Ok(())
+            }
+            ControllerCommand::ToggleArmed(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
+                let Some(mut cue) = self.model_handle.get_cue_by_id(&cue_id).await else {
+                    anyhow::bail!("ToggleArmed: cue not found. cue_id={}", cue_id);
+                };
+                cue.armed = !cue.armed;
+                self.model_handle.update_cue(cue).await?;
+                Ok(())
+            }
+            ControllerCommand::StoreLiveState(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
+                let Some(StateParam::Audio(live)) = state
+                    .active_cues
+                    .get(&cue_id)
+                    .map(|active_cue| &active_cue.params)
+                else {
+                    anyhow::bail!("StoreLiveState: cue is not playing. cue_id={}", cue_id);
+                };
+                let Some(mut cue) = self.model_handle.get_cue_by_id(&cue_id).await else {
+                    anyhow::bail!("StoreLiveState: cue not found. cue_id={}", cue_id);
+                };
+                let CueParam::Audio(params) = &mut cue.params else {
+                    anyhow::bail!("StoreLiveState: not an audio cue. cue_id={}", cue_id);
+                };
+                params.volume = live.volume;
+                params.pan = live.pan;
+                params.repeat = live.repeating;
+                params.start_time = live.start_time;
+                params.end_time = live.end_time;
+                params.fade_out_param = live.fade_out_param;
+                params.envelope = live.envelope.clone();
+                self.model_handle.update_cue(cue).await?;
+                Ok(())
+            }
+            ControllerCommand::CursorNext { list_id } => {
+                let list_id = list_id.unwrap_or(MAIN_CUE_LIST_ID);
+                let Some(cursor) = state.cursor_of(&list_id) else {
                     anyhow::bail!(
-                        "Invalid playback cursor destination cue_id. cue_id = {}",
-                        cursor_cue_id
+                        "CursorNext: playback_cursor is unavailable. list_id={}",
+                        list_id
                     );
+                };
+                let next_cursor = self.next_cursor_of(cursor).await;
+                self.move_list_cursor(list_id, next_cursor.map(CueRef::from))
+                    .await
+            }
+            ControllerCommand::CursorPrevious { list_id } => {
+                let list_id = list_id.unwrap_or(MAIN_CUE_LIST_ID);
+                let mut previous_cursor = match state.cursor_of(&list_id) {
+                    Some(cursor) => self.model_handle.get_previous_cue_id_by_id(&cursor).await,
+                    None => self.model_handle.get_last_cue_id(&list_id).await,
+                };
+                while let Some(cue_id) = previous_cursor
+                    && !self
+                        .model_handle
+                        .get_cue_by_id(&cue_id)
+                        .await
+                        .is_some_and(|cue| cue.armed)
+                {
+                    previous_cursor = self.model_handle.get_previous_cue_id_by_id(&cue_id).await;
                 }
+                match previous_cursor {
+                    Some(cue_id) => self.move_list_cursor(list_id, Some(cue_id.into())).await,
+                    None => Ok(()),
+                }
+            }
+            ControllerCommand::CursorToFirst { list_id } => {
+                let list_id = list_id.unwrap_or(MAIN_CUE_LIST_ID);
+                let Some(first_id) = self
+                    .model_handle
+                    .read()
+                    .await
+                    .cue_list
+                    .root_ids_of(&list_id)
+                    .map(|root_ids| root_ids.first().copied())
+                else {
+                    anyhow::bail!("CursorToFirst: cue list not found. list_id={}", list_id);
+                };
+                let first_cursor = match first_id {
+                    Some(first_id) => self.model_handle.get_armed_cue_id_from(&first_id).await,
+                    None => None,
+                };
+                self.move_list_cursor(list_id, first_cursor.map(CueRef::from))
+                    .await
+            }
+            ControllerCommand::SetMasterVolume { volume, fade_param } => {
+                self.executor_tx
+                    .send(ExecutorCommand::SetMasterVolume(volume, fade_param))
+                    .await?;
                 self.state_tx.send_modify(|state| {
-                    if state.playback_cursor.ne(&cue_id) {
-                        state.playback_cursor = cue_id;
-                        if self
-                            .event_tx
-                            .send(BackendEvent::PlaybackCursorMoved { cue_id })
-                            .is_err()
-                        {
-                            log::trace!("No UI clients are listening to playback events.");
-                        }
-                    }
+                    state.master_volume = volume;
                 });
+                self.event_tx
+                    .send(BackendEvent::MasterVolumeChanged { volume })?;
                 Ok(())
             }
+            ControllerCommand::GoFrom(cue_ref) => {
+                let cue_id = self.model_handle.resolve_cue_ref(&cue_ref).await?;
+                let Some(list_id) = self.model_handle.get_cue_list_id(&cue_id).await else {
+                    anyhow::bail!("GoFrom: cue not found. cue_id={}", cue_id);
+                };
+                self.move_list_cursor(list_id, Some(cue_id.into())).await?;
+                let state = self.state_tx.borrow().clone();
+                self.go_list(&state, list_id).await
+            }
+        }
+    }
+
+    async fn go_list(&mut self, state: &ShowState, list_id: Uuid) -> Result<()> {
+        let Some(cursor) = state.cursor_of(&list_id) else {
+            anyhow::bail!("GO: playback_cursor is unavailable. list_id={}", list_id);
+        };
+        let Some(cue_id) = self.model_handle.get_armed_cue_id_from(&cursor).await else {
+            anyhow::bail!("GO: no armed cue found from playback_cursor.");
+        };
+
+        let (go_lockout, confirm_repeat_go) = {
+            let model = self.model_handle.read().await;
+            (
+                model.settings.general.go_lockout,
+                model.settings.general.confirm_repeat_go,
+            )
+        };
+        let now = Instant::now();
+        if let Some(last_go) = self.last_go.get(&list_id)
+            && now.duration_since(*last_go).as_secs_f64() < go_lockout
+        {
+            return self.suppress_go(list_id, cue_id, GoSuppressedReason::Lockout);
+        }
+        let status = state
+            .active_cues
+            .get(&cue_id)
+            .map(|active_cue| active_cue.status)
+            .filter(|status| *status != PlaybackStatus::Loaded);
+        // without confirmation a running cue is left alone and GO only moves the cursor on
+        if let Some(status) = status
+            && confirm_repeat_go
+        {
+            let confirmed =
+                self.pending_go_confirm
+                    .remove(&list_id)
+                    .is_some_and(|(pending_id, at)| {
+                        pending_id == cue_id && now.duration_since(at) <= GO_CONFIRM_WINDOW
+                    });
+            if !confirmed {
+                self.pending_go_confirm.insert(list_id, (cue_id, now));
+                return self.suppress_go(list_id, cue_id, GoSuppressedReason::ConfirmRequired);
+            }
+            self.last_go.insert(list_id, now);
+            return self.restart_cue(cue_id, status).await;
         }
+        self.pending_go_confirm.remove(&list_id);
+        self.handle_go(cue_id).await?;
+        self.last_go.insert(list_id, now);
+
+        if self.advance_cursor_when_go {
+            self.update_playback_cursor(list_id, cue_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Points the cursor of `list_id` at a cue of that list, notifying clients
+    /// only when it actually moves.
+    async fn move_list_cursor(&self, list_id: Uuid, cue_ref: Option<CueRef>) -> Result<()> {
+        let cue_id = match cue_ref {
+            Some(cue_ref) => Some(self.model_handle.resolve_cue_ref(&cue_ref).await?),
+            None => None,
+        };
+        if let Some(cursor_cue_id) = cue_id
+            && self.model_handle.get_cue_list_id(&cursor_cue_id).await != Some(list_id)
+        {
+            anyhow::bail!(
+                "Invalid playback cursor destination cue_id. cue_id = {}, list_id = {}",
+                cursor_cue_id,
+                list_id
+            );
+        }
+        if self.state_tx.borrow().cursor_of(&list_id) != cue_id {
+            self.set_playback_cursor(list_id, cue_id).await?;
+        }
+        Ok(())
+    }
+
+    fn suppress_go(&self, list_id: Uuid, cue_id: Uuid, reason: GoSuppressedReason) -> Result<()> {
+        log::info!("GO suppressed. cue_id={}, reason={:?}", cue_id, reason);
+        self.event_tx.send(BackendEvent::GoSuppressed {
+            list_id,
+            cue_id,
+            reason,
+        })?;
+        Ok(())
+    }
+
+    /// Seeks a running cue back to its start, resuming it when paused.
+    async fn restart_cue(&self, cue_id: Uuid, status: PlaybackStatus) -> Result<()> {
+        self.executor_tx
+            .send(ExecutorCommand::SeekTo(cue_id, 0.0))
+            .await?;
+        if matches!(
+            status,
+            PlaybackStatus::PreWaitPaused | PlaybackStatus::Paused
+        ) {
+            self.executor_tx
+                .send(ExecutorCommand::Resume(cue_id))
+                .await?;
+        }
+        Ok(())
     }
 
     async fn handle_go(&self, cue_id: Uuid) -> Result<()> {
@@ -357,13 +677,14 @@ impl CueController {
         Ok(())
     }
 
-    async fn update_playback_cursor(&self) -> Result<()> {
-        let state = self.state_tx.borrow().clone();
-        let playback_cursor = if let Some(cursor) = state.playback_cursor {
-            cursor
-        } else {
-            anyhow::bail!("Playback cursor unavailable.");
-        };
+    async fn update_playback_cursor(&self, list_id: Uuid, playback_cursor: Uuid) -> Result<()> {
+        let next_cursor = self.next_cursor_of(playback_cursor).await;
+        self.set_playback_cursor(list_id, next_cursor).await?;
+        Ok(())
+    }
+
+    /// Armed cue the cursor advances to from `playback_cursor` on GO.
+    async fn next_cursor_of(&self, playback_cursor: Uuid) -> Option<Uuid> {
         let next_cursor = if let Some(cue) = self.model_handle.get_cue_by_id(&playback_cursor).await
             && let CueParam::Group { base, children } = &cue.params
             && let GroupMode::StartFirst { enter } = base.mode
@@ -381,16 +702,33 @@ impl CueController {
                 .get_next_cue_id_by_id(&playback_cursor)
                 .await
         };
-        self.set_playback_cursor(next_cursor).await?;
-        Ok(())
+        match next_cursor {
+            Some(next_id) => self.model_handle.get_armed_cue_id_from(&next_id).await,
+            None => None,
+        }
     }
 
-    async fn set_playback_cursor(&self, cursor: Option<Uuid>) -> Result<()> {
-        self.state_tx.send_modify(|state| {
-            state.playback_cursor = cursor;
-        });
-        self.event_tx
-            .send(BackendEvent::PlaybackCursorMoved { cue_id: cursor })?;
+    async fn set_playback_cursor(&self, list_id: Uuid, cursor: Option<Uuid>) -> Result<()> {
+        if list_id == MAIN_CUE_LIST_ID {
+            self.state_tx.send_modify(|state| {
+                state.playback_cursor = cursor;
+            });
+            self.event_tx
+                .send(BackendEvent::PlaybackCursorMoved { cue_id: cursor })?;
+        } else {
+            self.state_tx.send_modify(|state| match cursor {
+                Some(cursor) => {
+                    state.list_cursors.insert(list_id, cursor);
+                }
+                None => {
+                    state.list_cursors.remove(&list_id);
+                }
+            });
+            self.event_tx.send(BackendEvent::ListCursorMoved {
+                list_id,
+                cue_id: cursor,
+            })?;
+        }
         Ok(())
     }
 
@@ -448,7 +786,7 @@ impl CueController {
                 if let Some(active_cue) = show_state.active_cues.get_mut(cue_id) {
                     active_cue.position = *position;
                     active_cue.duration = *duration;
-                    active_cue.params = *initial_params;
+                    active_cue.params = initial_params.clone();
                     active_cue.status = PlaybackStatus::Playing;
                 } else {
                     let active_cue = ActiveCue {
@@ -456,7 +794,7 @@ impl CueController {
                         position: *position,
                         duration: *duration,
                         status: PlaybackStatus::Playing,
-                        params: *initial_params,
+                        params: initial_params.clone(),
                     };
                     show_state.active_cues.insert(*cue_id, active_cue);
                 }
@@ -556,7 +894,7 @@ impl CueController {
             }
             ExecutorEvent::StateParamUpdated { cue_id, params } => {
                 if let Some(active_cue) = show_state.active_cues.get_mut(cue_id) {
-                    active_cue.params = *params;
+                    active_cue.params = params.clone();
                     state_changed = true;
                 }
             }
@@ -631,6 +969,57 @@ impl CueController {
                 }
             }
             ExecutorEvent::PreWaitCompleted { .. } => {} // skip to keep active cue because cue will be started. but event is emitted for client.
+            ExecutorEvent::PostWaitStarted { cue_id, duration } => {
+                if !show_state.active_cues.contains_key(cue_id) {
+                    let active_cue = ActiveCue {
+                        cue_id: *cue_id,
+                        position: 0.0,
+                        duration: *duration,
+                        status: PlaybackStatus::PostWaiting,
+                        params: StateParam::None,
+                    };
+                    show_state.active_cues.insert(*cue_id, active_cue);
+                    state_changed = true;
+                }
+            }
+            ExecutorEvent::PostWaitProgress {
+                cue_id,
+                position,
+                duration,
+            } => {
+                // cue itself may be still playing (AfterStart), so post-wait appears after it finishes
+                let active_cue = show_state.active_cues.entry(*cue_id).or_insert_with(|| {
+                    state_changed = true;
+                    ActiveCue {
+                        cue_id: *cue_id,
+                        position: *position,
+                        duration: *duration,
+                        status: PlaybackStatus::PostWaiting,
+                        params: StateParam::None,
+                    }
+                });
+                if active_cue.status == PlaybackStatus::PostWaiting {
+                    if (position - active_cue.position).abs() > 0.1 {
+                        active_cue.position = (position * 10.0).floor() / 10.0;
+                        state_changed = true;
+                    }
+                    if active_cue.duration != *duration {
+                        active_cue.duration = *duration;
+                        state_changed = true;
+                    }
+                }
+                send_event = false; // skip sending PostWaitProgress event
+            }
+            ExecutorEvent::PostWaitCompleted { cue_id } => {
+                if show_state
+                    .active_cues
+                    .get(cue_id)
+                    .is_some_and(|active_cue| active_cue.status == PlaybackStatus::PostWaiting)
+                {
+                    show_state.active_cues.shift_remove(cue_id);
+                    state_changed = true;
+                }
+            }
         }
 
         if state_changed && self.state_tx.send(show_state).is_err() {
@@ -654,13 +1043,16 @@ mod tests {
 
     use crate::{
         BackendSettings,
+        controller::state::AudioStateParam,
         event::CueStatusEventParam,
         manager::ShowModelManager,
         model::{
             self,
+            cart::CartSlot,
             cue::{
-                Cue, CueColor,
+                Cue, CueColor, NamedCueList,
                 audio::{AudioCueParam, Decibels, Easing, FadeParam, SoundType},
+                group::GroupCueParamBase,
             },
         },
     };
@@ -681,6 +1073,21 @@ mod tests {
         Sender<ExecutorEvent>,
         watch::Receiver<ShowState>,
         broadcast::Receiver<BackendEvent>,
+    ) {
+        setup_controller_with(cue_ids, |_| {}).await
+    }
+
+    /// Like `setup_controller`, then lets `edit` adjust the model.
+    async fn setup_controller_with(
+        cue_ids: &[Uuid],
+        edit: impl FnOnce(&mut model::ShowModel),
+    ) -> (
+        CueController,
+        CueControllerHandle,
+        Receiver<ExecutorCommand>,
+        Sender<ExecutorEvent>,
+        watch::Receiver<ShowState>,
+        broadcast::Receiver<BackendEvent>,
     ) {
         let (exec_tx, exec_rx) = mpsc::channel::<ExecutorCommand>(32);
         let (playback_event_tx, playback_event_rx) = mpsc::channel::<ExecutorEvent>(32);
@@ -705,7 +1112,9 @@ mod tests {
                     name: None,
                     notes: "".to_string(),
                     color: CueColor::None,
+                    armed: true,
                     pre_wait: 0.0,
+                    timeline_offset: 0.0,
                     chain: model::cue::CueChain::DoNotChain,
                     parent_id: None,
                     params: model::cue::CueParam::Audio(AudioCueParam {
@@ -725,10 +1134,14 @@ mod tests {
                         repeat: false,
                         sound_type: SoundType::Streaming,
                         envelope: Vec::new(),
+                        original_path: None,
                     }),
                 },
             );
         }
+        edit(&mut write_lock);
+        drop(write_lock);
+        tokio::spawn(manager.run());
         let (controller, controller_handle) = CueController::new(
             handle.clone(),
             settings_rx,
@@ -769,6 +1182,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn set_and_toggle_armed() {
+        let cue_id = Uuid::new_v4();
+        let (controller, controller_handle, _exec_rx, _, _state_rx, mut event_rx) =
+            setup_controller(&[cue_id]).await;
+        let model_handle = controller.model_handle.clone();
+
+        tokio::spawn(controller.run());
+
+        for (command, expected) in [
+            (ControllerCommand::ToggleArmed(cue_id.into()), false),
+            (ControllerCommand::ToggleArmed(cue_id.into()), true),
+            (
+                ControllerCommand::SetArmed {
+                    cue_id: cue_id.into(),
+                    armed: false,
+                },
+                false,
+            ),
+        ] {
+            controller_handle.send_command(command).await.unwrap();
+            loop {
+                if let Ok(BackendEvent::CueListUpdated { .. }) = event_rx.recv().await {
+                    break;
+                }
+            }
+            let cue = model_handle.get_cue_by_id(&cue_id).await.unwrap();
+            assert_eq!(cue.armed, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn load_command_by_number() {
+        let cue_id = Uuid::new_v4();
+        let (controller, controller_handle, mut exec_rx, _, _, _) =
+            setup_controller(&[cue_id]).await;
+
+        tokio::spawn(controller.run());
+
+        let command: ControllerCommand =
+            serde_json::from_str(r#"{"command":"load","params":"1"}"#).unwrap();
+        assert!(
+            matches!(&command, ControllerCommand::Load(CueRef::Number(number)) if number == "1")
+        );
+        controller_handle.send_command(command).await.unwrap();
+
+        if let Some(ExecutorCommand::Load(id)) = exec_rx.recv().await {
+            assert_eq!(id, cue_id);
+        } else {
+            unreachable!();
+        }
+    }
+
     #[tokio::test]
     async fn set_playback_cursor() {
         let cue_id = Uuid::new_v4();
@@ -797,6 +1263,383 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn go_list_keeps_cursors_apart() {
+        let main_cue = Uuid::new_v4();
+        let list_id = Uuid::new_v4();
+        let list_cues = [Uuid::new_v4(), Uuid::new_v4()];
+        let (controller, controller_handle, mut exec_rx, _, state_rx, mut event_rx) =
+            setup_controller_with(&[main_cue, list_cues[0], list_cues[1]], |model| {
+                model.cue_list.root_ids.truncate(1);
+                model.cue_list.lists.push(NamedCueList {
+                    id: list_id,
+                    name: "Sound FX".into(),
+                    root_ids: list_cues.to_vec(),
+                });
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle
+            .set_list_cursor(list_id, Some(main_cue))
+            .await
+            .unwrap();
+        controller_handle
+            .set_playback_cursor(Some(main_cue))
+            .await
+            .unwrap();
+        controller_handle
+            .set_list_cursor(list_id, Some(list_cues[0]))
+            .await
+            .unwrap();
+        controller_handle.go_list(list_id).await.unwrap();
+
+        if let Some(ExecutorCommand::Execute(id)) = exec_rx.recv().await {
+            assert_eq!(id, list_cues[0]);
+        } else {
+            unreachable!();
+        }
+        loop {
+            if let Ok(BackendEvent::ListCursorMoved { cue_id, .. }) = event_rx.recv().await
+                && cue_id == Some(list_cues[1])
+            {
+                break;
+            }
+        }
+        let state = state_rx.borrow();
+        assert_eq!(state.playback_cursor, Some(main_cue));
+        assert_eq!(state.cursor_of(&list_id), Some(list_cues[1]));
+    }
+
+    #[tokio::test]
+    async fn go_lockout_is_kept_per_list() {
+        let main_cue = Uuid::new_v4();
+        let list_id = Uuid::new_v4();
+        let list_cue = Uuid::new_v4();
+        let (controller, controller_handle, mut exec_rx, _, _state_rx, _) =
+            setup_controller_with(&[main_cue, list_cue], |model| {
+                model.cue_list.root_ids.truncate(1);
+                model.cue_list.lists.push(NamedCueList {
+                    id: list_id,
+                    name: "Sound FX".into(),
+                    root_ids: vec![list_cue],
+                });
+                model.settings.general.go_lockout = 10.0;
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle
+            .set_playback_cursor(Some(main_cue))
+            .await
+            .unwrap();
+        controller_handle
+            .set_list_cursor(list_id, Some(list_cue))
+            .await
+            .unwrap();
+        controller_handle.go().await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Execute(id)) if id == main_cue
+        ));
+        // a GO on the main list does not lock out another operator's list
+        controller_handle.go_list(list_id).await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Execute(id)) if id == list_cue
+        ));
+    }
+
+    #[tokio::test]
+    async fn cursor_moves_within_named_list() {
+        let main_cue = Uuid::new_v4();
+        let list_id = Uuid::new_v4();
+        let list_first = Uuid::new_v4();
+        let list_second = Uuid::new_v4();
+        let (controller, controller_handle, _exec_rx, _, mut state_rx, _) =
+            setup_controller_with(&[main_cue, list_first, list_second], |model| {
+                model.cue_list.root_ids.truncate(1);
+                model.cue_list.lists.push(NamedCueList {
+                    id: list_id,
+                    name: "Sound FX".into(),
+                    root_ids: vec![list_first, list_second],
+                });
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle
+            .set_playback_cursor(Some(main_cue))
+            .await
+            .unwrap();
+        controller_handle
+            .set_list_cursor(list_id, Some(list_first))
+            .await
+            .unwrap();
+        controller_handle.cursor_next(Some(list_id)).await.unwrap();
+        state_rx
+            .wait_for(|state| state.cursor_of(&list_id) == Some(list_second))
+            .await
+            .unwrap();
+        controller_handle
+            .cursor_previous(Some(list_id))
+            .await
+            .unwrap();
+        state_rx
+            .wait_for(|state| state.cursor_of(&list_id) == Some(list_first))
+            .await
+            .unwrap();
+        controller_handle.cursor_next(Some(list_id)).await.unwrap();
+        state_rx
+            .wait_for(|state| state.cursor_of(&list_id) == Some(list_second))
+            .await
+            .unwrap();
+        controller_handle
+            .cursor_to_first(Some(list_id))
+            .await
+            .unwrap();
+        state_rx
+            .wait_for(|state| state.cursor_of(&list_id) == Some(list_first))
+            .await
+            .unwrap();
+        // the main list's cursor is left where it was
+        assert_eq!(state_rx.borrow().playback_cursor, Some(main_cue));
+    }
+
+    #[tokio::test]
+    async fn go_lockout_and_repeat_confirm() {
+        let cue_id = Uuid::new_v4();
+        let (
+            controller,
+            controller_handle,
+            mut exec_rx,
+            playback_event_tx,
+            mut state_rx,
+            mut event_rx,
+        ) = setup_controller_with(&[cue_id], |model| {
+            model.settings.general.go_lockout = 0.2;
+            model.settings.general.confirm_repeat_go = true;
+        })
+        .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle
+            .set_playback_cursor(Some(cue_id))
+            .await
+            .unwrap();
+        controller_handle.go().await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Execute(id)) if id == cue_id
+        ));
+        playback_event_tx
+            .send(ExecutorEvent::Started {
+                cue_id,
+                position: 0.0,
+                duration: 50.0,
+                initial_params: StateParam::None,
+            })
+            .await
+            .unwrap();
+        state_rx
+            .wait_for(|state| state.active_cues.contains_key(&cue_id))
+            .await
+            .unwrap();
+
+        controller_handle
+            .set_playback_cursor(Some(cue_id))
+            .await
+            .unwrap();
+        let mut expect_suppressed = async |expected: GoSuppressedReason| loop {
+            if let Ok(BackendEvent::GoSuppressed { reason, .. }) = event_rx.recv().await {
+                assert_eq!(reason, expected);
+                break;
+            }
+        };
+        controller_handle.go().await.unwrap();
+        expect_suppressed(GoSuppressedReason::Lockout).await;
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        controller_handle.go().await.unwrap();
+        expect_suppressed(GoSuppressedReason::ConfirmRequired).await;
+        controller_handle.go().await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::SeekTo(id, position)) if id == cue_id && position == 0.0
+        ));
+    }
+
+    #[tokio::test]
+    async fn go_on_running_cue_advances_cursor_without_confirm() {
+        let [first, second] = [(); 2].map(|_| Uuid::new_v4());
+        let (controller, controller_handle, mut exec_rx, playback_event_tx, mut state_rx, _) =
+            setup_controller_with(&[first, second], |_| {}).await;
+
+        tokio::spawn(controller.run());
+
+        playback_event_tx
+            .send(ExecutorEvent::Started {
+                cue_id: first,
+                position: 0.0,
+                duration: 50.0,
+                initial_params: StateParam::None,
+            })
+            .await
+            .unwrap();
+        state_rx
+            .wait_for(|state| state.active_cues.contains_key(&first))
+            .await
+            .unwrap();
+
+        controller_handle
+            .set_playback_cursor(Some(first))
+            .await
+            .unwrap();
+        controller_handle.go().await.unwrap();
+        state_rx
+            .wait_for(|state| state.cursor_of(&MAIN_CUE_LIST_ID) == Some(second))
+            .await
+            .unwrap();
+        assert!(exec_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn cursor_steps_through_entered_group() {
+        let [first, group, child_a, child_b, last] = [(); 5].map(|_| Uuid::new_v4());
+        let (controller, controller_handle, mut exec_rx, _, mut state_rx, _) =
+            setup_controller_with(&[first, group, child_a, child_b, last], |model| {
+                model
+                    .cue_list
+                    .root_ids
+                    .retain(|id| *id != child_a && *id != child_b);
+                model.cue_list.cues.get_mut(&group).unwrap().params = CueParam::Group {
+                    base: GroupCueParamBase {
+                        mode: GroupMode::StartFirst { enter: true },
+                        volume: Decibels::IDENTITY,
+                    },
+                    children: vec![child_a, child_b],
+                };
+                for child_id in [child_a, child_b] {
+                    model.cue_list.cues.get_mut(&child_id).unwrap().parent_id = Some(group);
+                }
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle
+            .set_playback_cursor(Some(first))
+            .await
+            .unwrap();
+        for expected in [group, child_b, last] {
+            controller_handle.cursor_next(None).await.unwrap();
+            state_rx
+                .wait_for(|state| state.playback_cursor == Some(expected))
+                .await
+                .unwrap();
+        }
+        for expected in [child_b, group, first] {
+            controller_handle.cursor_previous(None).await.unwrap();
+            state_rx
+                .wait_for(|state| state.playback_cursor == Some(expected))
+                .await
+                .unwrap();
+        }
+
+        controller_handle.go_from(child_b.into()).await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Execute(id)) if id == child_b
+        ));
+        state_rx
+            .wait_for(|state| state.playback_cursor == Some(last))
+            .await
+            .unwrap();
+        controller_handle.cursor_to_first(None).await.unwrap();
+        state_rx
+            .wait_for(|state| state.playback_cursor == Some(first))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn set_master_volume() {
+        let (controller, controller_handle, mut exec_rx, _, state_rx, mut event_rx) =
+            setup_controller(&[]).await;
+
+        tokio::spawn(controller.run());
+
+        let fade_param = FadeParam {
+            duration: 2.0,
+            easing: Easing::Linear,
+        };
+        controller_handle
+            .set_master_volume(Decibels::from(-12.0), Some(fade_param))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::SetMasterVolume(volume, Some(fade))) if volume == Decibels::from(-12.0) && fade == fade_param
+        ));
+        assert_eq!(
+            event_rx.recv().await.unwrap(),
+            BackendEvent::MasterVolumeChanged {
+                volume: Decibels::from(-12.0)
+            }
+        );
+        assert_eq!(state_rx.borrow().master_volume, Decibels::from(-12.0));
+    }
+
+    #[tokio::test]
+    async fn store_live_state_writes_back_into_cue() {
+        let cue_id = Uuid::new_v4();
+        let (controller, controller_handle, _, playback_event_tx, _state_rx, mut event_rx) =
+            setup_controller(&[cue_id]).await;
+
+        tokio::spawn(controller.run());
+
+        let live = AudioStateParam {
+            volume: Decibels::from(-6.0),
+            pan: 0.5,
+            rate: 1.5,
+            end_time: Some(40.0),
+            ..Default::default()
+        };
+        playback_event_tx
+            .send(ExecutorEvent::Started {
+                cue_id,
+                position: 0.0,
+                duration: 40.0,
+                initial_params: StateParam::Audio(live),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            event_rx.recv().await.unwrap(),
+            BackendEvent::CueStatus(CueStatusEventParam::Started { .. })
+        ));
+
+        controller_handle.store_live_state(cue_id).await.unwrap();
+
+        let BackendEvent::CueListUpdated { cue_list } = event_rx.recv().await.unwrap() else {
+            unreachable!();
+        };
+        let Some(CueParam::Audio(params)) = cue_list.cues.get(&cue_id).map(|cue| &cue.params)
+        else {
+            unreachable!();
+        };
+        assert_eq!(params.volume, Decibels::from(-6.0));
+        assert_eq!(params.pan, 0.5);
+        assert_eq!(params.start_time, None);
+        assert_eq!(params.end_time, Some(40.0));
+        assert_eq!(params.fade_out_param, None);
+    }
+
     #[tokio::test]
     async fn started_event() {
         let cue_id = Uuid::new_v4();
@@ -1008,4 +1851,142 @@ mod tests {
         );
         assert!(!state_rx.borrow().active_cues.contains_key(&cue_id));
     }
+
+    #[tokio::test]
+    async fn fire_cart_slot_toggles() {
+        let cue_id = Uuid::new_v4();
+        let (controller, controller_handle, mut exec_rx, playback_event_tx, mut state_rx, _) =
+            setup_controller_with(&[cue_id], |model| {
+                model.cart = vec![
+                    None,
+                    Some(CartSlot {
+                        cue_id,
+                        mode: CartSlotMode::Toggle,
+                    }),
+                ];
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        controller_handle.fire_cart_slot(1).await.unwrap();
+        if let Some(ExecutorCommand::Execute(id)) = exec_rx.recv().await {
+            assert_eq!(id, cue_id);
+        } else {
+            unreachable!();
+        }
+        assert_eq!(state_rx.borrow().playback_cursor, None);
+
+        playback_event_tx
+            .send(ExecutorEvent::Started {
+                cue_id,
+                position: 0.0,
+                duration: 50.0,
+                initial_params: StateParam::None,
+            })
+            .await
+            .unwrap();
+        state_rx.changed().await.unwrap();
+
+        controller_handle.fire_cart_slot(1).await.unwrap();
+        if let Some(ExecutorCommand::Stop(id, stop_mode)) = exec_rx.recv().await {
+            assert_eq!(id, cue_id);
+            assert!(matches!(stop_mode, StopMode::Soft));
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[tokio::test]
+    async fn panic_fades_then_hard_stops() {
+        let cue_id = Uuid::new_v4();
+        let (controller, controller_handle, mut exec_rx, playback_event_tx, mut state_rx, _) =
+            setup_controller_with(&[cue_id], |model| {
+                model.settings.audio.panic_fade_duration = 1.5;
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        playback_event_tx
+            .send(ExecutorEvent::Started {
+                cue_id,
+                position: 0.0,
+                duration: 50.0,
+                initial_params: StateParam::None,
+            })
+            .await
+            .unwrap();
+        state_rx.changed().await.unwrap();
+
+        controller_handle.panic().await.unwrap();
+        if let Some(ExecutorCommand::Stop(id, StopMode::Fade(fade_param))) = exec_rx.recv().await {
+            assert_eq!(id, cue_id);
+            assert_eq!(fade_param.duration, 1.5);
+        } else {
+            unreachable!();
+        }
+
+        playback_event_tx
+            .send(ExecutorEvent::Stopping {
+                cue_id,
+                position: 1.0,
+                duration: 50.0,
+            })
+            .await
+            .unwrap();
+        state_rx.changed().await.unwrap();
+
+        controller_handle.panic().await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Stop(id, StopMode::Hard)) if id == cue_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn panic_stops_pre_waiting_group() {
+        let group = Uuid::new_v4();
+        let child = Uuid::new_v4();
+        let (controller, controller_handle, mut exec_rx, playback_event_tx, mut state_rx, _) =
+            setup_controller_with(&[group, child], |model| {
+                model.cue_list.root_ids.truncate(1);
+                let group_cue = model.cue_list.cues.get_mut(&group).unwrap();
+                group_cue.pre_wait = 5.0;
+                group_cue.params = CueParam::Group {
+                    base: GroupCueParamBase {
+                        mode: GroupMode::Concurrency,
+                        volume: Decibels::IDENTITY,
+                    },
+                    children: vec![child],
+                };
+                model.cue_list.cues.get_mut(&child).unwrap().parent_id = Some(group);
+            })
+            .await;
+
+        tokio::spawn(controller.run());
+
+        playback_event_tx
+            .send(ExecutorEvent::PreWaitStarted {
+                cue_id: group,
+                duration: 5.0,
+            })
+            .await
+            .unwrap();
+        state_rx
+            .wait_for(|state| {
+                state
+                    .active_cues
+                    .get(&group)
+                    .is_some_and(|active_cue| active_cue.status == PlaybackStatus::PreWaiting)
+            })
+            .await
+            .unwrap();
+
+        controller_handle.panic().await.unwrap();
+        assert!(matches!(
+            exec_rx.recv().await,
+            Some(ExecutorCommand::Stop(id, StopMode::Fade(_))) if id == group
+        ));
+    }
 }
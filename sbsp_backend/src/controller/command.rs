@@ -1,12 +1,17 @@
 // SPDX-License-Identifier: Elastic-2.0
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::action::CueAction;
+use crate::{
+    action::CueAction,
+    model::cue::audio::{Decibels, FadeParam},
+};
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
     tag = "command",
@@ -16,15 +21,80 @@ use crate::action::CueAction;
 )]
 pub enum ControllerCommand {
     Go,
-    Load(Uuid),
-    Pause(Uuid),
-    Resume(Uuid),
-    Stop(Uuid),
-    SeekTo(Uuid, f64),
-    SeekBy(Uuid, f64),
+    /// Same as `Go` for the cue list `list_id`, using that list's cursor.
+    GoList {
+        list_id: Uuid,
+    },
+    Load(CueRef),
+    Pause(CueRef),
+    Resume(CueRef),
+    Stop(CueRef),
+    SeekTo(CueRef, f64),
+    SeekBy(CueRef, f64),
     PauseAll,
     ResumeAll,
     StopAll,
-    PerformAction(Uuid, CueAction),
-    SetPlaybackCursor { cue_id: Option<Uuid> },
+    /// Fades out everything over the show's panic fade time and halts pre-waits
+    /// and chains. Pressing again while cues are still stopping stops them hard.
+    Panic,
+    PerformAction(CueRef, CueAction),
+    /// Fires the cue of a cart slot as its mode says. The playback cursor is left alone.
+    FireCartSlot(usize),
+    SetPlaybackCursor {
+        cue_id: Option<CueRef>,
+    },
+    SetListCursor {
+        list_id: Uuid,
+        cue_id: Option<CueRef>,
+    },
+    SetArmed {
+        cue_id: CueRef,
+        armed: bool,
+    },
+    ToggleArmed(CueRef),
+    /// Copies the live parameters of the running audio cue into the cue itself.
+    /// The playback rate has no counterpart in the cue and is left out.
+    StoreLiveState(CueRef),
+    /// Moves the cursor of the list, the main one when `list_id` is `None`,
+    /// to the cue GO would advance it to.
+    CursorNext {
+        list_id: Option<Uuid>,
+    },
+    /// Moves the cursor of the list back by one armed cue, or to the last cue when it is past the end.
+    CursorPrevious {
+        list_id: Option<Uuid>,
+    },
+    CursorToFirst {
+        list_id: Option<Uuid>,
+    },
+    /// Moves the cursor of the cue's list to the cue and GOes.
+    GoFrom(CueRef),
+    /// Sets the gain applied to the whole output, fading over `fade_param` when given.
+    SetMasterVolume {
+        volume: Decibels,
+        fade_param: Option<FadeParam>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(untagged)]
+pub enum CueRef {
+    Id(Uuid),
+    Number(String),
+}
+
+impl From<Uuid> for CueRef {
+    fn from(value: Uuid) -> Self {
+        Self::Id(value)
+    }
+}
+
+impl fmt::Display for CueRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CueRef::Id(id) => write!(f, "{}", id),
+            CueRef::Number(number) => write!(f, "#{}", number),
+        }
+    }
 }
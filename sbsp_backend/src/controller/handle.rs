@@ -4,88 +4,172 @@
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
-use crate::action::CueAction;
+use crate::{
+    action::CueAction,
+    model::cue::audio::{Decibels, FadeParam},
+};
 
-use super::ControllerCommand;
+use super::{
+    ControllerCommand, CueRef,
+    recorder::{CommandRecorder, CommandRecording, ReplayHandle, spawn_replay},
+};
 
 #[derive(Clone)]
 pub struct CueControllerHandle {
     pub(crate) command_tx: mpsc::Sender<ControllerCommand>,
+    pub(crate) recorder: CommandRecorder,
 }
 
 impl CueControllerHandle {
     pub async fn send_command(&self, command: ControllerCommand) -> anyhow::Result<()> {
+        self.recorder.record(&command);
         self.command_tx.send(command).await?;
         Ok(())
     }
 
+    /// Starts capturing every command sent through this handle or its clones,
+    /// discarding any recording in progress.
+    pub fn start_recording(&self) {
+        self.recorder.start();
+    }
+
+    pub fn stop_recording(&self) -> Option<CommandRecording> {
+        self.recorder.stop()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_recording()
+    }
+
+    /// Sends the recorded commands again with their original spacing divided by `speed`.
+    /// Replayed commands bypass the recorder.
+    pub fn replay(&self, recording: CommandRecording, speed: f64) -> ReplayHandle {
+        spawn_replay(self.command_tx.clone(), recording, speed)
+    }
+
     pub async fn go(&self) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::Go).await?;
-        Ok(())
+        self.send_command(ControllerCommand::Go).await
+    }
+
+    pub async fn go_list(&self, list_id: Uuid) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::GoList { list_id })
+            .await
+    }
+
+    pub async fn go_from(&self, cue_ref: CueRef) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::GoFrom(cue_ref)).await
+    }
+
+    pub async fn cursor_next(&self, list_id: Option<Uuid>) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::CursorNext { list_id })
+            .await
+    }
+
+    pub async fn cursor_previous(&self, list_id: Option<Uuid>) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::CursorPrevious { list_id })
+            .await
+    }
+
+    pub async fn cursor_to_first(&self, list_id: Option<Uuid>) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::CursorToFirst { list_id })
+            .await
+    }
+
+    pub async fn set_master_volume(
+        &self,
+        volume: Decibels,
+        fade_param: Option<FadeParam>,
+    ) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::SetMasterVolume { volume, fade_param })
+            .await
+    }
+
+    pub async fn fire_cart_slot(&self, index: usize) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::FireCartSlot(index))
+            .await
     }
 
     pub async fn load(&self, uuid: Uuid) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::Load(uuid)).await?;
-        Ok(())
+        self.send_command(ControllerCommand::Load(uuid.into()))
+            .await
     }
 
     pub async fn pause(&self, uuid: Uuid) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::Pause(uuid)).await?;
-        Ok(())
+        self.send_command(ControllerCommand::Pause(uuid.into()))
+            .await
     }
 
     pub async fn resume(&self, uuid: Uuid) -> anyhow::Result<()> {
-        self.command_tx
-            .send(ControllerCommand::Resume(uuid))
-            .await?;
-        Ok(())
+        self.send_command(ControllerCommand::Resume(uuid.into()))
+            .await
     }
 
     pub async fn stop(&self, uuid: Uuid) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::Stop(uuid)).await?;
-        Ok(())
+        self.send_command(ControllerCommand::Stop(uuid.into()))
+            .await
     }
 
     pub async fn seek_to(&self, uuid: Uuid, position: f64) -> anyhow::Result<()> {
-        self.command_tx
-            .send(ControllerCommand::SeekTo(uuid, position))
-            .await?;
-        Ok(())
+        self.send_command(ControllerCommand::SeekTo(uuid.into(), position))
+            .await
     }
 
     pub async fn seek_by(&self, uuid: Uuid, amount: f64) -> anyhow::Result<()> {
-        self.command_tx
-            .send(ControllerCommand::SeekBy(uuid, amount))
-            .await?;
-        Ok(())
+        self.send_command(ControllerCommand::SeekBy(uuid.into(), amount))
+            .await
     }
 
     pub async fn pause_all(&self) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::PauseAll).await?;
-        Ok(())
+        self.send_command(ControllerCommand::PauseAll).await
     }
 
     pub async fn resume_all(&self) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::ResumeAll).await?;
-        Ok(())
+        self.send_command(ControllerCommand::ResumeAll).await
     }
 
     pub async fn stop_all(&self) -> anyhow::Result<()> {
-        self.command_tx.send(ControllerCommand::StopAll).await?;
-        Ok(())
+        self.send_command(ControllerCommand::StopAll).await
+    }
+
+    pub async fn panic(&self) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::Panic).await
     }
 
     pub async fn perform_action(&self, uuid: Uuid, action: CueAction) -> anyhow::Result<()> {
-        self.command_tx
-            .send(ControllerCommand::PerformAction(uuid, action))
-            .await?;
-        Ok(())
+        self.send_command(ControllerCommand::PerformAction(uuid.into(), action))
+            .await
+    }
+
+    pub async fn set_armed(&self, uuid: Uuid, armed: bool) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::SetArmed {
+            cue_id: uuid.into(),
+            armed,
+        })
+        .await
+    }
+
+    pub async fn toggle_armed(&self, uuid: Uuid) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::ToggleArmed(uuid.into()))
+            .await
+    }
+
+    pub async fn store_live_state(&self, uuid: Uuid) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::StoreLiveState(uuid.into()))
+            .await
     }
 
     pub async fn set_playback_cursor(&self, uuid: Option<Uuid>) -> anyhow::Result<()> {
-        self.command_tx
-            .send(ControllerCommand::SetPlaybackCursor { cue_id: uuid })
-            .await?;
-        Ok(())
+        self.send_command(ControllerCommand::SetPlaybackCursor {
+            cue_id: uuid.map(CueRef::from),
+        })
+        .await
+    }
+
+    pub async fn set_list_cursor(&self, list_id: Uuid, uuid: Option<Uuid>) -> anyhow::Result<()> {
+        self.send_command(ControllerCommand::SetListCursor {
+            list_id,
+            cue_id: uuid.map(CueRef::from),
+        })
+        .await
     }
 }
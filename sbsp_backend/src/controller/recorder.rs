@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinHandle,
+    time::Instant,
+};
+
+use super::ControllerCommand;
+
+pub const MIN_REPLAY_SPEED: f64 = 0.1;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedCommand {
+    /// Seconds since the recording was started.
+    pub offset: f64,
+    pub command: ControllerCommand,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandRecording {
+    pub commands: Vec<RecordedCommand>,
+}
+
+struct ActiveRecording {
+    started: Instant,
+    commands: Vec<RecordedCommand>,
+}
+
+/// Shared by every clone of a `CueControllerHandle`, so commands are captured
+/// whichever clone sends them.
+#[derive(Clone, Default)]
+pub(crate) struct CommandRecorder {
+    active: Arc<Mutex<Option<ActiveRecording>>>,
+}
+
+impl CommandRecorder {
+    pub(crate) fn start(&self) {
+        *self.active.lock().unwrap() = Some(ActiveRecording {
+            started: Instant::now(),
+            commands: Vec::new(),
+        });
+    }
+
+    pub(crate) fn stop(&self) -> Option<CommandRecording> {
+        self.active
+            .lock()
+            .unwrap()
+            .take()
+            .map(|active| CommandRecording {
+                commands: active.commands,
+            })
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.active.lock().unwrap().is_some()
+    }
+
+    pub(crate) fn record(&self, command: &ControllerCommand) {
+        if let Some(active) = self.active.lock().unwrap().as_mut() {
+            active.commands.push(RecordedCommand {
+                offset: active.started.elapsed().as_secs_f64(),
+                command: command.clone(),
+            });
+        }
+    }
+}
+
+pub struct ReplayHandle {
+    speed_tx: watch::Sender<f64>,
+    task: JoinHandle<()>,
+}
+
+impl ReplayHandle {
+    /// Changes the replay speed from now on. 2.0 plays the rest twice as fast.
+    pub fn set_speed(&self, speed: f64) {
+        self.speed_tx.send_replace(speed.max(MIN_REPLAY_SPEED));
+    }
+
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits until every command has been sent or the replay was stopped.
+    pub async fn finished(self) {
+        let _ = self.task.await;
+    }
+}
+
+pub(crate) fn spawn_replay(
+    command_tx: mpsc::Sender<ControllerCommand>,
+    recording: CommandRecording,
+    speed: f64,
+) -> ReplayHandle {
+    let (speed_tx, speed_rx) = watch::channel(speed.max(MIN_REPLAY_SPEED));
+    let task = tokio::spawn(run_replay(command_tx, recording, speed_rx));
+    ReplayHandle { speed_tx, task }
+}
+
+async fn run_replay(
+    command_tx: mpsc::Sender<ControllerCommand>,
+    recording: CommandRecording,
+    mut speed_rx: watch::Receiver<f64>,
+) {
+    // position in recording time, which advances at `speed` times real time
+    let mut position = 0.0;
+    for recorded in recording.commands {
+        while recorded.offset > position {
+            let speed = *speed_rx.borrow_and_update();
+            let wait = Duration::from_secs_f64((recorded.offset - position) / speed);
+            let started = Instant::now();
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {
+                    position = recorded.offset;
+                }
+                Ok(()) = speed_rx.changed() => {
+                    position += started.elapsed().as_secs_f64() * speed;
+                }
+            }
+        }
+        if command_tx.send(recorded.command).await.is_err() {
+            log::warn!("Replay stopped, the cue controller has gone away.");
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::CueControllerHandle;
+
+    #[tokio::test(start_paused = true)]
+    async fn replays_recorded_commands_with_their_timing() {
+        let (command_tx, mut command_rx) = mpsc::channel(8);
+        let handle = CueControllerHandle {
+            command_tx,
+            recorder: CommandRecorder::default(),
+        };
+
+        handle.go().await.unwrap();
+        handle.start_recording();
+        handle.go().await.unwrap();
+        tokio::time::advance(Duration::from_millis(200)).await;
+        handle.clone().stop_all().await.unwrap();
+        let recording = handle.stop_recording().unwrap();
+        handle.panic().await.unwrap();
+        while command_rx.try_recv().is_ok() {}
+
+        // only commands sent while recording are kept, from any clone
+        assert_eq!(recording.commands.len(), 2);
+        assert!(matches!(
+            recording.commands[0].command,
+            ControllerCommand::Go
+        ));
+        assert!(matches!(
+            recording.commands[1].command,
+            ControllerCommand::StopAll
+        ));
+        let gap = recording.commands[1].offset - recording.commands[0].offset;
+        assert!((gap - 0.2).abs() < 1e-9, "gap was {}", gap);
+
+        handle.start_recording();
+        let started = Instant::now();
+        let replay = handle.replay(recording, 2.0);
+        assert!(matches!(
+            command_rx.recv().await,
+            Some(ControllerCommand::Go)
+        ));
+        // at double speed the 200 ms gap takes 100 ms
+        tokio::time::advance(Duration::from_millis(99)).await;
+        assert!(command_rx.try_recv().is_err());
+        tokio::time::advance(Duration::from_millis(1)).await;
+        assert!(matches!(
+            command_rx.recv().await,
+            Some(ControllerCommand::StopAll)
+        ));
+        assert_eq!(started.elapsed(), Duration::from_millis(100));
+        replay.finished().await;
+
+        // the replayed commands are not recorded again
+        assert!(handle.stop_recording().unwrap().commands.is_empty());
+    }
+}
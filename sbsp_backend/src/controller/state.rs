@@ -1,11 +1,16 @@
 // SPDX-License-Identifier: Elastic-2.0
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
+use std::collections::HashMap;
+
 use indexmap::map::IndexMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::model::cue::audio::Decibels;
+use crate::model::cue::{
+    MAIN_CUE_LIST_ID,
+    audio::{Decibels, EnvelopeSegment, FadeParam},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
@@ -14,12 +19,13 @@ pub enum PlaybackStatus {
     Loaded,
     PreWaiting,
     PreWaitPaused,
+    PostWaiting,
     Playing,
     Paused,
     Stopping,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
     tag = "type",
@@ -32,12 +38,33 @@ pub enum StateParam {
     Audio(AudioStateParam),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, Copy)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct AudioStateParam {
     pub repeating: bool,
     pub volume: Decibels,
+    pub pan: f32,
+    pub rate: f64,
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+    pub fade_out_param: Option<FadeParam>,
+    pub envelope: Vec<EnvelopeSegment>,
+}
+
+impl Default for AudioStateParam {
+    fn default() -> Self {
+        Self {
+            repeating: false,
+            volume: Decibels::IDENTITY,
+            pan: 0.0,
+            rate: 1.0,
+            start_time: None,
+            end_time: None,
+            fade_out_param: None,
+            envelope: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,15 +82,29 @@ pub struct ActiveCue {
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct ShowState {
+    /// Cursor of the main cue list.
     pub playback_cursor: Option<Uuid>,
+    /// Cursors of the other cue lists, keyed by list id.
+    pub list_cursors: HashMap<Uuid, Uuid>,
     pub active_cues: IndexMap<Uuid, ActiveCue>,
+    pub master_volume: Decibels,
 }
 
 impl ShowState {
     pub fn new() -> Self {
         Self {
             playback_cursor: None,
+            list_cursors: HashMap::new(),
             active_cues: IndexMap::new(),
+            master_volume: Decibels::IDENTITY,
+        }
+    }
+
+    pub fn cursor_of(&self, list_id: &Uuid) -> Option<Uuid> {
+        if *list_id == MAIN_CUE_LIST_ID {
+            self.playback_cursor
+        } else {
+            self.list_cursors.get(list_id).copied()
         }
     }
 }
@@ -0,0 +1,442 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    manager::CueSheetFormat,
+    model::{
+        ShowModel,
+        cue::{Cue, CueChain, CueList, CueParam, Uuid},
+    },
+};
+
+const COLUMNS: [&str; 10] = [
+    "Number", "Name", "Type", "Target", "Duration", "Fades", "Chain", "Pre-Wait", "Notes", "Depth",
+];
+
+/// Section title of the main cue list, matching the label in the editor.
+const MAIN_LIST_NAME: &str = "Main";
+
+/// One printable line of the cue sheet. `depth` is the group nesting level.
+struct SheetLine {
+    depth: usize,
+    columns: [String; 9],
+}
+
+/// The lines of one cue list. Named lists follow the main one.
+struct SheetSection<'a> {
+    name: &'a str,
+    lines: Vec<SheetLine>,
+}
+
+impl CueSheetFormat {
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// Renders every cue list in playback order, one section per list. `durations`
+/// maps audio targets, as written in the model, to the file length in seconds.
+pub fn render(
+    model: &ShowModel,
+    durations: &HashMap<PathBuf, f64>,
+    format: CueSheetFormat,
+) -> String {
+    let section = |name, root_ids: &[Uuid]| {
+        let mut lines = Vec::new();
+        collect_lines(&model.cue_list, root_ids, 0, durations, &mut lines);
+        SheetSection { name, lines }
+    };
+    let mut sections = vec![section(MAIN_LIST_NAME, &model.cue_list.root_ids)];
+    sections.extend(
+        model
+            .cue_list
+            .lists
+            .iter()
+            .map(|list| section(&list.name, &list.root_ids)),
+    );
+    match format {
+        CueSheetFormat::Csv => render_csv(&sections),
+        CueSheetFormat::Markdown => render_markdown(&model.name, &sections),
+        CueSheetFormat::Html => render_html(&model.name, &sections),
+    }
+}
+
+fn collect_lines(
+    cue_list: &CueList,
+    ids: &[Uuid],
+    depth: usize,
+    durations: &HashMap<PathBuf, f64>,
+    lines: &mut Vec<SheetLine>,
+) {
+    for cue in ids.iter().filter_map(|id| cue_list.cues.get(id)) {
+        lines.push(SheetLine {
+            depth,
+            columns: describe(cue_list, cue, durations),
+        });
+        if let CueParam::Group { children, .. } = &cue.params {
+            collect_lines(cue_list, children, depth + 1, durations, lines);
+        }
+    }
+}
+
+fn describe(cue_list: &CueList, cue: &Cue, durations: &HashMap<PathBuf, f64>) -> [String; 9] {
+    let cue_number = |id: &Uuid| {
+        cue_list
+            .cues
+            .get(id)
+            .map(|target| target.number.clone())
+            .unwrap_or_else(|| "?".into())
+    };
+
+    let (kind, target, duration, fades) = match &cue.params {
+        CueParam::Audio(param) => {
+            let duration = durations.get(&param.target).map(|length| {
+                let end = param.end_time.unwrap_or(*length).min(*length);
+                (end - param.start_time.unwrap_or(0.0)).max(0.0)
+            });
+            let mut fades = Vec::new();
+            if let Some(fade_in) = &param.fade_in_param {
+                fades.push(format!("In {}", format_time(fade_in.duration)));
+            }
+            if let Some(fade_out) = &param.fade_out_param {
+                fades.push(format!("Out {}", format_time(fade_out.duration)));
+            }
+            (
+                "Audio",
+                param.target.to_string_lossy().into_owned(),
+                duration,
+                fades.join(" / "),
+            )
+        }
+        CueParam::Wait(param) => ("Wait", String::new(), Some(param.duration), String::new()),
+        CueParam::Fade(param) => (
+            "Fade",
+            cue_number(&param.target),
+            Some(param.fade_param.duration),
+            format!("{:.1} dB", f32::from(param.volume)),
+        ),
+        CueParam::Start(param) => ("Start", cue_number(&param.target), None, String::new()),
+        CueParam::Stop(param) => ("Stop", cue_number(&param.target), None, String::new()),
+        CueParam::Pause(param) => ("Pause", cue_number(&param.target), None, String::new()),
+        CueParam::Load(param) => ("Load", cue_number(&param.target), None, String::new()),
+        CueParam::Group { .. } => ("Group", String::new(), None, String::new()),
+    };
+
+    let chain = match &cue.chain {
+        CueChain::DoNotChain => String::new(),
+        CueChain::AfterStart {
+            target_id,
+            post_wait,
+        }
+        | CueChain::AfterComplete {
+            target_id,
+            post_wait,
+        } => {
+            let mut chain = if matches!(cue.chain, CueChain::AfterStart { .. }) {
+                "Auto-continue".to_string()
+            } else {
+                "Auto-follow".to_string()
+            };
+            if let Some(target_id) = target_id {
+                let _ = write!(chain, " to {}", cue_number(target_id));
+            }
+            if *post_wait > 0.0 {
+                let _ = write!(chain, " +{}", format_time(*post_wait));
+            }
+            chain
+        }
+    };
+
+    let name = cue.name.clone().unwrap_or_else(|| match &cue.params {
+        CueParam::Audio(param) => param
+            .target
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        _ => String::new(),
+    });
+
+    [
+        cue.number.clone(),
+        name,
+        kind.to_string(),
+        target,
+        duration.map(format_time).unwrap_or_default(),
+        fades,
+        chain,
+        if cue.pre_wait > 0.0 {
+            format_time(cue.pre_wait)
+        } else {
+            String::new()
+        },
+        cue.notes.clone(),
+    ]
+}
+
+/// Formats seconds as `m:ss.ss`.
+fn format_time(seconds: f64) -> String {
+    let minutes = (seconds / 60.0).floor();
+    format!("{}:{:05.2}", minutes as u64, seconds - minutes * 60.0)
+}
+
+fn render_csv(sections: &[SheetSection]) -> String {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    let mut output = COLUMNS.join(",");
+    output.push_str(",List\r\n");
+    for section in sections {
+        for line in &section.lines {
+            let mut fields: Vec<String> = line.columns.iter().map(|field| escape(field)).collect();
+            fields.push(line.depth.to_string());
+            fields.push(escape(section.name));
+            output.push_str(&fields.join(","));
+            output.push_str("\r\n");
+        }
+    }
+    output
+}
+
+fn render_markdown(title: &str, sections: &[SheetSection]) -> String {
+    fn escape(field: &str) -> String {
+        field
+            .replace('\\', "\\\\")
+            .replace('|', "\\|")
+            .replace(['\r', '\n'], " ")
+    }
+
+    let header = &COLUMNS[..COLUMNS.len() - 1];
+    let mut output = format!("# {}\n", escape(title));
+    for section in sections {
+        // a show with only the main list keeps a single untitled table
+        if sections.len() > 1 {
+            let _ = write!(output, "\n## {}\n", escape(section.name));
+        }
+        let _ = writeln!(output, "\n| {} |", header.join(" | "));
+        let _ = writeln!(output, "|{}", "---|".repeat(header.len()));
+        for line in &section.lines {
+            let mut fields: Vec<String> = line.columns.iter().map(|field| escape(field)).collect();
+            // indent names inside groups; leading spaces would be trimmed by renderers
+            fields[1] = format!("{}{}", "&nbsp;&nbsp;".repeat(line.depth), fields[1]);
+            let _ = writeln!(output, "| {} |", fields.join(" | "));
+        }
+    }
+    output
+}
+
+fn render_html(title: &str, sections: &[SheetSection]) -> String {
+    fn escape(field: &str) -> String {
+        field
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\n', "<br>")
+    }
+
+    let title = escape(title);
+    let mut output = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n\
+         body {{ font-family: sans-serif; font-size: 10pt; }}\n\
+         table {{ border-collapse: collapse; width: 100%; }}\n\
+         th, td {{ border: 1px solid #888; padding: 2px 6px; text-align: left; vertical-align: top; }}\n\
+         thead {{ display: table-header-group; }}\n\
+         tr {{ page-break-inside: avoid; }}\n\
+         .group {{ font-weight: bold; }}\n\
+         </style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        title, title
+    );
+    for section in sections {
+        if sections.len() > 1 {
+            let _ = writeln!(output, "<h2>{}</h2>", escape(section.name));
+        }
+        output.push_str("<table>\n<thead>\n<tr>");
+        for column in &COLUMNS[..COLUMNS.len() - 1] {
+            let _ = write!(output, "<th>{}</th>", column);
+        }
+        output.push_str("</tr>\n</thead>\n<tbody>\n");
+        for line in &section.lines {
+            if line.columns[2] == "Group" {
+                output.push_str("<tr class=\"group\">");
+            } else {
+                output.push_str("<tr>");
+            }
+            for (index, field) in line.columns.iter().enumerate() {
+                if index == 1 && line.depth > 0 {
+                    let _ = write!(
+                        output,
+                        "<td style=\"padding-left: {}em\">{}</td>",
+                        line.depth as f32 * 1.5 + 0.4,
+                        escape(field)
+                    );
+                } else {
+                    let _ = write!(output, "<td>{}</td>", escape(field));
+                }
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str("</tbody>\n</table>\n");
+    }
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use crate::{
+        manager::CueSheetFormat,
+        model::{
+            ShowModel,
+            cue::{
+                Cue, CueChain, CueParam, NamedCueList, Uuid, WaitCueParam,
+                audio::{AudioCueParam, FadeParam},
+            },
+        },
+    };
+
+    use super::render;
+
+    fn model() -> ShowModel {
+        let audio_id = Uuid::new_v4();
+        let wait_id = Uuid::new_v4();
+        let mut model = ShowModel {
+            name: "Rehearsal".into(),
+            ..Default::default()
+        };
+        model.cue_list.root_ids = vec![audio_id, wait_id];
+        model.cue_list.cues.insert(
+            audio_id,
+            Cue {
+                id: audio_id,
+                number: "1".into(),
+                name: None,
+                notes: "Cue on \"go\", then fade".into(),
+                color: Default::default(),
+                armed: true,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::AfterComplete {
+                    target_id: None,
+                    post_wait: 1.5,
+                },
+                parent_id: None,
+                params: CueParam::Audio(AudioCueParam {
+                    target: PathBuf::from("audio/overture.wav"),
+                    start_time: Some(10.0),
+                    fade_in_param: Some(FadeParam::default()),
+                    end_time: None,
+                    fade_out_param: None,
+                    volume: Default::default(),
+                    pan: 0.0,
+                    repeat: false,
+                    sound_type: Default::default(),
+                    envelope: vec![],
+                    original_path: None,
+                }),
+            },
+        );
+        model.cue_list.cues.insert(
+            wait_id,
+            Cue {
+                id: wait_id,
+                number: "2".into(),
+                name: Some("Hold | blackout".into()),
+                notes: String::new(),
+                color: Default::default(),
+                armed: true,
+                pre_wait: 2.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Wait(WaitCueParam { duration: 75.0 }),
+            },
+        );
+        model
+    }
+
+    #[test]
+    fn render_csv_sheet() {
+        let durations = HashMap::from([(PathBuf::from("audio/overture.wav"), 70.0)]);
+        let csv = render(&model(), &durations, CueSheetFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "1,overture,Audio,audio/overture.wav,1:00.00,In 0:03.00,Auto-follow +0:01.50,,\"Cue on \"\"go\"\", then fade\",0,Main"
+        );
+        assert_eq!(
+            lines[2],
+            "2,Hold | blackout,Wait,,1:15.00,,,0:02.00,,0,Main"
+        );
+    }
+
+    #[test]
+    fn render_markdown_escapes_pipes() {
+        let markdown = render(&model(), &HashMap::new(), CueSheetFormat::Markdown);
+        assert!(markdown.starts_with("# Rehearsal\n"));
+        assert!(markdown.contains("| 2 | Hold \\| blackout | Wait |"));
+        assert!(!markdown.contains("## "));
+    }
+
+    #[test]
+    fn render_named_lists_as_sections() {
+        let mut model = model();
+        let sfx_id = Uuid::new_v4();
+        model.cue_list.lists.push(NamedCueList {
+            id: Uuid::new_v4(),
+            name: "Sound FX".into(),
+            root_ids: vec![sfx_id],
+        });
+        model.cue_list.cues.insert(
+            sfx_id,
+            Cue {
+                id: sfx_id,
+                number: "S1".into(),
+                name: Some("Thunder".into()),
+                notes: String::new(),
+                color: Default::default(),
+                armed: true,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Wait(WaitCueParam { duration: 3.0 }),
+            },
+        );
+
+        let csv = render(&model, &HashMap::new(), CueSheetFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[3], "S1,Thunder,Wait,,0:03.00,,,,,0,Sound FX");
+
+        let markdown = render(&model, &HashMap::new(), CueSheetFormat::Markdown);
+        let main = markdown.find("## Main").unwrap();
+        let sfx = markdown.find("## Sound FX").unwrap();
+        assert!(main < markdown.find("| 2 | Hold").unwrap());
+        assert!(sfx > markdown.find("| 2 | Hold").unwrap());
+        assert!(sfx < markdown.find("| S1 | Thunder |").unwrap());
+
+        let html = render(&model, &HashMap::new(), CueSheetFormat::Html);
+        assert_eq!(html.matches("<table>").count(), 2);
+        assert!(html.contains("<h2>Sound FX</h2>"));
+    }
+}
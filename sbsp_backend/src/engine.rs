@@ -11,6 +11,7 @@ pub enum EngineEvent {
     Audio(AudioEngineEvent),
     Wait(WaitEvent),
     PreWait(WaitEvent),
+    PostWait(WaitEvent),
     Fade(WaitEvent),
 }
 
@@ -6,6 +6,7 @@ mod command;
 mod event;
 pub mod level_meter;
 mod lowcost_skip;
+mod master_volume;
 mod mono;
 mod static_source;
 
@@ -40,12 +41,12 @@ use super::EngineEvent;
 use crate::{
     BackendAudioSettings, BackendSettings,
     action::AudioAction,
-    controller::state::AudioStateParam,
     engine::audio_engine::{
         audio_source::{
             AudioPlaybackState, AudioSource, AudioSourceHandle, AudioSourceSettings, ChannelMapping,
         },
         level_meter::{LevelMeter, SharedLevel},
+        master_volume::{MasterVolume, MasterVolumeHandle},
         static_source::StaticSource,
     },
     model::{
@@ -59,6 +60,7 @@ const NANOS_PER_SEC: u64 = 1_000_000_000;
 struct AudioOutput {
     _sink: MixerDeviceSink,
     mixer: Mixer,
+    master_volume: MasterVolumeHandle,
 }
 
 impl AudioOutput {
@@ -85,6 +87,7 @@ struct PlayingSound {
 pub struct AudioEngine {
     output: Option<AudioOutput>,
     is_mono: Arc<AtomicBool>,
+    master_volume: Decibels,
     show_settings: ShowAudioSettings,
     backend_settings: BackendAudioSettings,
     command_rx: mpsc::Receiver<AudioCommand>,
@@ -115,6 +118,7 @@ impl AudioEngine {
         let (main_mixer, mixer_source) = rodio::mixer::mixer(channel_count, sample_rate);
         main_mixer.add(Zero::new(channel_count, sample_rate));
 
+        let (mixer_source, master_volume) = MasterVolume::new(mixer_source, Decibels::IDENTITY);
         let main_source = mono::Mono::new(mixer_source, is_mono.clone());
 
         sink.mixer().add(main_source);
@@ -122,11 +126,13 @@ impl AudioEngine {
         let output = AudioOutput {
             _sink: sink,
             mixer: main_mixer,
+            master_volume,
         };
 
         Ok(Self {
             output: Some(output),
             is_mono,
+            master_volume: Decibels::IDENTITY,
             show_settings,
             backend_settings,
             command_rx,
@@ -157,6 +163,7 @@ impl AudioEngine {
         let (main_mixer, mixer_source) = rodio::mixer::mixer(channel_count, sample_rate);
         main_mixer.add(Zero::new(channel_count, sample_rate));
 
+        let (mixer_source, master_volume) = MasterVolume::new(mixer_source, Decibels::IDENTITY);
         let main_source = LevelMeter::new(
             mono::Mono::new(mixer_source, is_mono.clone()),
             shared_level.clone(),
@@ -166,12 +173,14 @@ impl AudioEngine {
         let output = AudioOutput {
             _sink: sink,
             mixer: main_mixer,
+            master_volume,
         };
 
         Ok((
             Self {
                 output: Some(output),
                 is_mono,
+                master_volume: Decibels::IDENTITY,
                 show_settings,
                 backend_settings,
                 command_rx,
@@ -199,6 +208,7 @@ impl AudioEngine {
         let (main_mixer, mixer_source) = rodio::mixer::mixer(channel_count, sample_rate);
         main_mixer.add(Zero::new(channel_count, sample_rate));
 
+        let (mixer_source, master_volume) = MasterVolume::new(mixer_source, self.master_volume);
         if let Some(shared_level) = &self.level_meter {
             let main_source = LevelMeter::new(
                 mono::Mono::new(mixer_source, is_mono.clone()),
@@ -215,6 +225,7 @@ impl AudioEngine {
         self.output = Some(AudioOutput {
             _sink: sink,
             mixer: main_mixer,
+            master_volume,
         });
         Ok(())
     }
@@ -297,12 +308,20 @@ impl AudioEngine {
                         }
                         AudioCommand::Pause { id } => self.handle_pause(id).await,
                         AudioCommand::Resume { id } => self.handle_resume(id).await,
-                        AudioCommand::SoftStop { id } => self.handle_stop(id, false).await,
-                        AudioCommand::HardStop { id } => self.handle_stop(id, true).await,
+                        AudioCommand::SoftStop { id } => self.handle_stop(id, |handle| handle.stop(false)).await,
+                        AudioCommand::FadeStop { id, fade_param } => self.handle_stop(id, |handle| handle.fade_stop(fade_param)).await,
+                        AudioCommand::HardStop { id } => self.handle_stop(id, |handle| handle.stop(true)).await,
                         AudioCommand::SeekTo { id, position } => self.handle_seek_to(id, position).await,
                         AudioCommand::SeekBy { id, amount } => self.handle_seek_by(id, amount).await,
                         AudioCommand::FadeVolume { id, volume, fade_param } => self.handle_fade_volume(id, volume, fade_param).await,
                         AudioCommand::PerformAction { id, action } => self.handle_action(id, action).await,
+                        AudioCommand::SetMasterVolume { volume, fade_param } => {
+                            self.master_volume = volume;
+                            if let Some(output) = self.output.as_mut() {
+                                output.master_volume.set_volume(volume, fade_param);
+                            }
+                            Ok(())
+                        },
                         AudioCommand::Reconfigure(settings) => {
                             if settings.mono_output != self.show_settings.mono_output {
                                 self.is_mono.store(settings.mono_output, Ordering::Release);
@@ -482,10 +501,7 @@ impl AudioEngine {
                 instance_id: id,
                 position: handle.position(),
                 duration: handle.duration,
-                initial_params: AudioStateParam {
-                    repeating: data.repeat,
-                    volume: data.volume,
-                },
+                initial_params: handle.state_param(),
             }))
             .await?;
 
@@ -528,12 +544,16 @@ impl AudioEngine {
         }
     }
 
-    async fn handle_stop(&mut self, id: Uuid, is_hard: bool) -> Result<()> {
+    async fn handle_stop(
+        &mut self,
+        id: Uuid,
+        stop: impl FnOnce(&mut AudioSourceHandle),
+    ) -> Result<()> {
         if let Some(playing_sound) = self.playing_sounds.get_mut(&id) {
-            playing_sound.handle.stop(is_hard);
+            stop(&mut playing_sound.handle);
             Ok(())
         } else if let Some(mut loaded_sound) = self.loaded_sounds.remove(&id) {
-            loaded_sound.stop(is_hard);
+            stop(&mut loaded_sound);
             self.event_tx
                 .send(EngineEvent::Audio(AudioEngineEvent::Stopped {
                     instance_id: id,
@@ -634,68 +654,37 @@ impl AudioEngine {
     }
 
     async fn handle_action(&mut self, id: Uuid, action: AudioAction) -> Result<()> {
+        let handle = if let Some(playing_sound) = self.playing_sounds.get_mut(&id) {
+            &mut playing_sound.handle
+        } else if let Some(loaded_handle) = self.loaded_sounds.get_mut(&id) {
+            loaded_handle
+        } else {
+            anyhow::bail!("unknown instance_id. id={}", id);
+        };
+
         match action {
             AudioAction::ToggleRepeat => {
-                if let Some(playing_sound) = self.playing_sounds.get_mut(&id) {
-                    let repeat_state = playing_sound.handle.is_repeating();
-                    playing_sound.handle.set_repeat(!repeat_state);
-                    let volume = playing_sound.handle.get_volume();
-                    self.event_tx
-                        .send(EngineEvent::Audio(AudioEngineEvent::StateParamUpdated {
-                            instance_id: id,
-                            params: AudioStateParam {
-                                repeating: !repeat_state,
-                                volume,
-                            },
-                        }))
-                        .await?;
-                } else if let Some(loaded_handle) = self.loaded_sounds.get_mut(&id) {
-                    let repeat_state = loaded_handle.is_repeating();
-                    loaded_handle.set_repeat(!repeat_state);
-                    let volume = loaded_handle.get_volume();
-                    self.event_tx
-                        .send(EngineEvent::Audio(AudioEngineEvent::StateParamUpdated {
-                            instance_id: id,
-                            params: AudioStateParam {
-                                repeating: !repeat_state,
-                                volume,
-                            },
-                        }))
-                        .await?;
-                } else {
-                    anyhow::bail!("unknown instance_id. id={}", id);
-                }
-            }
-            AudioAction::SetVolume(volume) => {
-                if let Some(playing_sound) = self.playing_sounds.get_mut(&id) {
-                    let repeat_state = playing_sound.handle.is_repeating();
-                    playing_sound.handle.set_volume(volume);
-                    self.event_tx
-                        .send(EngineEvent::Audio(AudioEngineEvent::StateParamUpdated {
-                            instance_id: id,
-                            params: AudioStateParam {
-                                repeating: repeat_state,
-                                volume,
-                            },
-                        }))
-                        .await?;
-                } else if let Some(loaded_handle) = self.loaded_sounds.get_mut(&id) {
-                    let repeat_state = loaded_handle.is_repeating();
-                    loaded_handle.set_volume(volume);
-                    self.event_tx
-                        .send(EngineEvent::Audio(AudioEngineEvent::StateParamUpdated {
-                            instance_id: id,
-                            params: AudioStateParam {
-                                repeating: repeat_state,
-                                volume,
-                            },
-                        }))
-                        .await?;
-                } else {
-                    anyhow::bail!("unknown instance_id. id={}", id);
-                }
+                let repeat_state = handle.is_repeating();
+                handle.set_repeat(!repeat_state);
             }
+            AudioAction::SetVolume(volume) => handle.set_volume(volume),
+            AudioAction::SetPan(pan) => handle.set_pan(pan),
+            AudioAction::SetRate(rate) => handle.set_rate(rate),
+            AudioAction::SetStartEnd {
+                start_time,
+                end_time,
+            } => handle.set_start_end(start_time, end_time),
+            AudioAction::SetFadeOut(fade_param) => handle.set_fade_out(fade_param),
+            AudioAction::ReplaceEnvelope(envelope) => handle.replace_envelope(envelope),
         }
+
+        let params = handle.state_param();
+        self.event_tx
+            .send(EngineEvent::Audio(AudioEngineEvent::StateParamUpdated {
+                instance_id: id,
+                params,
+            }))
+            .await?;
         Ok(())
     }
 }
@@ -2,7 +2,7 @@
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
 mod envelope;
-mod volume;
+pub(super) mod volume;
 
 use std::{
     f32::consts::SQRT_2,
@@ -23,6 +23,7 @@ use rtrb::{Consumer, Producer, RingBuffer};
 use tokio::sync::oneshot;
 
 use crate::{
+    controller::state::AudioStateParam,
     engine::audio_engine::{AudioCommandData, audio_source::envelope::Envelope},
     model::cue::audio::{Decibels, Easing, EnvelopeSegment, FadeParam},
 };
@@ -31,8 +32,10 @@ use super::lowcost_skip::SkipDuration;
 use volume::Volume;
 
 const MAX_CHANNELS: u16 = 128;
+const MIN_RATE: f64 = 0.1;
+const MAX_RATE: f64 = 4.0;
 
-const DEFAULT_FADE_PARAM: FadeParam = FadeParam {
+pub(super) const DEFAULT_FADE_PARAM: FadeParam = FadeParam {
     duration: 0.001,
     easing: Easing::Linear,
 };
@@ -114,7 +117,10 @@ enum AudioSourceControlCommand {
     Start,
     Pause,
     Resume,
-    SoftStop,
+    /// Fades out with `fade_param`, or the cue's own fade-out when `None`.
+    SoftStop {
+        fade_param: Option<FadeParam>,
+    },
     HardStop,
     Seek {
         position: f64,
@@ -124,6 +130,14 @@ enum AudioSourceControlCommand {
         volume: Decibels,
         fade_param: FadeParam,
     },
+    SetChannelMapping(ChannelMapping),
+    SetRate(f64),
+    SetRange {
+        start_time: Option<f64>,
+        end_time: Option<f64>,
+    },
+    SetFadeOut(FadeParam),
+    SetEnvelope(Envelope),
 }
 
 struct AudioSourceShared {
@@ -148,6 +162,14 @@ pub struct AudioSourceHandle {
     pub duration: f64,
     volume: Decibels,
     fade_volume: Decibels,
+    trim: Decibels,
+    pan: f32,
+    rate: f64,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    fade_out_param: Option<FadeParam>,
+    envelope: Vec<EnvelopeSegment>,
+    media_duration: Option<f64>,
 }
 
 impl AudioSourceHandle {
@@ -167,8 +189,18 @@ impl AudioSourceHandle {
         self.shared.repeat.load(Ordering::Acquire)
     }
 
-    pub fn get_volume(&self) -> Decibels {
-        self.volume
+    /// Live parameters of the sound, as reported to the controller.
+    pub fn state_param(&self) -> AudioStateParam {
+        AudioStateParam {
+            repeating: self.is_repeating(),
+            volume: self.volume,
+            pan: self.pan,
+            rate: self.rate,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            fade_out_param: self.fade_out_param,
+            envelope: self.envelope.clone(),
+        }
     }
 
     pub fn start(&mut self) {
@@ -195,8 +227,21 @@ impl AudioSourceHandle {
         if is_hard {
             let _ = self.control.push(AudioSourceControlCommand::HardStop);
         } else {
-            let _ = self.control.push(AudioSourceControlCommand::SoftStop);
+            let _ = self
+                .control
+                .push(AudioSourceControlCommand::SoftStop { fade_param: None });
+        }
+    }
+
+    /// Soft stop fading out over `fade_param` instead of the cue's fade-out.
+    pub fn fade_stop(&mut self, fade_param: FadeParam) {
+        let state = self.state();
+        if state == AudioPlaybackState::Stopped || state == AudioPlaybackState::Completed {
+            return;
         }
+        let _ = self.control.push(AudioSourceControlCommand::SoftStop {
+            fade_param: Some(fade_param),
+        });
     }
 
     pub async fn seek_to(&mut self, position: f64) -> Result<f64, anyhow::Error> {
@@ -235,7 +280,7 @@ impl AudioSourceHandle {
         self.volume = volume;
 
         let _ = self.control.push(AudioSourceControlCommand::SetVolume {
-            volume: self.volume + self.fade_volume,
+            volume: self.volume + self.fade_volume + self.trim,
             fade_param: DEFAULT_FADE_PARAM,
         });
     }
@@ -244,10 +289,67 @@ impl AudioSourceHandle {
         self.fade_volume = volume;
 
         let _ = self.control.push(AudioSourceControlCommand::SetVolume {
-            volume: self.volume + self.fade_volume,
+            volume: self.volume + self.fade_volume + self.trim,
             fade_param,
         });
     }
+
+    pub fn set_pan(&mut self, pan: f32) {
+        self.pan = pan.clamp(-1.0, 1.0);
+
+        let _ = self
+            .control
+            .push(AudioSourceControlCommand::SetChannelMapping(
+                ChannelMapping::from_pan(self.pan),
+            ));
+    }
+
+    pub fn set_rate(&mut self, rate: f64) {
+        self.rate = rate.clamp(MIN_RATE, MAX_RATE);
+
+        let _ = self
+            .control
+            .push(AudioSourceControlCommand::SetRate(self.rate));
+    }
+
+    /// Moves the start and end points. The playhead keeps its place in the file
+    /// unless it falls outside the new range.
+    pub fn set_start_end(&mut self, start_time: Option<f64>, end_time: Option<f64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+
+        let end = match (end_time, self.media_duration) {
+            (Some(end), Some(media_duration)) => end.min(media_duration),
+            (Some(end), None) => end,
+            (None, Some(media_duration)) => media_duration,
+            (None, None) => 0.0,
+        };
+        self.duration = (end - start_time.unwrap_or(0.0)).max(0.0);
+
+        let _ = self.control.push(AudioSourceControlCommand::SetRange {
+            start_time,
+            end_time,
+        });
+    }
+
+    pub fn set_fade_out(&mut self, fade_param: Option<FadeParam>) {
+        self.fade_out_param = fade_param;
+
+        let _ = self.control.push(AudioSourceControlCommand::SetFadeOut(
+            fade_param.unwrap_or(DEFAULT_FADE_PARAM),
+        ));
+    }
+
+    pub fn replace_envelope(&mut self, envelope: Vec<EnvelopeSegment>) {
+        self.envelope = envelope;
+
+        let _ = self
+            .control
+            .push(AudioSourceControlCommand::SetEnvelope(Envelope::new(
+                self.envelope.clone(),
+                self.duration,
+            )));
+    }
 }
 
 pub struct ChannelMapping {
@@ -316,6 +418,8 @@ pub struct AudioSourceSettings {
     pub fadeout_param: Option<FadeParam>,
     pub fadein_param: Option<FadeParam>,
     pub volume: Decibels,
+    pub trim: Decibels,
+    pub pan: f32,
     pub channel_mapping: ChannelMapping,
     pub envelope: Vec<EnvelopeSegment>,
 }
@@ -329,6 +433,8 @@ impl From<&AudioCommandData> for AudioSourceSettings {
             fadeout_param: value.fade_out_param,
             fadein_param: value.fade_in_param,
             volume: value.volume,
+            trim: value.trim,
+            pan: value.pan,
             channel_mapping: ChannelMapping::auto_map(2, 2),
             envelope: value.envelope.clone(),
         }
@@ -340,6 +446,40 @@ enum InnerSource<I> {
     Skipped(SkipDuration<I>),
     Taken(TakeDuration<I>),
     Ranged(SkipDuration<TakeDuration<I>>),
+    /// Only seen while the range is being rebuilt.
+    Detached,
+}
+
+impl<I> InnerSource<I>
+where
+    I: Source,
+{
+    fn new(input: I, start_time: Option<f64>, end_time: Option<f64>) -> Self {
+        match (start_time, end_time) {
+            (None, None) => InnerSource::Original(input),
+            (None, Some(end)) => {
+                InnerSource::Taken(input.take_duration(Duration::from_secs_f64(end)))
+            }
+            (Some(start), None) => {
+                InnerSource::Skipped(SkipDuration::new(input, Duration::from_secs_f64(start)))
+            }
+            (Some(start), Some(end)) => InnerSource::Ranged(SkipDuration::new(
+                input.take_duration(Duration::from_secs_f64(end)),
+                Duration::from_secs_f64(start),
+            )),
+        }
+    }
+
+    fn set_range(&mut self, start_time: Option<f64>, end_time: Option<f64>) {
+        let input = match std::mem::replace(self, InnerSource::Detached) {
+            InnerSource::Original(inner) => inner,
+            InnerSource::Skipped(skip_duration) => skip_duration.into_inner(),
+            InnerSource::Taken(take_duration) => take_duration.into_inner(),
+            InnerSource::Ranged(skip_duration) => skip_duration.into_inner().into_inner(),
+            InnerSource::Detached => unreachable!(),
+        };
+        *self = InnerSource::new(input, start_time, end_time);
+    }
 }
 
 impl<I> Deref for InnerSource<I>
@@ -354,6 +494,7 @@ where
             InnerSource::Skipped(skip_duration) => skip_duration as &dyn Source,
             InnerSource::Taken(take_duration) => take_duration as &dyn Source,
             InnerSource::Ranged(skip_duration) => skip_duration as &dyn Source,
+            InnerSource::Detached => unreachable!(),
         }
     }
 }
@@ -368,6 +509,7 @@ where
             InnerSource::Skipped(skip_duration) => skip_duration as &mut dyn Source,
             InnerSource::Taken(take_duration) => take_duration as &mut dyn Source,
             InnerSource::Ranged(skip_duration) => skip_duration as &mut dyn Source,
+            InnerSource::Detached => unreachable!(),
         }
     }
 }
@@ -391,6 +533,13 @@ where
     control_volume: Volume,
     volume: Volume,
     envelope: Envelope,
+    rate: f64,
+    // Playback reads between `current_frame` and `next_frame` so the rate can be non-integer.
+    frame_fraction: f64,
+    current_frame: [Sample; MAX_CHANNELS as usize],
+    next_frame: [Sample; MAX_CHANNELS as usize],
+    has_next_frame: bool,
+    primed: bool,
     output_buffer: Box<[Sample]>,
 }
 
@@ -413,23 +562,20 @@ where
             Volume::new(Decibels::IDENTITY)
         };
         let volume_db = settings.volume;
+        let trim = settings.trim;
         let envelope = settings.envelope.clone();
         let output_buffer = vec![0.0; settings.channel_mapping.output_channels].into_boxed_slice();
         let update_interval = Self::calculate_interval(&sample_rate);
 
-        let input = match (settings.start_time, settings.end_time) {
-            (None, None) => InnerSource::Original(input),
-            (None, Some(end)) => {
-                InnerSource::Taken(input.take_duration(Duration::from_secs_f64(end)))
-            }
-            (Some(start), None) => {
-                InnerSource::Skipped(SkipDuration::new(input, Duration::from_secs_f64(start)))
-            }
-            (Some(start), Some(end)) => InnerSource::Ranged(SkipDuration::new(
-                input.take_duration(Duration::from_secs_f64(end)),
-                Duration::from_secs_f64(start),
-            )),
-        };
+        let pan = settings.pan;
+        let start_time = settings.start_time;
+        let end_time = settings.end_time;
+        let fade_out_param = settings.fadeout_param;
+        let media_duration = input
+            .total_duration()
+            .map(|duration| duration.as_secs_f64());
+
+        let input = InnerSource::new(input, start_time, end_time);
 
         let duration = input
             .total_duration()
@@ -452,8 +598,14 @@ where
                 playing_frames_counted: 0,
                 update_interval,
                 control_volume,
-                volume: Volume::new(volume_db),
-                envelope: Envelope::new(envelope, duration),
+                volume: Volume::new(volume_db + trim),
+                envelope: Envelope::new(envelope.clone(), duration),
+                rate: 1.0,
+                frame_fraction: 0.0,
+                current_frame: [0.0; MAX_CHANNELS as usize],
+                next_frame: [0.0; MAX_CHANNELS as usize],
+                has_next_frame: false,
+                primed: false,
             },
             AudioSourceHandle {
                 shared,
@@ -461,6 +613,14 @@ where
                 duration,
                 volume: volume_db,
                 fade_volume: Decibels::IDENTITY,
+                trim,
+                pan,
+                rate: 1.0,
+                start_time,
+                end_time,
+                fade_out_param,
+                envelope,
+                media_duration,
             },
         )
     }
@@ -468,6 +628,82 @@ where
     fn calculate_interval(sample_rate: &NonZero<u32>) -> usize {
         sample_rate.get() as usize / 1_000
     }
+
+    fn playing_position(&self) -> f64 {
+        self.offset_position
+            + self.playing_frames_counted as f64 / self.current_span_sample_rate.get() as f64
+    }
+
+    fn read_frame(&mut self) -> bool {
+        for i in 0..self.current_span_channels.get().min(MAX_CHANNELS) {
+            if let Some(sample) = self.input.next() {
+                self.next_frame[i as usize] = sample;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Makes `current_frame` the frame just read and reads the one after it.
+    /// Only the channels of the current span are touched.
+    fn shift_frame(&mut self) {
+        std::mem::swap(&mut self.current_frame, &mut self.next_frame);
+        self.has_next_frame = self.read_frame();
+        if !self.has_next_frame {
+            // hold the last frame so interpolation towards the end stays flat
+            let channels = self.current_span_channels.get().min(MAX_CHANNELS) as usize;
+            self.next_frame[..channels].copy_from_slice(&self.current_frame[..channels]);
+        }
+    }
+
+    /// Moves the read point forward by `rate` input frames. Returns false once the input is exhausted.
+    fn advance_frame(&mut self) -> bool {
+        if !self.primed {
+            self.primed = true;
+            self.frame_fraction = 0.0;
+            if !self.read_frame() {
+                return false;
+            }
+            self.shift_frame();
+            return true;
+        }
+
+        self.frame_fraction += self.rate;
+        while self.frame_fraction >= 1.0 {
+            self.frame_fraction -= 1.0;
+            if !self.has_next_frame {
+                return false;
+            }
+            self.playing_frames_counted += 1;
+            self.shift_frame();
+        }
+        true
+    }
+
+    fn set_range(&mut self, start_time: Option<f64>, end_time: Option<f64>) {
+        // resume from the frame after the one already played
+        let next_frame = if self.primed {
+            1.0 / self.current_span_sample_rate.get() as f64
+        } else {
+            0.0
+        };
+        let file_position =
+            self.playing_position() + next_frame + self.settings.start_time.unwrap_or(0.0);
+
+        self.input.set_range(start_time, end_time);
+        self.settings.start_time = start_time;
+        self.settings.end_time = end_time;
+
+        let duration = self
+            .input
+            .total_duration()
+            .map_or(0.0, |duration| duration.as_secs_f64());
+        self.envelope.set_duration(duration);
+
+        let position = (file_position - start_time.unwrap_or(0.0)).clamp(0.0, duration);
+        let _ = self.try_seek(Duration::from_secs_f64(position));
+    }
 }
 
 impl<I> Iterator for AudioSource<I>
@@ -488,13 +724,9 @@ where
                 self.frames_counted = 0;
 
                 if state.is_advancing() {
-                    self.shared.position.store(
-                        (self.offset_position
-                            + self.playing_frames_counted as f64
-                                / self.current_span_sample_rate.get() as f64)
-                            .to_bits(),
-                        Ordering::Release,
-                    );
+                    self.shared
+                        .position
+                        .store(self.playing_position().to_bits(), Ordering::Release);
                 }
 
                 // Command Handling
@@ -522,13 +754,15 @@ where
                                     .set_volume(Decibels::IDENTITY, DEFAULT_FADE_PARAM);
                             }
                         }
-                        AudioSourceControlCommand::SoftStop => match state {
+                        AudioSourceControlCommand::SoftStop { fade_param } => match state {
                             AudioPlaybackState::Playing
                             | AudioPlaybackState::Pausing
                             | AudioPlaybackState::Resuming => {
                                 state = AudioPlaybackState::SoftStopping;
-                                self.control_volume
-                                    .set_volume(Decibels::MUTE, self.fadeout_param);
+                                self.control_volume.set_volume(
+                                    Decibels::MUTE,
+                                    fade_param.unwrap_or(self.fadeout_param),
+                                );
                             }
                             AudioPlaybackState::Loaded | AudioPlaybackState::Paused => {
                                 state = AudioPlaybackState::Stopped;
@@ -558,6 +792,25 @@ where
                         AudioSourceControlCommand::SetVolume { volume, fade_param } => {
                             self.volume.set_volume(volume, fade_param);
                         }
+                        AudioSourceControlCommand::SetChannelMapping(channel_mapping) => {
+                            self.settings.channel_mapping = channel_mapping;
+                        }
+                        AudioSourceControlCommand::SetRate(rate) => {
+                            self.rate = rate;
+                        }
+                        AudioSourceControlCommand::SetRange {
+                            start_time,
+                            end_time,
+                        } => {
+                            self.set_range(start_time, end_time);
+                        }
+                        AudioSourceControlCommand::SetFadeOut(fade_param) => {
+                            self.fadeout_param = fade_param;
+                        }
+                        AudioSourceControlCommand::SetEnvelope(mut envelope) => {
+                            envelope.seek(self.playing_position());
+                            self.envelope = envelope;
+                        }
                     }
 
                     // State publish
@@ -585,34 +838,28 @@ where
             self.volume.update(dt);
 
             if state.is_advancing() {
-                let factor = self.control_volume.volume
-                    + self.volume.volume
-                    + self.envelope.update(
-                        self.offset_position
-                            + self.playing_frames_counted as f64
-                                / self.current_span_sample_rate.get() as f64,
-                    );
-
-                let mut completed = false;
-                let mut inputs = [0.0; MAX_CHANNELS as usize];
-                for i in 0..self.current_span_channels.get().min(MAX_CHANNELS) {
-                    let sample = self.input.next();
-                    if let Some(s) = sample {
-                        inputs[i as usize] = s;
-                    } else {
-                        completed = true;
-                        break;
-                    }
-                }
+                if self.advance_frame() {
+                    let factor = self.control_volume.volume
+                        + self.volume.volume
+                        + self.envelope.update(self.playing_position());
+                    let fraction = self.frame_fraction as f32;
+                    // on a whole frame, as always at rate 1.0, the next frame adds nothing
+                    let interpolate = fraction != 0.0;
 
-                if !completed {
                     for out_n in 0..self.settings.channel_mapping.output_channels {
                         let mut out = 0.0;
-                        for (in_n, src) in inputs
+                        for (in_n, (current, next)) in self
+                            .current_frame
                             .iter()
+                            .zip(self.next_frame.iter())
                             .take(self.settings.channel_mapping.input_channels)
                             .enumerate()
                         {
+                            let src = if interpolate {
+                                current + (next - current) * fraction
+                            } else {
+                                *current
+                            };
                             out += self.settings.channel_mapping.get_factor(in_n, out_n) * src;
                         }
                         self.output_buffer[out_n] = out * factor.as_amplitude();
@@ -629,8 +876,6 @@ where
                     // State publish
                     self.shared.state.store(state as u8, Ordering::Release);
                 };
-
-                self.playing_frames_counted += 1;
             }
 
             self.frames_counted += 1;
@@ -683,6 +928,8 @@ where
                 .store(self.offset_position.to_bits(), Ordering::Release);
             self.envelope.seek(pos.as_secs_f64());
             self.playing_frames_counted = 0;
+            self.frame_fraction = 0.0;
+            self.primed = false;
             self.frames_counted = 0;
             self.current_channel = 0;
         }
@@ -705,3 +952,75 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rodio::{buffer::SamplesBuffer, math::nz};
+
+    use super::{AudioSource, AudioSourceSettings, ChannelMapping};
+    use crate::model::cue::audio::Decibels;
+
+    // 100 stereo frames at 1kHz, each sample holding its frame index.
+    fn make_source() -> (AudioSource<SamplesBuffer>, super::AudioSourceHandle) {
+        let data = (0..100)
+            .flat_map(|i| [i as f32, i as f32])
+            .collect::<Vec<_>>();
+        AudioSource::new(
+            SamplesBuffer::new(nz!(2), nz!(1000), data),
+            AudioSourceSettings {
+                repeat: false,
+                start_time: None,
+                end_time: None,
+                fadeout_param: None,
+                fadein_param: None,
+                volume: Decibels::IDENTITY,
+                trim: Decibels::IDENTITY,
+                pan: 0.0,
+                channel_mapping: ChannelMapping::from_pan(0.0),
+                envelope: Vec::new(),
+            },
+        )
+    }
+
+    fn frames(source: &mut AudioSource<SamplesBuffer>, count: Option<usize>) -> Vec<f32> {
+        let mut frames = Vec::new();
+        while count.is_none_or(|count| frames.len() < count) {
+            let Some(left) = source.next() else {
+                break;
+            };
+            source.next();
+            frames.push(left.round());
+        }
+        frames
+    }
+
+    #[test]
+    fn rate_changes_playback_speed() {
+        let (mut source, mut handle) = make_source();
+        handle.start();
+        handle.set_rate(2.0);
+
+        let played = frames(&mut source, None);
+        // the rate is applied one control tick after the start
+        assert_eq!(played[..4], [0.0, 2.0, 4.0, 6.0]);
+        assert_eq!(played.len(), 50);
+        assert_eq!(handle.state_param().rate, 2.0);
+    }
+
+    #[test]
+    fn start_end_keeps_playhead_inside_range() {
+        let (mut source, mut handle) = make_source();
+        handle.start();
+
+        let played = frames(&mut source, Some(30));
+        assert_eq!(played.last(), Some(&29.0));
+
+        handle.set_start_end(Some(0.02), Some(0.05));
+        assert!((handle.duration - 0.03).abs() < 1e-9);
+
+        let played = frames(&mut source, None);
+        assert_eq!(played.first(), Some(&30.0));
+        // stops at the new end, give or take a frame of rounding
+        assert!((48.0..50.0).contains(played.last().unwrap()));
+    }
+}
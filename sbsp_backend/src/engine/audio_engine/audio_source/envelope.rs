@@ -61,6 +61,10 @@ impl Envelope {
         seg.volume
     }
 
+    pub fn set_duration(&mut self, duration: f64) {
+        self.duration = duration;
+    }
+
     pub fn seek(&mut self, pos: f64) {
         if self.segments.is_empty() {
             return;
@@ -32,6 +32,11 @@ pub enum AudioCommand {
     SoftStop {
         id: Uuid,
     },
+    /// Soft stop fading out over `fade_param` instead of the cue's fade-out.
+    FadeStop {
+        id: Uuid,
+        fade_param: FadeParam,
+    },
     HardStop {
         id: Uuid,
     },
@@ -52,6 +57,11 @@ pub enum AudioCommand {
         id: Uuid,
         action: AudioAction,
     },
+    /// Fades the master volume applied to the whole output.
+    SetMasterVolume {
+        volume: Decibels,
+        fade_param: Option<FadeParam>,
+    },
     Reconfigure(ShowAudioSettings),
 }
 
@@ -63,12 +73,13 @@ impl AudioCommand {
             AudioCommand::Pause { id } => *id,
             AudioCommand::Resume { id } => *id,
             AudioCommand::SoftStop { id } => *id,
+            AudioCommand::FadeStop { id, .. } => *id,
             AudioCommand::HardStop { id } => *id,
             AudioCommand::SeekTo { id, .. } => *id,
             AudioCommand::SeekBy { id, .. } => *id,
             AudioCommand::PerformAction { id, .. } => *id,
             AudioCommand::FadeVolume { id, .. } => *id,
-            AudioCommand::Reconfigure(_) => Uuid::nil(),
+            AudioCommand::SetMasterVolume { .. } | AudioCommand::Reconfigure(_) => Uuid::nil(),
         }
     }
 }
@@ -78,6 +89,8 @@ pub struct AudioCommandData {
     pub sound_type: SoundType,
     pub filepath: PathBuf,
     pub volume: Decibels,
+    /// Volume trim of the groups containing the cue, kept apart from `volume`.
+    pub trim: Decibels,
     pub pan: f32,
     pub start_time: Option<f64>,
     pub fade_in_param: Option<FadeParam>,
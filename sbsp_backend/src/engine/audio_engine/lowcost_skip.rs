@@ -31,6 +31,10 @@ where
         }
     }
 
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+
     fn do_skip_duration(input: &mut I, mut duration: Duration)
     where
         I: Source,
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::time::Duration;
+
+use rodio::{ChannelCount, Sample, SampleRate, Source, source::SeekError};
+use rtrb::{Consumer, Producer, RingBuffer};
+
+use super::audio_source::{DEFAULT_FADE_PARAM, volume::Volume};
+use crate::model::cue::audio::{Decibels, FadeParam};
+
+struct MasterVolumeCommand {
+    volume: Decibels,
+    fade_param: FadeParam,
+}
+
+pub struct MasterVolumeHandle {
+    control: Producer<MasterVolumeCommand>,
+}
+
+impl MasterVolumeHandle {
+    pub fn set_volume(&mut self, volume: Decibels, fade_param: Option<FadeParam>) {
+        let _ = self.control.push(MasterVolumeCommand {
+            volume,
+            fade_param: fade_param.unwrap_or(DEFAULT_FADE_PARAM),
+        });
+    }
+}
+
+// Applies the show's master volume to everything mixed into the output.
+pub struct MasterVolume<I>
+where
+    I: Source,
+{
+    input: I,
+    control: Consumer<MasterVolumeCommand>,
+    volume: Volume,
+    amplitude: f32,
+    current_channel: u16,
+}
+
+impl<I> MasterVolume<I>
+where
+    I: Source,
+{
+    pub fn new(input: I, volume: Decibels) -> (Self, MasterVolumeHandle) {
+        let (control_pr, control_co) = RingBuffer::new(8);
+        let channels = input.channels().get();
+        (
+            Self {
+                input,
+                control: control_co,
+                volume: Volume::new(volume),
+                amplitude: volume.as_amplitude(),
+                current_channel: channels,
+            },
+            MasterVolumeHandle {
+                control: control_pr,
+            },
+        )
+    }
+}
+
+impl<I> Iterator for MasterVolume<I>
+where
+    I: Source,
+{
+    type Item = Sample;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_channel >= self.input.channels().get() {
+            self.current_channel = 0;
+            if let Ok(command) = self.control.pop() {
+                self.volume.set_volume(command.volume, command.fade_param);
+            }
+            self.volume
+                .update(1.0 / self.input.sample_rate().get() as f64);
+            self.amplitude = self.volume.volume.as_amplitude();
+        }
+        self.current_channel += 1;
+        self.input.next().map(|sample| sample * self.amplitude)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I> Source for MasterVolume<I>
+where
+    I: Source,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        self.input.current_span_len()
+    }
+
+    #[inline]
+    fn channels(&self) -> ChannelCount {
+        self.input.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> SampleRate {
+        self.input.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.input.total_duration()
+    }
+
+    #[inline]
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.input.try_seek(pos)
+    }
+}
@@ -21,6 +21,7 @@ use super::EngineEvent;
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum WaitType {
     PreWait,
+    PostWait,
     Wait,
     FadeWait,
 }
@@ -219,7 +220,7 @@ impl WaitEngine {
 
                             if waiting_instance.status.eq(&WaitingStatus::Waiting) {
                                 waiting_instance.status = WaitingStatus::Completed;
-                                wait_event = WaitEvent::Completed { instance_id: instance_key.1 }
+                                wait_event = WaitEvent::Completed { instance_id: instance_key.1, overshoot: (elapsed - waiting_instance.remaining_duration).as_secs_f64() }
                             } else {
                                 continue;
                             }
@@ -248,6 +249,7 @@ impl WaitEngine {
     fn wrap_wait_event(wait_type: WaitType, wait_event: WaitEvent) -> EngineEvent {
         match wait_type {
             WaitType::PreWait => EngineEvent::PreWait(wait_event),
+            WaitType::PostWait => EngineEvent::PostWait(wait_event),
             WaitType::Wait => EngineEvent::Wait(wait_event),
             WaitType::FadeWait => EngineEvent::Fade(wait_event),
         }
@@ -37,6 +37,8 @@ pub enum WaitEvent {
     },
     Completed {
         instance_id: Uuid,
+        /// Seconds the completion was reported after the wait actually ran out.
+        overshoot: f64,
     },
 }
 
@@ -50,7 +52,7 @@ impl WaitEvent {
             WaitEvent::Resumed { instance_id } => *instance_id,
             WaitEvent::Seeked { instance_id, .. } => *instance_id,
             WaitEvent::Stopped { instance_id } => *instance_id,
-            WaitEvent::Completed { instance_id } => *instance_id,
+            WaitEvent::Completed { instance_id, .. } => *instance_id,
         }
     }
 }
@@ -11,8 +11,18 @@ use crate::executor::ExecutorEvent;
 use crate::{
     asset_processor::{AssetData, AssetMetadata},
     controller::state::StateParam,
-    manager::project::ProjectType,
-    model::{ShowModel, cue::CueList, settings::ShowSettings},
+    manager::{
+        asset_import::{AssetImportEntry, AssetRelink, MissingAsset},
+        history::HistoryStatus,
+        project::ProjectType,
+    },
+    model::{
+        ShowModel,
+        cart::CartSlot,
+        cue::{CueList, audio::Decibels},
+        settings::ShowSettings,
+    },
+    preflight::PreflightWarning,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,7 +41,19 @@ pub enum BackendEvent {
     PlaybackCursorMoved {
         cue_id: Option<Uuid>,
     },
+    ListCursorMoved {
+        list_id: Uuid,
+        cue_id: Option<Uuid>,
+    },
     SyncState(SyncData),
+    MasterVolumeChanged {
+        volume: Decibels,
+    },
+    GoSuppressed {
+        list_id: Uuid,
+        cue_id: Uuid,
+        reason: GoSuppressedReason,
+    },
 
     // Model Events
     ShowModelLoaded {
@@ -48,6 +70,8 @@ pub enum BackendEvent {
     },
     CueRemoved {
         cue_ids: HashSet<Uuid>,
+        /// Remaining cues whose references to a removed cue were cleared.
+        affected_cue_ids: HashSet<Uuid>,
     },
     CueListUpdated {
         #[serde(flatten)]
@@ -59,6 +83,42 @@ pub enum BackendEvent {
     ModelNameUpdated {
         new_name: String,
     },
+    CartUpdated {
+        cart: Vec<Option<CartSlot>>,
+    },
+    RecoveryAvailable {
+        path: PathBuf,
+        project_path: Option<PathBuf>,
+    },
+    HistoryUpdated {
+        #[serde(flatten)]
+        status: HistoryStatus,
+    },
+    AssetsImported {
+        entries: Vec<AssetImportEntry>,
+    },
+    AssetsMissing {
+        missing: Vec<MissingAsset>,
+    },
+    AssetsRelinked {
+        relinked: Vec<AssetRelink>,
+        missing: Vec<MissingAsset>,
+    },
+    PreflightReport {
+        warnings: Vec<PreflightWarning>,
+    },
+    BundleExported {
+        path: PathBuf,
+    },
+    CueSheetExported {
+        path: PathBuf,
+    },
+    CueSheetImported {
+        path: PathBuf,
+        cue_count: usize,
+        unresolved_files: Vec<PathBuf>,
+        warnings: Vec<String>,
+    },
 
     // AssetProcessor Events
     AssetMetadata {
@@ -75,6 +135,17 @@ pub enum BackendEvent {
     },
 }
 
+/// Why a GO press was ignored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum GoSuppressedReason {
+    /// Pressed within the lockout window after the previous GO.
+    Lockout,
+    /// The cue is still running. GO again to restart it.
+    ConfirmRequired,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
@@ -105,6 +176,13 @@ pub enum CueStatusEventParam {
     PreWaitCompleted {
         cue_id: Uuid,
     },
+    PostWaitStarted {
+        cue_id: Uuid,
+        duration: f64,
+    },
+    PostWaitCompleted {
+        cue_id: Uuid,
+    },
     Started {
         cue_id: Uuid,
         position: f64,
@@ -152,6 +230,10 @@ pub enum BackendError {
     SaveToFile { path: PathBuf, message: String },
     LoadFromFile { path: PathBuf, message: String },
     ExportToFolder { path: PathBuf, message: String },
+    ImportCueSheet { path: PathBuf, message: String },
+    ExportCueSheet { path: PathBuf, message: String },
+    ExportBundle { path: PathBuf, message: String },
+    RelinkAssets { path: PathBuf, message: String },
     CueEdit { message: String },
     Custom { id: usize, message: String },
 }
@@ -217,6 +299,13 @@ impl TryFrom<ExecutorEvent> for BackendEvent {
             ExecutorEvent::PreWaitCompleted { cue_id } => {
                 Some(CueStatusEventParam::PreWaitCompleted { cue_id })
             }
+            ExecutorEvent::PostWaitStarted { cue_id, duration } => {
+                Some(CueStatusEventParam::PostWaitStarted { cue_id, duration })
+            }
+            ExecutorEvent::PostWaitProgress { .. } => None,
+            ExecutorEvent::PostWaitCompleted { cue_id } => {
+                Some(CueStatusEventParam::PostWaitCompleted { cue_id })
+            }
         };
         if let Some(param) = status_param {
             Ok(BackendEvent::CueStatus(param))
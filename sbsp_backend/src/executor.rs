@@ -38,6 +38,56 @@ struct ActiveInstance {
     is_paused: bool,     // specify paused or playing
 }
 
+#[derive(Debug)]
+struct GroupClock {
+    base: f64,
+    started_at: Instant,
+    is_paused: bool,
+}
+
+impl GroupClock {
+    fn new() -> Self {
+        Self {
+            base: 0.0,
+            started_at: Instant::now(),
+            is_paused: false,
+        }
+    }
+
+    fn position(&self) -> f64 {
+        if self.is_paused {
+            self.base
+        } else {
+            self.base + self.started_at.elapsed().as_secs_f64()
+        }
+    }
+
+    fn pause(&mut self) {
+        if !self.is_paused {
+            self.base = self.position();
+            self.is_paused = true;
+        }
+    }
+
+    fn resume(&mut self) {
+        if self.is_paused {
+            self.started_at = Instant::now();
+            self.is_paused = false;
+        }
+    }
+
+    fn seek(&mut self, position: f64) {
+        self.base = position;
+        self.started_at = Instant::now();
+    }
+}
+
+#[derive(Debug)]
+struct PostWait {
+    target_id: Uuid,
+    is_paused: bool,
+}
+
 enum ChainType {
     Start,
     Complete,
@@ -45,6 +95,10 @@ enum ChainType {
 
 enum Task {
     Dispatch(ExecutorCommand),
+    Schedule {
+        cue_id: Uuid,
+        offset: f64,
+    },
     SettleStart(Uuid),
     SettleStop {
         cue_id: Uuid,
@@ -87,6 +141,12 @@ pub struct Executor {
     error_stack: Vec<String>,
     in_flight: HashSet<Uuid>,
     chain_trigger_history: HashMap<Uuid, VecDeque<Instant>>,
+    group_clocks: HashMap<Uuid, GroupClock>,
+    durations: HashMap<Uuid, f64>,
+    pending_seeks: HashMap<Uuid, f64>,
+    pending_restarts: HashMap<Uuid, f64>,
+    wait_origins: HashMap<Uuid, f64>,
+    post_waits: HashMap<Uuid, PostWait>,
 }
 
 impl Executor {
@@ -110,6 +170,12 @@ impl Executor {
             error_stack: Vec::new(),
             in_flight: HashSet::new(),
             chain_trigger_history: HashMap::new(),
+            group_clocks: HashMap::new(),
+            durations: HashMap::new(),
+            pending_seeks: HashMap::new(),
+            pending_restarts: HashMap::new(),
+            wait_origins: HashMap::new(),
+            post_waits: HashMap::new(),
         }
     }
 
@@ -147,6 +213,12 @@ impl Executor {
                     self.error_stack.push(e.to_string());
                 }
             }
+            Task::Schedule { cue_id, offset } => {
+                if let Err(e) = self.trigger_cue(cue_id, offset).await {
+                    log::error!("Failed to execute command: e={}", e);
+                    self.error_stack.push(e.to_string());
+                }
+            }
             Task::SettleStart(cue_id) => {
                 if let Some(parent) = self.model_handle.get_parent_by_id(&cue_id).await {
                     let mut need_notify_event = false;
@@ -171,7 +243,7 @@ impl Executor {
 
                     if need_notify_event {
                         self.task_stack.push(Task::SettleStart(parent.id));
-                        self.resolve_after_start_chain(parent.id).await;
+                        self.resolve_after_start_chain(parent.id, 0.0).await;
                         self.executor_event_tx
                             .send(ExecutorEvent::Triggered { cue_id: parent.id })
                             .await
@@ -199,15 +271,15 @@ impl Executor {
                     return;
                 };
 
-                if children
-                    .iter()
-                    .any(|id| self.active_instances.contains_key(id))
-                    || !self.active_instances.contains_key(&parent.id)
+                if children.iter().any(|id| {
+                    self.active_instances.contains_key(id) || self.post_waits.contains_key(id)
+                }) || !self.active_instances.contains_key(&parent.id)
                 {
                     return;
                 }
 
                 self.active_instances.remove(&parent.id);
+                self.group_clocks.remove(&parent.id);
 
                 if is_completed {
                     self.task_stack
@@ -251,11 +323,12 @@ impl Executor {
                                 is_paused: false,
                             },
                         );
+                        let (position, duration) = self.group_progress(cue_id).await;
                         self.executor_event_tx
                             .send(ExecutorEvent::Started {
                                 cue_id,
-                                position: 0.0,
-                                duration: 0.0,
+                                position,
+                                duration,
                                 initial_params: StateParam::None,
                             })
                             .await
@@ -330,9 +403,10 @@ impl Executor {
                     }
                     ScopeContext::GroupExecute { child_count } => {
                         match (failures.len(), child_count) {
-                            (0, _) => {} // successfully executed.
+                            (0, _) => self.settle_after_scope(cue_id).await, // successfully executed.
                             (n, total) if n == total => {
                                 self.active_instances.remove(&cue_id);
+                                self.group_clocks.remove(&cue_id);
                                 self.emit_error(
                                     cue_id,
                                     format!("Failed to execute group children. e={:?}", failures),
@@ -345,15 +419,17 @@ impl Executor {
                                     "Failed to execute some group children. e={:?}",
                                     failures
                                 );
+                                self.settle_after_scope(cue_id).await;
                             } // continue with error log
                         }
                     }
                     ScopeContext::GroupPause => {
+                        let (position, duration) = self.group_progress(cue_id).await;
                         self.executor_event_tx
                             .send(ExecutorEvent::Paused {
                                 cue_id,
-                                position: 0.0,
-                                duration: 0.0,
+                                position,
+                                duration,
                             })
                             .await
                             .ok();
@@ -391,39 +467,7 @@ impl Executor {
                     self.load_cue(&cue).await?;
                 }
             }
-            ExecutorCommand::Execute(cue_id) => {
-                if let Some(active_instance) = self.active_instances.get(&cue_id)
-                    && active_instance.is_triggered
-                {
-                    log::warn!("Cue already executed. cue_id={}", cue_id);
-                } else if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await {
-                    self.task_stack.push(Task::SettleStart(cue_id));
-                    self.executor_event_tx
-                        .send(ExecutorEvent::Triggered { cue_id })
-                        .await?;
-                    if cue.pre_wait > 0.0 {
-                        if !self.active_instances.contains_key(&cue_id) {
-                            self.load_cue(&cue).await?;
-                        }
-                        if let Some(instance) = self.active_instances.get_mut(&cue_id) {
-                            instance.is_prewaiting = true;
-                            instance.is_triggered = true;
-                        }
-                        self.wait_tx
-                            .send(WaitCommand::Start {
-                                wait_type: WaitType::PreWait,
-                                instance_id: cue_id,
-                                duration: cue.pre_wait,
-                            })
-                            .await?;
-                    } else {
-                        self.execute_cue(&cue).await?;
-                    }
-                    self.resolve_after_start_chain(cue_id).await;
-                } else {
-                    anyhow::bail!("EXECUTE: cue not found. cue_id={}", cue_id);
-                }
-            }
+            ExecutorCommand::Execute(cue_id) => self.trigger_cue(cue_id, 0.0).await?,
             ExecutorCommand::Pause(cue_id) => self.pause_cue(cue_id).await?,
             ExecutorCommand::Resume(cue_id) => self.resume_cue(cue_id).await?,
             ExecutorCommand::Stop(cue_id, stop_mode) => self.stop_cue(cue_id, stop_mode).await?,
@@ -446,6 +490,11 @@ impl Executor {
                     }
                 }
             }
+            ExecutorCommand::SetMasterVolume(volume, fade_param) => {
+                self.audio_tx
+                    .send(AudioCommand::SetMasterVolume { volume, fade_param })
+                    .await?;
+            }
             ExecutorCommand::ReconfigureEngines(settings) => {
                 self.audio_tx
                     .send(AudioCommand::Reconfigure(settings.audio))
@@ -455,6 +504,59 @@ impl Executor {
         Ok(())
     }
 
+    async fn trigger_cue(&mut self, cue_id: Uuid, offset: f64) -> Result<(), anyhow::Error> {
+        if let Some(active_instance) = self.active_instances.get(&cue_id)
+            && active_instance.is_triggered
+        {
+            log::warn!("Cue already executed. cue_id={}", cue_id);
+        } else if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await {
+            if !cue.armed {
+                log::info!("Skipping disarmed cue. cue_id={}", cue_id);
+                // pass through chains as if the cue had finished instantly
+                if cue
+                    .parent_id
+                    .is_none_or(|parent_id| !self.in_flight.contains(&parent_id))
+                {
+                    self.task_stack.push(Task::SettleStop {
+                        cue_id,
+                        is_completed: true,
+                    });
+                }
+                self.resolve_after_start_chain(cue_id, offset).await;
+                self.resolve_after_complete_chain(cue_id).await;
+                return Ok(());
+            }
+            self.task_stack.push(Task::SettleStart(cue_id));
+            self.executor_event_tx
+                .send(ExecutorEvent::Triggered { cue_id })
+                .await?;
+            let pre_wait = cue.pre_wait + offset;
+            if pre_wait > 0.0 {
+                if !self.active_instances.contains_key(&cue_id) {
+                    self.load_cue(&cue).await?;
+                }
+                if let Some(instance) = self.active_instances.get_mut(&cue_id) {
+                    instance.is_prewaiting = true;
+                    instance.is_triggered = true;
+                }
+                self.wait_tx
+                    .send(WaitCommand::Start {
+                        wait_type: WaitType::PreWait,
+                        instance_id: cue_id,
+                        duration: pre_wait,
+                    })
+                    .await?;
+            } else {
+                self.execute_cue(&cue).await?;
+                self.apply_pending_seek(cue_id);
+            }
+            self.resolve_after_start_chain(cue_id, offset).await;
+        } else {
+            anyhow::bail!("EXECUTE: cue not found. cue_id={}", cue_id);
+        }
+        Ok(())
+    }
+
     async fn load_cue(&mut self, cue: &Cue) -> Result<(), anyhow::Error> {
         if self.active_instances.contains_key(&cue.id) {
             anyhow::bail!("Cue already loaded or executed. cue_id={}", cue.id);
@@ -471,6 +573,7 @@ impl Executor {
                 repeat,
                 sound_type,
                 envelope,
+                ..
             }) => {
                 let filepath = self.model_handle.get_asset_standard_path(target).await?;
 
@@ -480,6 +583,7 @@ impl Executor {
                         data: AudioCommandData {
                             filepath,
                             volume: *volume,
+                            trim: self.model_handle.get_group_trim(&cue.id).await,
                             pan: *pan,
                             start_time: *start_time,
                             fade_in_param: *fade_in_param,
@@ -579,7 +683,7 @@ impl Executor {
                             });
                         }
                     }
-                    GroupMode::Concurrency => {
+                    GroupMode::Concurrency | GroupMode::Timeline => {
                         if !children.is_empty() {
                             self.active_instances.insert(
                                 cue.id,
@@ -627,6 +731,7 @@ impl Executor {
                 repeat,
                 sound_type,
                 envelope,
+                ..
             }) => {
                 let filepath = self.model_handle.get_asset_standard_path(target).await?;
 
@@ -635,6 +740,7 @@ impl Executor {
                     data: AudioCommandData {
                         filepath,
                         volume: *volume,
+                        trim: self.model_handle.get_group_trim(&cue.id).await,
                         pan: *pan,
                         start_time: *start_time,
                         fade_in_param: *fade_in_param,
@@ -678,6 +784,7 @@ impl Executor {
                 if let Some(cue) = self.model_handle.get_cue_by_id(&params.target).await
                     && self.active_instances.contains_key(&params.target)
                 {
+                    #[allow(clippy::collapsible_match)]
                     match cue.params {
                         CueParam::Audio(_) => {
                             if self
@@ -837,6 +944,9 @@ impl Executor {
                 match base.mode {
                     GroupMode::Playlist { .. } | GroupMode::StartFirst { .. } => {
                         if let Some(first_id) = children.first() {
+                            if matches!(base.mode, GroupMode::Playlist { .. }) {
+                                self.group_clocks.insert(cue.id, GroupClock::new());
+                            }
                             let context = ScopeContext::GroupExecute { child_count: 1 };
                             self.task_stack.push(Task::EndScope {
                                 cue_id: cue.id,
@@ -856,6 +966,7 @@ impl Executor {
                             let context = ScopeContext::GroupExecute {
                                 child_count: children.len(),
                             };
+                            self.group_clocks.insert(cue.id, GroupClock::new());
                             self.task_stack.push(Task::EndScope {
                                 cue_id: cue.id,
                                 context,
@@ -871,6 +982,34 @@ impl Executor {
                             });
                         }
                     }
+                    GroupMode::Timeline => {
+                        if !children.is_empty() {
+                            let context = ScopeContext::GroupExecute {
+                                child_count: children.len(),
+                            };
+                            self.group_clocks.insert(cue.id, GroupClock::new());
+                            self.task_stack.push(Task::EndScope {
+                                cue_id: cue.id,
+                                context,
+                                watermark: self.error_stack.len(),
+                            });
+                            for child_id in children.iter().rev() {
+                                let offset = self
+                                    .model_handle
+                                    .get_cue_by_id(child_id)
+                                    .await
+                                    .map_or(0.0, |child| child.timeline_offset.max(0.0));
+                                self.task_stack.push(Task::Schedule {
+                                    cue_id: *child_id,
+                                    offset,
+                                });
+                            }
+                            self.task_stack.push(Task::BeginScope {
+                                cue_id: cue.id,
+                                context,
+                            });
+                        }
+                    }
                 }
             }
         }
@@ -878,6 +1017,19 @@ impl Executor {
     }
 
     async fn pause_cue(&mut self, cue_id: Uuid) -> Result<(), anyhow::Error> {
+        // an AfterStart post-wait runs beside the cue itself, so pause both
+        if self
+            .post_waits
+            .get(&cue_id)
+            .is_some_and(|post_wait| !post_wait.is_paused)
+        {
+            self.wait_tx
+                .send(WaitCommand::Pause {
+                    wait_type: WaitType::PostWait,
+                    instance_id: cue_id,
+                })
+                .await?;
+        }
         if let Some(active_instance) = self.active_instances.get(&cue_id) {
             if active_instance.is_prewaiting {
                 self.wait_tx
@@ -913,6 +1065,9 @@ impl Executor {
                         log::error!("cyclic group containment; skipping. cue_id={}", cue_id);
                         return Ok(());
                     }
+                    if let Some(clock) = self.group_clocks.get_mut(&cue_id) {
+                        clock.pause();
+                    }
                     if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
                         && let CueParam::Group { children, .. } = cue.params
                     {
@@ -945,6 +1100,18 @@ impl Executor {
     }
 
     async fn resume_cue(&mut self, cue_id: Uuid) -> Result<(), anyhow::Error> {
+        if self
+            .post_waits
+            .get(&cue_id)
+            .is_some_and(|post_wait| post_wait.is_paused)
+        {
+            self.wait_tx
+                .send(WaitCommand::Resume {
+                    wait_type: WaitType::PostWait,
+                    instance_id: cue_id,
+                })
+                .await?;
+        }
         if let Some(active_instance) = self.active_instances.get(&cue_id) {
             if active_instance.is_prewaiting {
                 self.wait_tx
@@ -980,6 +1147,9 @@ impl Executor {
                         log::error!("cyclic group containment; skipping. cue_id={}", cue_id);
                         return Ok(());
                     }
+                    if let Some(clock) = self.group_clocks.get_mut(&cue_id) {
+                        clock.resume();
+                    }
                     if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
                         && let CueParam::Group { children, .. } = cue.params
                     {
@@ -1012,6 +1182,7 @@ impl Executor {
     }
 
     async fn stop_cue(&mut self, cue_id: Uuid, stop_mode: StopMode) -> Result<(), anyhow::Error> {
+        self.cancel_post_wait(cue_id).await?;
         if let Some(active_instance) = self.active_instances.get(&cue_id) {
             let is_prewaiting = active_instance.is_prewaiting;
             if is_prewaiting {
@@ -1056,9 +1227,16 @@ impl Executor {
                         if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
                             && let CueParam::Group { children, .. } = cue.params
                         {
+                            for child_id in &children {
+                                self.pending_seeks.remove(child_id);
+                                self.pending_restarts.remove(child_id);
+                            }
                             let active_children: Vec<_> = children
                                 .iter()
-                                .filter(|c| self.active_instances.contains_key(c))
+                                .filter(|c| {
+                                    self.active_instances.contains_key(c)
+                                        || self.post_waits.contains_key(c)
+                                })
                                 .rev()
                                 .collect();
                             if !active_children.is_empty() {
@@ -1088,6 +1266,10 @@ impl Executor {
                         let command = match stop_mode {
                             StopMode::Soft => AudioCommand::SoftStop { id: cue_id },
                             StopMode::Hard => AudioCommand::HardStop { id: cue_id },
+                            StopMode::Fade(fade_param) => AudioCommand::FadeStop {
+                                id: cue_id,
+                                fade_param,
+                            },
                         };
                         self.audio_tx.send(command).await?;
                     }
@@ -1114,9 +1296,16 @@ impl Executor {
                         if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
                             && let CueParam::Group { children, .. } = cue.params
                         {
+                            for child_id in &children {
+                                self.pending_seeks.remove(child_id);
+                                self.pending_restarts.remove(child_id);
+                            }
                             let active_children: Vec<_> = children
                                 .iter()
-                                .filter(|c| self.active_instances.contains_key(c))
+                                .filter(|c| {
+                                    self.active_instances.contains_key(c)
+                                        || self.post_waits.contains_key(c)
+                                })
                                 .rev()
                                 .collect();
                             if !active_children.is_empty() {
@@ -1144,7 +1333,7 @@ impl Executor {
         Ok(())
     }
 
-    async fn seek_to_cue(&self, cue_id: Uuid, position: f64) -> Result<(), anyhow::Error> {
+    async fn seek_to_cue(&mut self, cue_id: Uuid, position: f64) -> Result<(), anyhow::Error> {
         if let Some(active_instance) = self.active_instances.get(&cue_id) {
             if active_instance.is_prewaiting {
                 self.wait_tx
@@ -1181,14 +1370,20 @@ impl Executor {
                     log::warn!("SeekTo command is not available for Transport cues. ignoring...");
                 }
                 EngineType::Group => {
-                    log::warn!("SeekTo command is not available for Group cues. ignoring...");
+                    if self.group_clocks.contains_key(&cue_id) {
+                        self.seek_group(cue_id, position).await?;
+                    } else {
+                        log::warn!(
+                            "SeekTo command is not available for this Group cue. ignoring..."
+                        );
+                    }
                 }
             }
         }
         Ok(())
     }
 
-    async fn seek_by_cue(&self, cue_id: Uuid, amount: f64) -> Result<(), anyhow::Error> {
+    async fn seek_by_cue(&mut self, cue_id: Uuid, amount: f64) -> Result<(), anyhow::Error> {
         if let Some(active_instance) = self.active_instances.get(&cue_id) {
             if active_instance.is_prewaiting {
                 self.wait_tx
@@ -1222,13 +1417,294 @@ impl Executor {
                     log::warn!("SeekBy command is not available for Transport cues. ignoring...");
                 }
                 EngineType::Group => {
-                    log::warn!("SeekTo command is not available for Group cues. ignoring...");
+                    if let Some(clock) = self.group_clocks.get(&cue_id) {
+                        let position = clock.position() + amount;
+                        self.seek_group(cue_id, position).await?;
+                    } else {
+                        log::warn!(
+                            "SeekBy command is not available for this Group cue. ignoring..."
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn seek_group(&mut self, cue_id: Uuid, position: f64) -> Result<(), anyhow::Error> {
+        let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await else {
+            return Ok(());
+        };
+        let CueParam::Group { base, children } = &cue.params else {
+            return Ok(());
+        };
+        let position = position.max(0.0);
+        let mut child_cues = Vec::with_capacity(children.len());
+        for child_id in children {
+            if let Some(child) = self.model_handle.get_cue_by_id(child_id).await {
+                child_cues.push(child);
+            }
+        }
+
+        match base.mode {
+            GroupMode::Playlist { .. } => {
+                let mut span_start = 0.0;
+                let mut target_index = child_cues.len().saturating_sub(1);
+                for (index, child) in child_cues.iter().enumerate() {
+                    let Some(length) = self.child_length(child) else {
+                        target_index = index;
+                        break;
+                    };
+                    let end = span_start + child.pre_wait + length;
+                    if position < end || index == target_index {
+                        target_index = index;
+                        break;
+                    }
+                    span_start = end;
+                }
+                if let Some(target) = child_cues.get(target_index) {
+                    self.seek_child(cue_id, target, span_start, span_start, position)
+                        .await?;
+                }
+                for (index, child) in child_cues.iter().enumerate() {
+                    if index != target_index && self.active_instances.contains_key(&child.id) {
+                        self.pending_seeks.remove(&child.id);
+                        self.pending_restarts.remove(&child.id);
+                        self.task_stack.push(Task::Dispatch(ExecutorCommand::Stop(
+                            child.id,
+                            StopMode::Hard,
+                        )));
+                    }
+                }
+            }
+            GroupMode::Concurrency | GroupMode::Timeline => {
+                for child in &child_cues {
+                    let span_start = Self::timeline_offset(&base.mode, child);
+                    self.seek_child(cue_id, child, span_start, 0.0, position)
+                        .await?;
                 }
             }
+            GroupMode::StartFirst { .. } => {}
+        }
+
+        if let Some(clock) = self.group_clocks.get_mut(&cue_id) {
+            clock.seek(position);
         }
+        self.executor_event_tx
+            .send(ExecutorEvent::Seeked { cue_id, position })
+            .await?;
         Ok(())
     }
 
+    async fn seek_child(
+        &mut self,
+        group_id: Uuid,
+        child: &Cue,
+        span_start: f64,
+        default_origin: f64,
+        position: f64,
+    ) -> Result<(), anyhow::Error> {
+        let body_start = span_start + child.pre_wait;
+        let origin = self
+            .wait_origins
+            .get(&child.id)
+            .copied()
+            .unwrap_or(default_origin);
+        self.pending_seeks.remove(&child.id);
+        self.pending_restarts.remove(&child.id);
+
+        match self.active_instances.get(&child.id) {
+            Some(instance) if instance.is_prewaiting && position >= origin => {
+                if position >= body_start {
+                    self.pending_seeks.insert(child.id, position - body_start);
+                }
+                self.wait_tx
+                    .send(WaitCommand::SeekTo {
+                        wait_type: WaitType::PreWait,
+                        instance_id: child.id,
+                        position: position.min(body_start) - origin,
+                    })
+                    .await?;
+            }
+            Some(instance) if instance.is_triggered => {
+                if !instance.is_prewaiting && position >= body_start {
+                    self.task_stack.push(Task::Dispatch(ExecutorCommand::SeekTo(
+                        child.id,
+                        position - body_start,
+                    )));
+                } else {
+                    self.pending_restarts
+                        .insert(child.id, body_start - position);
+                    self.wait_origins.insert(child.id, position);
+                    self.task_stack.push(Task::Dispatch(ExecutorCommand::Stop(
+                        child.id,
+                        StopMode::Hard,
+                    )));
+                }
+            }
+            Some(_) => {}
+            None => {
+                if position < body_start {
+                    self.wait_origins.insert(child.id, position);
+                    self.schedule_child(group_id, child.id, body_start - position - child.pre_wait);
+                } else if self
+                    .child_length(child)
+                    .is_none_or(|length| position - body_start < length)
+                {
+                    self.pending_seeks.insert(child.id, position - body_start);
+                    self.schedule_child(group_id, child.id, -child.pre_wait);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn timeline_offset(mode: &GroupMode, cue: &Cue) -> f64 {
+        if matches!(mode, GroupMode::Timeline) {
+            cue.timeline_offset.max(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn schedule_child(&mut self, group_id: Uuid, cue_id: Uuid, offset: f64) {
+        if self
+            .group_clocks
+            .get(&group_id)
+            .is_some_and(|clock| clock.is_paused)
+        {
+            self.task_stack
+                .push(Task::Dispatch(ExecutorCommand::Pause(cue_id)));
+        }
+        self.task_stack.push(Task::Schedule { cue_id, offset });
+    }
+
+    fn apply_pending_seek(&mut self, cue_id: Uuid) {
+        if let Some(position) = self.pending_seeks.remove(&cue_id) {
+            self.task_stack
+                .push(Task::Dispatch(ExecutorCommand::SeekTo(cue_id, position)));
+        }
+    }
+
+    fn child_length(&self, cue: &Cue) -> Option<f64> {
+        if let Some(duration) = self.durations.get(&cue.id) {
+            return Some(*duration);
+        }
+        match &cue.params {
+            CueParam::Wait(params) => Some(params.duration),
+            CueParam::Fade(params) => Some(params.fade_param.duration),
+            _ => None,
+        }
+    }
+
+    async fn group_progress(&self, cue_id: Uuid) -> (f64, f64) {
+        let Some(clock) = self.group_clocks.get(&cue_id) else {
+            return (0.0, 0.0);
+        };
+        let position = clock.position();
+        let mut duration = 0.0_f64;
+        if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
+            && let CueParam::Group { base, children } = &cue.params
+        {
+            for child_id in children {
+                if let Some(child) = self.model_handle.get_cue_by_id(child_id).await {
+                    let length = child.pre_wait + self.child_length(&child).unwrap_or(0.0);
+                    if matches!(base.mode, GroupMode::Playlist { .. }) {
+                        duration += length;
+                    } else {
+                        duration = duration.max(Self::timeline_offset(&base.mode, &child) + length);
+                    }
+                }
+            }
+        }
+        (position, duration)
+    }
+
+    async fn anchor_group_clock(&mut self, cue_id: Uuid, position: f64, is_prewait: bool) {
+        let Some(parent) = self.model_handle.get_parent_by_id(&cue_id).await else {
+            return;
+        };
+        let CueParam::Group { base, children } = &parent.params else {
+            return;
+        };
+        if !matches!(base.mode, GroupMode::Playlist { .. })
+            || !self.group_clocks.contains_key(&parent.id)
+        {
+            return;
+        }
+        let mut span_start = 0.0;
+        for child_id in children {
+            let Some(child) = self.model_handle.get_cue_by_id(child_id).await else {
+                continue;
+            };
+            if child.id == cue_id {
+                let group_position = if is_prewait {
+                    self.wait_origins
+                        .get(&cue_id)
+                        .copied()
+                        .unwrap_or(span_start)
+                        + position
+                } else {
+                    span_start + child.pre_wait + position
+                };
+                if let Some(clock) = self.group_clocks.get_mut(&parent.id) {
+                    clock.seek(group_position);
+                }
+                return;
+            }
+            span_start += child.pre_wait + self.child_length(&child).unwrap_or(0.0);
+        }
+    }
+
+    async fn report_group_progress(&mut self, cue_id: Uuid) {
+        let mut current_id = cue_id;
+        while let Some(parent) = self.model_handle.get_parent_by_id(&current_id).await {
+            if self
+                .group_clocks
+                .get(&parent.id)
+                .is_none_or(|clock| clock.is_paused)
+            {
+                return;
+            }
+            let (position, duration) = self.group_progress(parent.id).await;
+            self.durations.insert(parent.id, duration);
+            let event = ExecutorEvent::Progress {
+                cue_id: parent.id,
+                position,
+                duration,
+            };
+            if let Err(e) = self.executor_event_tx.try_send(event) {
+                log::warn!("EngineEvent dropped: {:?}", e);
+            }
+            current_id = parent.id;
+        }
+    }
+
+    async fn sync_group_clock(&mut self, cue_id: Uuid) {
+        let Some(parent) = self.model_handle.get_parent_by_id(&cue_id).await else {
+            return;
+        };
+        let CueParam::Group { children, .. } = &parent.params else {
+            return;
+        };
+        let Some(clock) = self.group_clocks.get_mut(&parent.id) else {
+            return;
+        };
+        let mut triggered = children
+            .iter()
+            .filter_map(|id| self.active_instances.get(id))
+            .filter(|instance| instance.is_triggered)
+            .peekable();
+        if triggered.peek().is_none() {
+            return;
+        }
+        if triggered.all(|instance| instance.is_paused) {
+            clock.pause();
+        } else {
+            clock.resume();
+        }
+    }
+
     async fn handle_engine_event(&mut self, event: EngineEvent) -> Result<(), anyhow::Error> {
         match event {
             EngineEvent::Audio(audio_event) => {
@@ -1237,25 +1713,33 @@ impl Executor {
                 let playback_event = match audio_event {
                     AudioEngineEvent::Loaded {
                         position, duration, ..
-                    } => ExecutorEvent::Loaded {
-                        cue_id,
-                        position,
-                        duration,
-                    },
+                    } => {
+                        self.durations.insert(cue_id, duration);
+                        ExecutorEvent::Loaded {
+                            cue_id,
+                            position,
+                            duration,
+                        }
+                    }
                     AudioEngineEvent::Started {
                         position,
                         duration,
                         initial_params,
                         ..
-                    } => ExecutorEvent::Started {
-                        cue_id,
-                        position,
-                        duration,
-                        initial_params: StateParam::Audio(initial_params),
-                    },
+                    } => {
+                        self.durations.insert(cue_id, duration);
+                        self.anchor_group_clock(cue_id, position, false).await;
+                        ExecutorEvent::Started {
+                            cue_id,
+                            position,
+                            duration,
+                            initial_params: StateParam::Audio(initial_params),
+                        }
+                    }
                     AudioEngineEvent::Progress {
                         position, duration, ..
                     } => {
+                        self.durations.insert(cue_id, duration);
                         let event = ExecutorEvent::Progress {
                             cue_id,
                             position,
@@ -1264,6 +1748,7 @@ impl Executor {
                         if let Err(e) = self.executor_event_tx.try_send(event) {
                             log::warn!("EngineEvent dropped: {:?}", e);
                         }
+                        self.report_group_progress(cue_id).await;
                         return Ok(());
                     }
                     AudioEngineEvent::Paused {
@@ -1272,6 +1757,7 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = true);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::Paused {
                             cue_id,
                             position,
@@ -1282,9 +1768,11 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = false);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::Resumed { cue_id }
                     }
                     AudioEngineEvent::Seeked { position, .. } => {
+                        self.anchor_group_clock(cue_id, position, false).await;
                         ExecutorEvent::Seeked { cue_id, position }
                     }
                     AudioEngineEvent::Stopping {
@@ -1328,6 +1816,7 @@ impl Executor {
                 let executor_event = match wait_event {
                     WaitEvent::Loaded { .. } => unreachable!(),
                     WaitEvent::Started { duration, .. } => {
+                        self.anchor_group_clock(cue_id, 0.0, true).await;
                         ExecutorEvent::PreWaitStarted { cue_id, duration }
                     }
                     WaitEvent::Progress {
@@ -1341,6 +1830,7 @@ impl Executor {
                         if let Err(e) = self.executor_event_tx.try_send(event) {
                             log::warn!("EngineEvent dropped: {:?}", e);
                         }
+                        self.report_group_progress(cue_id).await;
                         return Ok(());
                     }
                     WaitEvent::Paused {
@@ -1349,6 +1839,7 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = true);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::PreWaitPaused {
                             cue_id,
                             position,
@@ -1359,9 +1850,11 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = false);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::PreWaitResumed { cue_id }
                     }
                     WaitEvent::Seeked { position, .. } => {
+                        self.anchor_group_clock(cue_id, position, true).await;
                         ExecutorEvent::Seeked { cue_id, position }
                     }
                     WaitEvent::Stopped { .. } => {
@@ -1377,7 +1870,9 @@ impl Executor {
                             self.executor_event_tx
                                 .send(ExecutorEvent::PreWaitCompleted { cue_id })
                                 .await?;
+                            self.wait_origins.remove(&cue_id);
                             self.execute_cue(&cue).await?;
+                            self.apply_pending_seek(cue_id);
                             return Ok(());
                         } else {
                             self.executor_event_tx
@@ -1391,25 +1886,102 @@ impl Executor {
 
                 self.executor_event_tx.send(executor_event).await?;
             }
+            EngineEvent::PostWait(wait_event) => {
+                let cue_id = wait_event.id();
+
+                let executor_event = match wait_event {
+                    WaitEvent::Started { duration, .. } => {
+                        ExecutorEvent::PostWaitStarted { cue_id, duration }
+                    }
+                    WaitEvent::Progress {
+                        position, duration, ..
+                    } => {
+                        let event = ExecutorEvent::PostWaitProgress {
+                            cue_id,
+                            position,
+                            duration,
+                        };
+                        if let Err(e) = self.executor_event_tx.try_send(event) {
+                            log::warn!("EngineEvent dropped: {:?}", e);
+                        }
+                        return Ok(());
+                    }
+                    WaitEvent::Stopped { .. } => {
+                        self.post_waits.remove(&cue_id);
+                        if !self.active_instances.contains_key(&cue_id) {
+                            self.executor_event_tx
+                                .send(ExecutorEvent::Stopped { cue_id })
+                                .await?;
+                        }
+                        self.task_stack.push(Task::SettleStop {
+                            cue_id,
+                            is_completed: false,
+                        });
+                        return Ok(());
+                    }
+                    WaitEvent::Completed { overshoot, .. } => {
+                        let Some(post_wait) = self.post_waits.remove(&cue_id) else {
+                            return Ok(());
+                        };
+                        log::info!("PostWaitCompleted cue_id={}", cue_id);
+                        self.executor_event_tx
+                            .send(ExecutorEvent::PostWaitCompleted { cue_id })
+                            .await?;
+                        self.task_stack.push(Task::SettleStop {
+                            cue_id,
+                            is_completed: true,
+                        });
+                        if self.record_and_check_chain_trigger(post_wait.target_id) {
+                            self.task_stack.push(Task::Schedule {
+                                cue_id: post_wait.target_id,
+                                offset: -overshoot,
+                            });
+                        }
+                        return Ok(());
+                    }
+                    WaitEvent::Paused { .. } => {
+                        if let Some(post_wait) = self.post_waits.get_mut(&cue_id) {
+                            post_wait.is_paused = true;
+                        }
+                        return Ok(());
+                    }
+                    WaitEvent::Resumed { .. } => {
+                        if let Some(post_wait) = self.post_waits.get_mut(&cue_id) {
+                            post_wait.is_paused = false;
+                        }
+                        return Ok(());
+                    }
+                    WaitEvent::Loaded { .. } | WaitEvent::Seeked { .. } => return Ok(()),
+                };
+
+                self.executor_event_tx.send(executor_event).await?;
+            }
             EngineEvent::Wait(wait_event) | EngineEvent::Fade(wait_event) => {
                 let cue_id = wait_event.id();
 
                 let playback_event = match wait_event {
                     WaitEvent::Loaded {
                         position, duration, ..
-                    } => ExecutorEvent::Loaded {
-                        cue_id,
-                        position,
-                        duration,
-                    },
+                    } => {
+                        self.durations.insert(cue_id, duration);
+                        ExecutorEvent::Loaded {
+                            cue_id,
+                            position,
+                            duration,
+                        }
+                    }
                     WaitEvent::Started {
                         position, duration, ..
-                    } => ExecutorEvent::Started {
-                        cue_id,
-                        position,
-                        duration,
-                        initial_params: StateParam::None,
-                    },
+                    } => {
+                        self.durations.insert(cue_id, duration);
+                        self.anchor_group_clock(cue_id, position, false).await;
+                        ExecutorEvent::Started {
+                            cue_id,
+                            position,
+                            duration,
+                            initial_params: StateParam::None,
+                        }
+                    }
                     WaitEvent::Progress {
                         position, duration, ..
                     } => {
@@ -1421,6 +1993,7 @@ impl Executor {
                         if let Err(e) = self.executor_event_tx.try_send(event) {
                             log::warn!("EngineEvent dropped: {:?}", e);
                         }
+                        self.report_group_progress(cue_id).await;
                         return Ok(());
                     }
                     WaitEvent::Paused {
@@ -1429,6 +2002,7 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = true);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::Paused {
                             cue_id,
                             position,
@@ -1439,9 +2013,11 @@ impl Executor {
                         self.active_instances
                             .entry(cue_id)
                             .and_modify(|instance| instance.is_paused = false);
+                        self.sync_group_clock(cue_id).await;
                         ExecutorEvent::Resumed { cue_id }
                     }
                     WaitEvent::Seeked { position, .. } => {
+                        self.anchor_group_clock(cue_id, position, false).await;
                         ExecutorEvent::Seeked { cue_id, position }
                     }
                     WaitEvent::Stopped { .. } => {
@@ -1460,6 +2036,19 @@ impl Executor {
         Ok(())
     }
 
+    async fn settle_after_scope(&mut self, cue_id: Uuid) {
+        // children skipped while the scope was open could not settle the group by themselves
+        if let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
+            && let CueParam::Group { children, .. } = &cue.params
+            && let Some(first_id) = children.first()
+        {
+            self.task_stack.push(Task::SettleStop {
+                cue_id: *first_id,
+                is_completed: true,
+            });
+        }
+    }
+
     async fn emit_stopped(&mut self, cue_id: Uuid) -> Result<(), anyhow::Error> {
         self.executor_event_tx
             .send(ExecutorEvent::Stopped { cue_id })
@@ -1468,10 +2057,21 @@ impl Executor {
             cue_id,
             is_completed: false,
         });
+        if let Some(delay) = self.pending_restarts.remove(&cue_id)
+            && let Some(cue) = self.model_handle.get_cue_by_id(&cue_id).await
+            && let Some(group_id) = cue.parent_id
+        {
+            self.schedule_child(group_id, cue_id, delay - cue.pre_wait);
+        } else {
+            self.wait_origins.remove(&cue_id);
+        }
         Ok(())
     }
 
     async fn emit_error(&mut self, cue_id: Uuid, error: String) -> Result<(), anyhow::Error> {
+        self.pending_seeks.remove(&cue_id);
+        self.pending_restarts.remove(&cue_id);
+        self.wait_origins.remove(&cue_id);
         self.executor_event_tx
             .send(ExecutorEvent::Error { cue_id, error })
             .await?;
@@ -1494,43 +2094,90 @@ impl Executor {
         Ok(())
     }
 
-    async fn resolve_after_start_chain(&mut self, cue_id: Uuid) {
-        let Some(target) = self.resolve_chain_target(cue_id, ChainType::Start).await else { return };
+    async fn resolve_after_start_chain(&mut self, cue_id: Uuid, offset: f64) {
+        let Some((target, post_wait)) = self.resolve_chain_target(cue_id, ChainType::Start).await else { return };
 
-        if self.record_and_check_chain_trigger(target) {
-            self.task_stack
-                .push(Task::Dispatch(ExecutorCommand::Execute(target)));
-        }
+        // negative offset means this cue was triggered late, so shorten post-wait to keep timing exact
+        self.follow_chain(cue_id, target, post_wait + offset.min(0.0))
+            .await;
     }
 
     async fn resolve_after_complete_chain(&mut self, cue_id: Uuid) {
-        let Some(target) = self.resolve_chain_target(cue_id, ChainType::Complete).await else { return };
+        let Some((target, post_wait)) = self.resolve_chain_target(cue_id, ChainType::Complete).await else { return };
 
-        if self.record_and_check_chain_trigger(target) {
-            self.task_stack
-                .push(Task::Dispatch(ExecutorCommand::Execute(target)));
+        self.follow_chain(cue_id, target, post_wait).await;
+    }
+
+    async fn follow_chain(&mut self, cue_id: Uuid, target: Uuid, post_wait: f64) {
+        if post_wait > 0.0 {
+            self.post_waits.insert(
+                cue_id,
+                PostWait {
+                    target_id: target,
+                    is_paused: false,
+                },
+            );
+            if let Err(e) = self
+                .wait_tx
+                .send(WaitCommand::Start {
+                    wait_type: WaitType::PostWait,
+                    instance_id: cue_id,
+                    duration: post_wait,
+                })
+                .await
+            {
+                log::error!("Failed to send wait command to start postwait timer. {}", e);
+                self.post_waits.remove(&cue_id);
+            }
+        } else if self.record_and_check_chain_trigger(target) {
+            self.task_stack.push(Task::Schedule {
+                cue_id: target,
+                offset: post_wait,
+            });
+        }
+    }
+
+    async fn cancel_post_wait(&mut self, cue_id: Uuid) -> Result<(), anyhow::Error> {
+        if self.post_waits.contains_key(&cue_id) {
+            self.wait_tx
+                .send(WaitCommand::Stop {
+                    wait_type: WaitType::PostWait,
+                    instance_id: cue_id,
+                })
+                .await?;
         }
+        Ok(())
     }
 
-    async fn resolve_chain_target(&self, cue_id: Uuid, chain_type: ChainType) -> Option<Uuid> {
+    async fn resolve_chain_target(
+        &self,
+        cue_id: Uuid,
+        chain_type: ChainType,
+    ) -> Option<(Uuid, f64)> {
         if let Some(chain) = self.model_handle.get_cue_chain_by_id(&cue_id).await {
-            match (chain_type, &chain) {
-                (ChainType::Start, CueChain::AfterStart { target_id }) => {
-                    if let Some(target) = target_id {
-                        Some(*target)
-                    } else {
-                        self.model_handle.get_next_cue_id_by_id(&cue_id).await
-                    }
-                }
-                (ChainType::Complete, CueChain::AfterComplete { target_id }) => {
-                    if let Some(target) = target_id {
-                        Some(*target)
-                    } else {
-                        self.model_handle.get_next_cue_id_by_id(&cue_id).await
-                    }
-                }
-                (_, _) => None,
-            }
+            let (target_id, post_wait) = match (chain_type, chain) {
+                (
+                    ChainType::Start,
+                    CueChain::AfterStart {
+                        target_id,
+                        post_wait,
+                    },
+                ) => (target_id, post_wait),
+                (
+                    ChainType::Complete,
+                    CueChain::AfterComplete {
+                        target_id,
+                        post_wait,
+                    },
+                ) => (target_id, post_wait),
+                (_, _) => return None,
+            };
+            let target = if let Some(target) = target_id {
+                Some(target)
+            } else {
+                self.model_handle.get_next_cue_id_by_id(&cue_id).await
+            };
+            target.map(|target| (target, post_wait))
         } else {
             log::warn!("Unknown cue found. model may be broken. cue_id={}", cue_id);
             None
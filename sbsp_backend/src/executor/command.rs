@@ -3,12 +3,20 @@
 
 use uuid::Uuid;
 
-use crate::{action::CueAction, model::settings::ShowSettings};
+use crate::{
+    action::CueAction,
+    model::{
+        cue::audio::{Decibels, FadeParam},
+        settings::ShowSettings,
+    },
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum StopMode {
     Soft,
     Hard,
+    /// Like `Soft`, but audio fades out over the given fade instead of its own fade-out.
+    Fade(FadeParam),
 }
 
 #[derive(Debug)]
@@ -21,5 +29,6 @@ pub enum ExecutorCommand {
     SeekTo(Uuid, f64),
     SeekBy(Uuid, f64),
     PerformAction(Uuid, CueAction),
+    SetMasterVolume(Decibels, Option<FadeParam>),
     ReconfigureEngines(Box<ShowSettings>),
 }
@@ -35,6 +35,18 @@ pub enum ExecutorEvent {
     PreWaitCompleted {
         cue_id: Uuid,
     },
+    PostWaitStarted {
+        cue_id: Uuid,
+        duration: f64,
+    },
+    PostWaitProgress {
+        cue_id: Uuid,
+        position: f64,
+        duration: f64,
+    },
+    PostWaitCompleted {
+        cue_id: Uuid,
+    },
     Started {
         cue_id: Uuid,
         position: f64,
@@ -55,7 +55,9 @@ async fn setup_executor(
             name: None,
             notes: "".to_string(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: model::cue::CueChain::DoNotChain,
             parent_id: None,
             params: model::cue::CueParam::Audio(AudioCueParam {
@@ -75,6 +77,7 @@ async fn setup_executor(
                 repeat: false,
                 sound_type: SoundType::Streaming,
                 envelope: Vec::new(),
+                original_path: None,
             }),
         },
     );
@@ -109,11 +112,34 @@ async fn setup_executor_with_cues(
     Receiver<AudioCommand>,
     Sender<EngineEvent>,
     Receiver<ExecutorEvent>,
+) {
+    let (manager, exec_tx, audio_rx, mut wait_rx, engine_event_tx, playback_event_rx) =
+        setup_executor_with_wait(cues, root_ids).await;
+    tokio::spawn(async move { while wait_rx.recv().await.is_some() {} });
+
+    (
+        manager,
+        exec_tx,
+        audio_rx,
+        engine_event_tx,
+        playback_event_rx,
+    )
+}
+
+async fn setup_executor_with_wait(
+    cues: Vec<Cue>,
+    root_ids: Vec<Uuid>,
+) -> (
+    ShowModelManager,
+    Sender<ExecutorCommand>,
+    Receiver<AudioCommand>,
+    Receiver<WaitCommand>,
+    Sender<EngineEvent>,
+    Receiver<ExecutorEvent>,
 ) {
     let (exec_tx, exec_rx) = mpsc::channel::<ExecutorCommand>(32);
     let (audio_tx, audio_rx) = mpsc::channel::<AudioCommand>(32);
-    let (wait_tx, mut wait_rx) = mpsc::channel::<WaitCommand>(32);
-    tokio::spawn(async move { while wait_rx.recv().await.is_some() {} });
+    let (wait_tx, wait_rx) = mpsc::channel::<WaitCommand>(32);
     let (playback_event_tx, playback_event_rx) = mpsc::channel::<ExecutorEvent>(32);
     let (engine_event_tx, engine_event_rx) = mpsc::channel::<EngineEvent>(32);
     let (event_tx, _) = broadcast::channel::<BackendEvent>(32);
@@ -142,6 +168,7 @@ async fn setup_executor_with_cues(
         manager,
         exec_tx,
         audio_rx,
+        wait_rx,
         engine_event_tx,
         playback_event_rx,
     )
@@ -154,7 +181,9 @@ fn make_audio_cue(id: Uuid, parent_id: Option<Uuid>, path: PathBuf) -> Cue {
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Audio(AudioCueParam {
@@ -168,6 +197,7 @@ fn make_audio_cue(id: Uuid, parent_id: Option<Uuid>, path: PathBuf) -> Cue {
             repeat: false,
             sound_type: SoundType::Streaming,
             envelope: Vec::new(),
+            original_path: None,
         }),
     }
 }
@@ -179,7 +209,9 @@ fn make_start_cue(id: Uuid, parent_id: Option<Uuid>, target: Uuid) -> Cue {
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Start(model::cue::StartCueParam { target }),
@@ -193,7 +225,9 @@ fn make_stop_cue(id: Uuid, parent_id: Option<Uuid>, target: Uuid, hard: bool) ->
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Stop(model::cue::StopCueParam { target, hard }),
@@ -207,7 +241,9 @@ fn make_pause_cue(id: Uuid, parent_id: Option<Uuid>, target: Uuid) -> Cue {
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Pause(model::cue::PauseCueParam { target }),
@@ -221,7 +257,9 @@ fn make_load_cue(id: Uuid, parent_id: Option<Uuid>, target: Uuid) -> Cue {
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Load(model::cue::LoadCueParam { target }),
@@ -240,12 +278,15 @@ fn make_concurrency_group_cue(id: Uuid, parent_id: Option<Uuid>, children: Vec<U
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Group {
             base: model::cue::group::GroupCueParamBase {
                 mode: GroupMode::Concurrency,
+                volume: Decibels::IDENTITY,
             },
             children,
         },
@@ -264,18 +305,48 @@ fn make_playlist_group_cue(
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id,
         params: model::cue::CueParam::Group {
             base: crate::model::cue::group::GroupCueParamBase {
                 mode: GroupMode::Playlist { repeat },
+                volume: Decibels::IDENTITY,
             },
             children,
         },
     }
 }
 
+fn make_timeline_group_cue(id: Uuid, parent_id: Option<Uuid>, children: Vec<Uuid>) -> Cue {
+    Cue {
+        id,
+        number: "1".to_string(),
+        name: None,
+        notes: "".to_string(),
+        color: CueColor::None,
+        armed: true,
+        pre_wait: 0.0,
+        timeline_offset: 0.0,
+        chain: model::cue::CueChain::DoNotChain,
+        parent_id,
+        params: model::cue::CueParam::Group {
+            base: model::cue::group::GroupCueParamBase {
+                mode: GroupMode::Timeline,
+                volume: Decibels::IDENTITY,
+            },
+            children,
+        },
+    }
+}
+
+fn with_timeline_offset(mut cue: Cue, timeline_offset: f64) -> Cue {
+    cue.timeline_offset = timeline_offset;
+    cue
+}
+
 #[tokio::test]
 async fn play_command() {
     let temp_target: NamedTempFile = NamedTempFile::with_suffix(".flac").unwrap();
@@ -288,7 +359,9 @@ async fn play_command() {
         name: None,
         notes: "".to_string(),
         color: CueColor::None,
+        armed: true,
         pre_wait: 0.0,
+        timeline_offset: 0.0,
         chain: model::cue::CueChain::DoNotChain,
         parent_id: None,
         params: model::cue::CueParam::Audio(AudioCueParam {
@@ -312,6 +385,7 @@ async fn play_command() {
                 end: 5.0,
                 volume: Decibels::from(-2.0),
             }],
+            original_path: None,
         }),
     };
 
@@ -1314,6 +1388,7 @@ async fn pre_wait_defers_started_until_actual_start() {
     engine_event_tx
         .send(EngineEvent::PreWait(WaitEvent::Completed {
             instance_id: cue_id,
+            overshoot: 0.0,
         }))
         .await
         .unwrap();
@@ -1580,3 +1655,520 @@ async fn stop_prewaiting_child_does_not_affect_active_sibling_group() {
         .unwrap();
     assert!(playback_event_rx.try_recv().is_err());
 }
+
+#[tokio::test]
+async fn timeline_group_starts_children_at_offsets_and_seeks_consistently() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let group_id = Uuid::new_v4();
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let cues = vec![
+        make_timeline_group_cue(group_id, None, vec![first_id, second_id]),
+        make_audio_cue(first_id, Some(group_id), path.clone()),
+        with_timeline_offset(make_audio_cue(second_id, Some(group_id), path), 2.0),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, mut wait_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_wait(cues, vec![group_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(group_id))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == group_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Started { cue_id, .. } if cue_id == group_id
+    ));
+    // オフセット0の子は即座に再生される
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == first_id)
+    );
+    // オフセット付きの子はロードされ、オフセット分待機する
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Load { id, .. } if id == second_id)
+    );
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Start { wait_type: WaitType::PreWait, instance_id, duration }
+            if instance_id == second_id && duration == 2.0
+    ));
+
+    // グループを3秒地点へシーク
+    exec_tx
+        .send(ExecutorCommand::SeekTo(group_id, 3.0))
+        .await
+        .unwrap();
+
+    // 待機中の子はオフセット地点まで進められる
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::SeekTo { wait_type: WaitType::PreWait, instance_id, position }
+            if instance_id == second_id && position == 2.0
+    ));
+    // 再生中の子はグループ位置と同じ地点へ
+    assert!(matches!(
+        audio_rx.recv().await.unwrap(),
+        AudioCommand::SeekTo { id, position } if id == first_id && position == 3.0
+    ));
+
+    engine_event_tx
+        .send(EngineEvent::PreWait(WaitEvent::Completed {
+            instance_id: second_id,
+            overshoot: 0.0,
+        }))
+        .await
+        .unwrap();
+
+    // 待機完了後、オフセットを差し引いた位置から再生される
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == second_id)
+    );
+    assert!(matches!(
+        audio_rx.recv().await.unwrap(),
+        AudioCommand::SeekTo { id, position } if id == second_id && position == 1.0
+    ));
+}
+
+#[tokio::test]
+async fn playlist_group_seek_lands_in_child_at_offset() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let group_id = Uuid::new_v4();
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let cues = vec![
+        make_playlist_group_cue(group_id, None, vec![first_id, second_id], false),
+        make_audio_cue(first_id, Some(group_id), path.clone()),
+        make_audio_cue(second_id, Some(group_id), path),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, _wait_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_wait(cues, vec![group_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(group_id))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == group_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Started { cue_id, .. } if cue_id == group_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == first_id
+    ));
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == first_id)
+    );
+
+    engine_event_tx
+        .send(EngineEvent::Audio(AudioEngineEvent::Started {
+            instance_id: first_id,
+            position: 0.0,
+            duration: 5.0,
+            initial_params: AudioStateParam::default(),
+        }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Started { cue_id, .. } if cue_id == first_id
+    ));
+
+    // 進捗はグループの通算位置と長さとしても報告される
+    engine_event_tx
+        .send(EngineEvent::Audio(AudioEngineEvent::Progress {
+            instance_id: first_id,
+            position: 1.0,
+            duration: 5.0,
+        }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Progress { cue_id, .. } if cue_id == first_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Progress { cue_id, duration, .. } if cue_id == group_id && duration == 5.0
+    ));
+
+    // グループを7秒地点へシーク（1つ目の子は5秒）
+    exec_tx
+        .send(ExecutorCommand::SeekTo(group_id, 7.0))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Seeked { cue_id, position } if cue_id == group_id && position == 7.0
+    ));
+    // 範囲外の子は停止される
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::HardStop { id } if id == first_id)
+    );
+    // 2つ目の子が差分の位置から再生される
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == second_id)
+    );
+    assert!(matches!(
+        audio_rx.recv().await.unwrap(),
+        AudioCommand::SeekTo { id, position } if id == second_id && position == 2.0
+    ));
+}
+
+fn with_chain(mut cue: Cue, chain: CueChain) -> Cue {
+    cue.chain = chain;
+    cue
+}
+
+#[tokio::test]
+async fn post_wait_delays_chain_target_with_own_timer() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let cues = vec![
+        with_chain(
+            make_audio_cue(first_id, None, path.clone()),
+            CueChain::AfterStart {
+                target_id: Some(second_id),
+                post_wait: 2.5,
+            },
+        ),
+        make_audio_cue(second_id, None, path),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, mut wait_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_wait(cues, vec![first_id, second_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(first_id))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == first_id
+    ));
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == first_id)
+    );
+    // チェーン先は即座に実行されず、ポストウェイトのタイマーが開始される
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Start { wait_type: WaitType::PostWait, instance_id, duration }
+            if instance_id == first_id && duration == 2.5
+    ));
+
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Started {
+            instance_id: first_id,
+            position: 0.0,
+            duration: 2.5,
+        }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::PostWaitStarted { cue_id, duration } if cue_id == first_id && duration == 2.5
+    ));
+
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Completed {
+            instance_id: first_id,
+            overshoot: 0.0,
+        }))
+        .await
+        .unwrap();
+
+    // ポストウェイト完了後にチェーン先が実行される
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::PostWaitCompleted { cue_id } if cue_id == first_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == second_id
+    ));
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == second_id)
+    );
+}
+
+#[tokio::test]
+async fn pausing_during_post_wait_holds_chain_target() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let cues = vec![
+        with_chain(
+            make_audio_cue(first_id, None, path.clone()),
+            CueChain::AfterStart {
+                target_id: Some(second_id),
+                post_wait: 2.5,
+            },
+        ),
+        make_audio_cue(second_id, None, path),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, mut wait_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_wait(cues, vec![first_id, second_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(first_id))
+        .await
+        .unwrap();
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == first_id)
+    );
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Start { wait_type: WaitType::PostWait, instance_id, .. } if instance_id == first_id
+    ));
+
+    // ポーズはキュー本体とポストウェイトの両方に届く
+    exec_tx
+        .send(ExecutorCommand::Pause(first_id))
+        .await
+        .unwrap();
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Pause { wait_type: WaitType::PostWait, instance_id } if instance_id == first_id
+    ));
+    assert!(matches!(audio_rx.recv().await.unwrap(), AudioCommand::Pause { id } if id == first_id));
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Paused {
+            instance_id: first_id,
+            position: 1.0,
+            duration: 2.5,
+        }))
+        .await
+        .unwrap();
+
+    // 一時停止中はチェーン先が実行されない
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(audio_rx.try_recv().is_err());
+    while let Ok(event) = playback_event_rx.try_recv() {
+        assert!(!matches!(event, ExecutorEvent::Triggered { cue_id } if cue_id == second_id));
+    }
+
+    exec_tx
+        .send(ExecutorCommand::Resume(first_id))
+        .await
+        .unwrap();
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Resume { wait_type: WaitType::PostWait, instance_id } if instance_id == first_id
+    ));
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Resumed {
+            instance_id: first_id,
+        }))
+        .await
+        .unwrap();
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Completed {
+            instance_id: first_id,
+            overshoot: 0.0,
+        }))
+        .await
+        .unwrap();
+
+    // 遅延はタイマーの超過分だけで、ポーズ時間は含まれない
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Resume { id } if id == first_id)
+    );
+    assert!(matches!(
+        audio_rx.recv().await.unwrap(),
+        AudioCommand::Play { id, .. } if id == second_id
+    ));
+    assert!(audio_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn stop_cancels_pending_post_wait() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let cues = vec![
+        with_chain(
+            make_audio_cue(first_id, None, path.clone()),
+            CueChain::AfterComplete {
+                target_id: None,
+                post_wait: 1.0,
+            },
+        ),
+        make_audio_cue(second_id, None, path),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, mut wait_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_wait(cues, vec![first_id, second_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(first_id))
+        .await
+        .unwrap();
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == first_id)
+    );
+
+    engine_event_tx
+        .send(EngineEvent::Audio(AudioEngineEvent::Completed {
+            instance_id: first_id,
+        }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Start { wait_type: WaitType::PostWait, instance_id, duration }
+            if instance_id == first_id && duration == 1.0
+    ));
+
+    // 完了済みのキューを停止するとポストウェイトも取り消される
+    exec_tx
+        .send(ExecutorCommand::Stop(first_id, StopMode::Soft))
+        .await
+        .unwrap();
+    assert!(matches!(
+        wait_rx.recv().await.unwrap(),
+        WaitCommand::Stop { wait_type: WaitType::PostWait, instance_id } if instance_id == first_id
+    ));
+
+    engine_event_tx
+        .send(EngineEvent::PostWait(WaitEvent::Stopped {
+            instance_id: first_id,
+        }))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == first_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Completed { cue_id } if cue_id == first_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Stopped { cue_id } if cue_id == first_id
+    ));
+    assert!(audio_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn disarmed_playlist_child_is_skipped() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let group_id = Uuid::new_v4();
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+
+    let mut disarmed = make_audio_cue(first_id, Some(group_id), path.clone());
+    disarmed.armed = false;
+    let cues = vec![
+        make_playlist_group_cue(group_id, None, vec![first_id, second_id], false),
+        disarmed,
+        make_audio_cue(second_id, Some(group_id), path),
+    ];
+
+    let (_manager, exec_tx, mut audio_rx, engine_event_tx, mut playback_event_rx) =
+        setup_executor_with_cues(cues, vec![group_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(group_id))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == group_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Started { cue_id, .. } if cue_id == group_id
+    ));
+    // 無効化された子はイベントを出さずにスキップされ、次の子が再生される
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Triggered { cue_id } if cue_id == second_id
+    ));
+    assert!(
+        matches!(audio_rx.recv().await.unwrap(), AudioCommand::Play { id, .. } if id == second_id)
+    );
+
+    engine_event_tx
+        .send(EngineEvent::Audio(AudioEngineEvent::Completed {
+            instance_id: second_id,
+        }))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Completed { cue_id } if cue_id == second_id
+    ));
+    assert!(matches!(
+        playback_event_rx.recv().await.unwrap(),
+        ExecutorEvent::Completed { cue_id } if cue_id == group_id
+    ));
+}
+
+#[tokio::test]
+async fn group_volume_trims_add_up_for_descendants() {
+    let temp_target = NamedTempFile::with_suffix(".flac").unwrap();
+    let path = temp_target.path().to_path_buf();
+
+    let outer_id = Uuid::new_v4();
+    let inner_id = Uuid::new_v4();
+    let child_id = Uuid::new_v4();
+
+    let mut outer = make_concurrency_group_cue(outer_id, None, vec![inner_id]);
+    let mut inner = make_concurrency_group_cue(inner_id, Some(outer_id), vec![child_id]);
+    for (cue, trim) in [(&mut outer, -3.0), (&mut inner, -6.0)] {
+        if let model::cue::CueParam::Group { base, .. } = &mut cue.params {
+            base.volume = Decibels::from(trim);
+        }
+    }
+    let cues = vec![outer, inner, make_audio_cue(child_id, Some(inner_id), path)];
+
+    let (_manager, exec_tx, mut audio_rx, _, _playback_event_rx) =
+        setup_executor_with_cues(cues, vec![outer_id]).await;
+
+    exec_tx
+        .send(ExecutorCommand::Execute(child_id))
+        .await
+        .unwrap();
+
+    if let Some(AudioCommand::Play { id, data }) = audio_rx.recv().await {
+        assert_eq!(id, child_id);
+        assert_eq!(data.trim, Decibels::from(-9.0));
+    } else {
+        unreachable!();
+    }
+}
@@ -0,0 +1,459 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+mod qlab;
+mod table;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, bail};
+
+use crate::model::cue::{
+    Cue, CueChain, CueColor, CueList, CueParam, LoadCueParam, PauseCueParam, StartCueParam,
+    StopCueParam, Uuid, WaitCueParam,
+    audio::{AudioCueParam, SoundType},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Tsv,
+    QLabJson,
+}
+
+impl ImportFormat {
+    /// Guesses the format from the file extension. Plain text files are
+    /// sniffed for tabs, which is how QLab exports its cue list as text.
+    pub fn detect(path: &Path, content: &str) -> anyhow::Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("csv") => Ok(Self::Csv),
+            Some("tsv") | Some("tab") => Ok(Self::Tsv),
+            Some("json") => Ok(Self::QLabJson),
+            Some("txt") | None => {
+                let first_line = content.lines().next().unwrap_or_default();
+                if first_line.contains('\t') {
+                    Ok(Self::Tsv)
+                } else {
+                    Ok(Self::Csv)
+                }
+            }
+            Some(extension) => bail!("Unsupported cue sheet format: .{}", extension),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub cue_list: CueList,
+    /// Audio files referenced by the sheet that could not be found.
+    /// Their cues are still created and point at the missing path.
+    pub unresolved_files: Vec<PathBuf>,
+    pub warnings: Vec<String>,
+}
+
+impl ImportReport {
+    /// Cues in sheet order, ready for `ModelCommand::AddCues`.
+    pub fn into_cues(mut self) -> Vec<Cue> {
+        self.cue_list
+            .root_ids
+            .iter()
+            .filter_map(|id| self.cue_list.cues.remove(id))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RowKind {
+    Audio,
+    Memo,
+    Wait,
+    Start,
+    Stop,
+    Pause,
+    Load,
+    Unsupported(String),
+}
+
+impl RowKind {
+    fn parse(value: &str) -> Self {
+        match normalize(value).as_str() {
+            "audio" | "sound" | "music" => Self::Audio,
+            "" | "memo" | "group" | "cuelist" => Self::Memo,
+            "wait" => Self::Wait,
+            "start" => Self::Start,
+            "stop" => Self::Stop,
+            "pause" => Self::Pause,
+            "load" => Self::Load,
+            _ => Self::Unsupported(value.trim().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ChainMode {
+    DoNotChain,
+    AfterStart,
+    AfterComplete,
+}
+
+impl ChainMode {
+    fn parse(value: &str) -> Option<Self> {
+        match normalize(value).as_str() {
+            "" | "no" | "none" | "off" | "false" | "0" | "donotcontinue" | "donotchain" => {
+                Some(Self::DoNotChain)
+            }
+            "autocontinue" | "continue" | "afterstart" | "yes" | "true" | "1" => {
+                Some(Self::AfterStart)
+            }
+            "autofollow" | "follow" | "aftercomplete" | "2" => Some(Self::AfterComplete),
+            _ => None,
+        }
+    }
+}
+
+/// A cue sheet row in a format-independent shape.
+#[derive(Debug, Clone)]
+struct SheetRow {
+    number: String,
+    name: Option<String>,
+    kind: Option<RowKind>,
+    file: Option<String>,
+    /// Number of the cue a control cue acts on.
+    target: Option<String>,
+    volume: Option<f32>,
+    pre_wait: f64,
+    duration: Option<f64>,
+    post_wait: f64,
+    chain: ChainMode,
+    notes: String,
+    armed: bool,
+    color: CueColor,
+}
+
+impl Default for SheetRow {
+    fn default() -> Self {
+        Self {
+            number: String::new(),
+            name: None,
+            kind: None,
+            file: None,
+            target: None,
+            volume: None,
+            pre_wait: 0.0,
+            duration: None,
+            post_wait: 0.0,
+            chain: ChainMode::DoNotChain,
+            notes: String::new(),
+            armed: true,
+            color: CueColor::None,
+        }
+    }
+}
+
+/// Reads a cue sheet and converts it to a flat cue list. File paths in the
+/// sheet are resolved relative to the sheet's directory.
+pub async fn import_cue_sheet(path: &Path) -> anyhow::Result<ImportReport> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let format = ImportFormat::detect(&path, &content)?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        parse_cue_sheet(&content, format, base_dir)
+    })
+    .await?
+}
+
+pub fn parse_cue_sheet(
+    content: &str,
+    format: ImportFormat,
+    base_dir: &Path,
+) -> anyhow::Result<ImportReport> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut warnings = Vec::new();
+    let rows = match format {
+        ImportFormat::Csv => table::parse_rows(content, ',', &mut warnings)?,
+        ImportFormat::Tsv => table::parse_rows(content, '\t', &mut warnings)?,
+        ImportFormat::QLabJson => qlab::parse_rows(content, &mut warnings)?,
+    };
+    Ok(build_cues(rows, base_dir, warnings))
+}
+
+fn build_cues(rows: Vec<SheetRow>, base_dir: &Path, mut warnings: Vec<String>) -> ImportReport {
+    let ids: Vec<Uuid> = rows.iter().map(|_| Uuid::new_v4()).collect();
+    let mut number_to_id = HashMap::new();
+    for (row, id) in rows.iter().zip(&ids) {
+        if !row.number.is_empty() {
+            number_to_id.entry(row.number.clone()).or_insert(*id);
+        }
+    }
+
+    let mut report = ImportReport::default();
+    for (row, id) in rows.into_iter().zip(ids) {
+        let label = if row.number.is_empty() {
+            row.name.clone().unwrap_or_default()
+        } else {
+            row.number.clone()
+        };
+        let kind = row.kind.clone().unwrap_or(if row.file.is_some() {
+            RowKind::Audio
+        } else {
+            RowKind::Memo
+        });
+        let mut notes = row.notes.clone();
+
+        let resolve_target = |warnings: &mut Vec<String>| {
+            let target = row
+                .target
+                .as_ref()
+                .and_then(|number| number_to_id.get(number.trim()).copied());
+            if target.is_none() {
+                warnings.push(format!(
+                    "Cue {}: target cue {} not found, imported as a memo.",
+                    label,
+                    row.target.as_deref().unwrap_or("(none)")
+                ));
+            }
+            target
+        };
+        let params = match kind {
+            RowKind::Audio => match row.file.as_deref().map(str::trim) {
+                Some(file) if !file.is_empty() => {
+                    let mut target = PathBuf::from(file);
+                    if target.is_relative() {
+                        target = base_dir.join(target);
+                    }
+                    if !target.is_file() {
+                        report.unresolved_files.push(target.clone());
+                    }
+                    Some(CueParam::Audio(AudioCueParam {
+                        target,
+                        start_time: None,
+                        fade_in_param: None,
+                        end_time: None,
+                        fade_out_param: None,
+                        volume: row.volume.unwrap_or_default().into(),
+                        pan: 0.0,
+                        repeat: false,
+                        sound_type: SoundType::Streaming,
+                        envelope: vec![],
+                        original_path: None,
+                    }))
+                }
+                _ => {
+                    warnings.push(format!(
+                        "Cue {}: audio cue has no file, imported as a memo.",
+                        label
+                    ));
+                    None
+                }
+            },
+            RowKind::Memo => None,
+            RowKind::Wait => Some(CueParam::Wait(WaitCueParam {
+                duration: row.duration.unwrap_or_default(),
+            })),
+            RowKind::Start => resolve_target(&mut warnings)
+                .map(|target| CueParam::Start(StartCueParam { target })),
+            RowKind::Stop => resolve_target(&mut warnings).map(|target| {
+                CueParam::Stop(StopCueParam {
+                    target,
+                    hard: false,
+                })
+            }),
+            RowKind::Pause => resolve_target(&mut warnings)
+                .map(|target| CueParam::Pause(PauseCueParam { target })),
+            RowKind::Load => {
+                resolve_target(&mut warnings).map(|target| CueParam::Load(LoadCueParam { target }))
+            }
+            RowKind::Unsupported(kind) => {
+                warnings.push(format!(
+                    "Cue {}: unsupported cue type \"{}\", imported as a memo.",
+                    label, kind
+                ));
+                if notes.is_empty() {
+                    notes = format!("Imported {} cue", kind);
+                } else {
+                    notes = format!("Imported {} cue\n{}", kind, notes);
+                }
+                None
+            }
+        };
+        let params = params.unwrap_or(CueParam::Wait(WaitCueParam { duration: 0.0 }));
+
+        let chain = match row.chain {
+            ChainMode::DoNotChain => CueChain::DoNotChain,
+            ChainMode::AfterStart => CueChain::AfterStart {
+                target_id: None,
+                post_wait: row.post_wait,
+            },
+            ChainMode::AfterComplete => CueChain::AfterComplete {
+                target_id: None,
+                post_wait: row.post_wait,
+            },
+        };
+
+        report.cue_list.root_ids.push(id);
+        report.cue_list.cues.insert(
+            id,
+            Cue {
+                id,
+                number: row.number,
+                name: row.name.filter(|name| !name.is_empty()),
+                notes,
+                color: row.color,
+                armed: row.armed,
+                pre_wait: row.pre_wait,
+                timeline_offset: 0.0,
+                chain,
+                parent_id: None,
+                params,
+            },
+        );
+    }
+    report.warnings = warnings;
+    report
+}
+
+/// Lowercases and strips everything but letters and digits, so that
+/// "Pre-Wait", "pre wait" and "PreWait" compare equal.
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Parses seconds ("12.5") or clock time ("1:02.5", "01:02:03").
+fn parse_time(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Some(0.0);
+    }
+    let mut seconds = 0.0;
+    for part in value.split(':') {
+        let part: f64 = part.trim().parse().ok()?;
+        if part < 0.0 {
+            return None;
+        }
+        seconds = seconds * 60.0 + part;
+    }
+    Some(seconds)
+}
+
+/// Parses a level in decibels, tolerating a "dB" suffix and "-inf".
+fn parse_volume(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let value = value
+        .strip_suffix("dB")
+        .or_else(|| value.strip_suffix("db"))
+        .unwrap_or(value)
+        .trim();
+    if value.is_empty() {
+        return Some(0.0);
+    }
+    if value.eq_ignore_ascii_case("-inf") {
+        return Some(-60.0);
+    }
+    value.parse::<f32>().ok().map(|volume| volume.max(-60.0))
+}
+
+fn parse_color(value: &str) -> CueColor {
+    match normalize(value).as_str() {
+        "red" => CueColor::Red,
+        "purple" => CueColor::Purple,
+        "blue" => CueColor::Blue,
+        "cyan" => CueColor::Cyan,
+        "green" => CueColor::Green,
+        "yellow" => CueColor::Yellow,
+        "orange" => CueColor::Orange,
+        "grey" | "gray" => CueColor::Grey,
+        _ => CueColor::None,
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match normalize(value).as_str() {
+        "" | "yes" | "true" | "1" | "armed" => Some(true),
+        "no" | "false" | "0" | "disarmed" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::model::cue::{CueChain, CueParam};
+
+    use super::{ImportFormat, parse_cue_sheet};
+
+    #[test]
+    fn import_csv_sheet() {
+        let sheet = "\
+Number,Name,File,Volume,Pre-Wait,Continue,Post-Wait,Notes
+1,Preshow,music/preshow.wav,-6 dB,0,Auto-follow,2,\"House open, \"\"walk-in\"\"\"
+2,Blackout,,,1:30,,,
+";
+        let report = parse_cue_sheet(sheet, ImportFormat::Csv, Path::new("/show")).unwrap();
+        let cues = report.clone().into_cues();
+
+        assert_eq!(cues.len(), 2);
+        let CueParam::Audio(audio) = &cues[0].params else {
+            panic!("expected an audio cue");
+        };
+        assert_eq!(audio.target, Path::new("/show/music/preshow.wav"));
+        assert_eq!(f32::from(audio.volume), -6.0);
+        assert_eq!(
+            cues[0].chain,
+            CueChain::AfterComplete {
+                target_id: None,
+                post_wait: 2.0
+            }
+        );
+        assert_eq!(cues[0].notes, "House open, \"walk-in\"");
+        assert_eq!(cues[1].name.as_deref(), Some("Blackout"));
+        assert_eq!(cues[1].pre_wait, 90.0);
+        assert!(matches!(cues[1].params, CueParam::Wait(_)));
+        assert_eq!(
+            report.unresolved_files,
+            vec![Path::new("/show/music/preshow.wav").to_path_buf()]
+        );
+    }
+
+    #[test]
+    fn import_qlab_json() {
+        let export = r#"[{
+            "uniqueID": "list",
+            "type": "Cue List",
+            "listName": "Main",
+            "cues": [
+                { "number": "1", "name": "Song", "type": "Audio", "fileTarget": "/missing/song.wav", "continueMode": 1 },
+                { "number": "2", "type": "Group", "name": "Scene", "cues": [
+                    { "number": "3", "type": "Stop", "cueTargetNumber": "1", "preWait": 5 },
+                    { "number": "4", "type": "Video", "name": "Projection" }
+                ]}
+            ]
+        }]"#;
+        let report = parse_cue_sheet(export, ImportFormat::QLabJson, Path::new("/")).unwrap();
+        let warnings = report.warnings.clone();
+        let cues = report.into_cues();
+
+        assert_eq!(cues.len(), 4);
+        assert!(matches!(cues[0].chain, CueChain::AfterStart { .. }));
+        let CueParam::Stop(stop) = &cues[2].params else {
+            panic!("expected a stop cue");
+        };
+        assert_eq!(stop.target, cues[0].id);
+        assert_eq!(cues[2].pre_wait, 5.0);
+        assert!(matches!(cues[3].params, CueParam::Wait(_)));
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use anyhow::{Context, bail};
+use serde_json::{Map, Value};
+
+use super::{ChainMode, RowKind, SheetRow, normalize, parse_color, parse_volume};
+
+/// Parses QLab's JSON cue list export. Cue lists and groups are flattened
+/// in playback order; a group becomes a memo in front of its children.
+pub(super) fn parse_rows(
+    content: &str,
+    warnings: &mut Vec<String>,
+) -> anyhow::Result<Vec<SheetRow>> {
+    let document: Value = serde_json::from_str(content).context("Invalid QLab JSON export")?;
+    let cues = match &document {
+        Value::Array(cues) => cues,
+        Value::Object(object) => match object.get("data").or_else(|| object.get("cues")) {
+            Some(Value::Array(cues)) => cues,
+            _ => bail!("QLab export has no cues."),
+        },
+        _ => bail!("QLab export root must be an array or an object."),
+    };
+
+    let mut rows = Vec::new();
+    collect_rows(cues, &mut rows, warnings);
+    Ok(rows)
+}
+
+fn collect_rows(cues: &[Value], rows: &mut Vec<SheetRow>, warnings: &mut Vec<String>) {
+    for cue in cues.iter().filter_map(Value::as_object) {
+        let kind = string_field(cue, "type").unwrap_or_default();
+        let is_list = matches!(normalize(&kind).as_str(), "cuelist" | "cart");
+        if !is_list {
+            rows.push(parse_row(cue, &kind, warnings));
+        }
+        if let Some(Value::Array(children)) = cue.get("cues") {
+            collect_rows(children, rows, warnings);
+        }
+    }
+}
+
+fn parse_row(cue: &Map<String, Value>, kind: &str, warnings: &mut Vec<String>) -> SheetRow {
+    let mut row = SheetRow {
+        number: string_field(cue, "number").unwrap_or_default(),
+        name: string_field(cue, "name").or_else(|| string_field(cue, "listName")),
+        kind: Some(RowKind::parse(kind)),
+        file: string_field(cue, "fileTarget").filter(|file| !file.is_empty()),
+        target: string_field(cue, "cueTargetNumber").filter(|target| !target.is_empty()),
+        pre_wait: number_field(cue, "preWait").unwrap_or_default(),
+        duration: number_field(cue, "duration"),
+        post_wait: number_field(cue, "postWait").unwrap_or_default(),
+        notes: string_field(cue, "notes").unwrap_or_default(),
+        armed: cue.get("armed").and_then(Value::as_bool).unwrap_or(true),
+        color: string_field(cue, "colorName")
+            .map(|color| parse_color(&color))
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+    row.volume = match cue.get("volume") {
+        Some(Value::Number(volume)) => volume.as_f64().map(|volume| volume as f32),
+        Some(Value::String(volume)) => parse_volume(volume),
+        _ => None,
+    };
+    row.chain = match cue.get("continueMode") {
+        None => ChainMode::DoNotChain,
+        Some(Value::Number(mode)) => match mode.as_u64() {
+            Some(1) => ChainMode::AfterStart,
+            Some(2) => ChainMode::AfterComplete,
+            _ => ChainMode::DoNotChain,
+        },
+        Some(mode) => {
+            let mode = mode.as_str().unwrap_or_default();
+            ChainMode::parse(mode).unwrap_or_else(|| {
+                warnings.push(format!(
+                    "Cue {}: unknown continue mode \"{}\".",
+                    row.number, mode
+                ));
+                ChainMode::DoNotChain
+            })
+        }
+    };
+    row
+}
+
+/// Reads a field that QLab may export either as a string or a number.
+fn string_field(cue: &Map<String, Value>, key: &str) -> Option<String> {
+    match cue.get(key)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+fn number_field(cue: &Map<String, Value>, key: &str) -> Option<f64> {
+    match cue.get(key)? {
+        Value::Number(value) => value.as_f64(),
+        Value::String(value) => value.trim().parse().ok(),
+        _ => None,
+    }
+}
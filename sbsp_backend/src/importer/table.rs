@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use anyhow::bail;
+
+use super::{
+    ChainMode, RowKind, SheetRow, normalize, parse_bool, parse_color, parse_time, parse_volume,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Number,
+    Name,
+    Type,
+    File,
+    Target,
+    Volume,
+    PreWait,
+    Duration,
+    PostWait,
+    Chain,
+    Notes,
+    Armed,
+    Color,
+}
+
+impl Column {
+    fn from_header(header: &str) -> Option<Self> {
+        let column = match normalize(header).as_str() {
+            "number" | "no" | "num" | "cue" | "cueno" | "cuenumber" => Self::Number,
+            "name" | "q" | "qname" | "title" | "cuename" | "description" => Self::Name,
+            "type" | "cuetype" | "kind" => Self::Type,
+            "file" | "filename" | "filetarget" | "path" | "audio" | "sound" => Self::File,
+            "target" => Self::Target,
+            "volume" | "vol" | "level" | "db" | "gain" => Self::Volume,
+            "prewait" | "predelay" | "delay" => Self::PreWait,
+            "duration" | "action" | "length" => Self::Duration,
+            "postwait" => Self::PostWait,
+            "continue" | "continuemode" | "chain" | "follow" | "autocontinue" => Self::Chain,
+            "notes" | "note" | "comment" | "comments" | "memo" => Self::Notes,
+            "armed" => Self::Armed,
+            "color" | "colour" | "colorname" => Self::Color,
+            _ => return None,
+        };
+        Some(column)
+    }
+}
+
+/// Parses a CSV/TSV cue sheet whose first record is a header row.
+/// Unknown columns are ignored.
+pub(super) fn parse_rows(
+    content: &str,
+    delimiter: char,
+    warnings: &mut Vec<String>,
+) -> anyhow::Result<Vec<SheetRow>> {
+    let mut records = split_records(content, delimiter).into_iter();
+    let Some(header) = records.next() else {
+        bail!("Cue sheet is empty.");
+    };
+    let columns: Vec<Option<Column>> = header.iter().map(|h| Column::from_header(h)).collect();
+    if !columns
+        .iter()
+        .any(|column| matches!(column, Some(Column::Number) | Some(Column::Name)))
+    {
+        bail!("Cue sheet header must have a number or name column.");
+    }
+
+    let mut rows = Vec::new();
+    for (index, record) in records.enumerate() {
+        // header is line 1
+        let line = index + 2;
+        let mut row = SheetRow::default();
+        for (column, value) in columns.iter().zip(&record) {
+            let Some(column) = column else {
+                continue;
+            };
+            let value = value.trim();
+            match column {
+                Column::Number => row.number = value.to_string(),
+                Column::Name => row.name = Some(value.to_string()),
+                Column::Type => {
+                    if !value.is_empty() {
+                        row.kind = Some(RowKind::parse(value))
+                    }
+                }
+                Column::File => {
+                    if !value.is_empty() {
+                        row.file = Some(value.to_string())
+                    }
+                }
+                Column::Target => {
+                    if !value.is_empty() {
+                        row.target = Some(value.to_string())
+                    }
+                }
+                Column::Volume => match parse_volume(value) {
+                    Some(volume) => row.volume = Some(volume),
+                    None => warnings.push(format!("Line {}: invalid volume \"{}\".", line, value)),
+                },
+                Column::PreWait => match parse_time(value) {
+                    Some(time) => row.pre_wait = time,
+                    None => {
+                        warnings.push(format!("Line {}: invalid pre-wait \"{}\".", line, value))
+                    }
+                },
+                Column::Duration => {
+                    if !value.is_empty() {
+                        row.duration = parse_time(value)
+                    }
+                }
+                Column::PostWait => match parse_time(value) {
+                    Some(time) => row.post_wait = time,
+                    None => {
+                        warnings.push(format!("Line {}: invalid post-wait \"{}\".", line, value))
+                    }
+                },
+                Column::Chain => match ChainMode::parse(value) {
+                    Some(chain) => row.chain = chain,
+                    None => warnings.push(format!(
+                        "Line {}: unknown continue mode \"{}\".",
+                        line, value
+                    )),
+                },
+                Column::Notes => row.notes = value.to_string(),
+                Column::Armed => row.armed = parse_bool(value).unwrap_or(true),
+                Column::Color => row.color = parse_color(value),
+            }
+        }
+        // a "target" column holds the file for audio cues and a cue number otherwise
+        if row.file.is_none()
+            && matches!(row.kind, Some(RowKind::Audio))
+            && let Some(target) = row.target.take()
+        {
+            row.file = Some(target);
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Splits delimited text into records, handling RFC 4180 quoting.
+/// Blank lines are skipped.
+fn split_records(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|field| !field.trim().is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                } else {
+                    record.clear();
+                }
+            }
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|field| !field.trim().is_empty()) {
+        records.push(record);
+    }
+    records
+}
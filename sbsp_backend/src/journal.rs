@@ -0,0 +1,472 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+mod entry;
+mod handle;
+
+pub use entry::{JournalEntry, JournalEntryKind, JournalQuery};
+pub(crate) use handle::JournalCommand;
+pub use handle::ShowJournalHandle;
+
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use tokio::sync::{broadcast, mpsc};
+use uuid::Uuid;
+
+use crate::{
+    event::{BackendError, BackendEvent, CueStatusEventParam, GoSuppressedReason},
+    model::cue::{Cue, CueList},
+};
+
+/// Journal file kept beside a saved project, e.g. `show.journal.jsonl` for `show.sbsp`.
+pub fn journal_path(project_path: &Path) -> PathBuf {
+    let stem = project_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    project_path.with_file_name(format!("{}.journal.jsonl", stem))
+}
+
+/// Records what happened during a show: cue triggers, stops, errors, ignored GOs,
+/// cue edits and failed operations, each with a wall-clock timestamp.
+pub struct ShowJournal {
+    command_rx: mpsc::Receiver<JournalCommand>,
+    event_rx: broadcast::Receiver<BackendEvent>,
+    /// Last known cues, used to resolve cue numbers and to diff edits.
+    cues: HashMap<Uuid, Cue>,
+    entries: Vec<JournalEntry>,
+    path: Option<PathBuf>,
+}
+
+impl ShowJournal {
+    pub fn new(event_tx: &broadcast::Sender<BackendEvent>) -> (Self, ShowJournalHandle) {
+        let (command_tx, command_rx) = mpsc::channel::<JournalCommand>(32);
+        (
+            Self {
+                command_rx,
+                event_rx: event_tx.subscribe(),
+                cues: HashMap::new(),
+                entries: Vec::new(),
+                path: None,
+            },
+            ShowJournalHandle { command_tx },
+        )
+    }
+
+    pub async fn run(mut self) {
+        loop {
+            tokio::select! {
+                command = self.command_rx.recv() => {
+                    match command {
+                        Some(JournalCommand::Query { query, responder }) => {
+                            let mut entries: Vec<JournalEntry> = self
+                                .entries
+                                .iter()
+                                .filter(|entry| query.matches(entry))
+                                .cloned()
+                                .collect();
+                            if let Some(limit) = query.limit {
+                                entries.drain(..entries.len().saturating_sub(limit));
+                            }
+                            if responder.send(entries).is_err() {
+                                log::warn!("Journal query requester has gone away.");
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                result = self.event_rx.recv() => {
+                    match result {
+                        Ok(event) => self.handle_event(event).await,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                        Err(broadcast::error::RecvError::Lagged(count)) => {
+                            log::warn!("Journal missed {} events.", count);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn handle_event(&mut self, event: BackendEvent) {
+        match event {
+            BackendEvent::CueStatus(status) => {
+                let (kind, cue_id, message) = match status {
+                    CueStatusEventParam::Triggered { cue_id } => {
+                        (JournalEntryKind::Triggered, cue_id, None)
+                    }
+                    CueStatusEventParam::Paused { cue_id, .. } => {
+                        (JournalEntryKind::Paused, cue_id, None)
+                    }
+                    CueStatusEventParam::Resumed { cue_id } => {
+                        (JournalEntryKind::Resumed, cue_id, None)
+                    }
+                    CueStatusEventParam::Stopped { cue_id } => {
+                        (JournalEntryKind::Stopped, cue_id, None)
+                    }
+                    CueStatusEventParam::Error { cue_id, error } => {
+                        (JournalEntryKind::Error, cue_id, Some(error))
+                    }
+                    _ => return,
+                };
+                self.record(kind, Some(cue_id), message).await;
+            }
+            BackendEvent::GoSuppressed { cue_id, reason, .. } => {
+                let message = match reason {
+                    GoSuppressedReason::Lockout => "Within the GO lockout",
+                    GoSuppressedReason::ConfirmRequired => "Cue is running, GO again to restart",
+                };
+                self.record(
+                    JournalEntryKind::GoSuppressed,
+                    Some(cue_id),
+                    Some(message.to_string()),
+                )
+                .await;
+            }
+            BackendEvent::CueListUpdated { cue_list } => {
+                self.record_edits(cue_list).await;
+            }
+            BackendEvent::OperationFailed { error } => {
+                self.record(
+                    JournalEntryKind::OperationFailed,
+                    None,
+                    Some(describe_error(&error)),
+                )
+                .await;
+            }
+            BackendEvent::ShowModelLoaded { model, path, .. } => {
+                self.cues = model.cue_list.cues;
+                self.entries = read_journal(&journal_path(&path)).await;
+                self.path = Some(journal_path(&path));
+                self.record(
+                    JournalEntryKind::ShowLoaded,
+                    None,
+                    Some(path.display().to_string()),
+                )
+                .await;
+            }
+            BackendEvent::ShowModelSaved { path, .. } => {
+                let new_path = journal_path(&path);
+                if self.path.as_ref() != Some(&new_path) {
+                    // the journal follows the project, so carry over what was
+                    // recorded before the first save or under the old name,
+                    // after whatever a show saved there before has journaled
+                    let mut entries = read_journal(&new_path).await;
+                    if let Err(e) = append_entries(&new_path, &self.entries).await {
+                        log::error!("Failed to write show journal. path={:?}, e={}", new_path, e);
+                    }
+                    entries.append(&mut self.entries);
+                    self.entries = entries;
+                    self.path = Some(new_path);
+                }
+                self.record(
+                    JournalEntryKind::ShowSaved,
+                    None,
+                    Some(path.display().to_string()),
+                )
+                .await;
+            }
+            BackendEvent::ShowModelReset { model } => {
+                self.cues = model.cue_list.cues;
+                self.entries.clear();
+                self.path = None;
+            }
+            _ => {}
+        }
+    }
+
+    async fn record_edits(&mut self, cue_list: CueList) {
+        let mut changes = Vec::new();
+        for (id, cue) in &cue_list.cues {
+            match self.cues.get(id) {
+                None => changes.push((JournalEntryKind::CueAdded, *id, cue.number.clone())),
+                Some(old) if old != cue => {
+                    changes.push((JournalEntryKind::CueEdited, *id, cue.number.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        for (id, cue) in &self.cues {
+            if !cue_list.cues.contains_key(id) {
+                changes.push((JournalEntryKind::CueRemoved, *id, cue.number.clone()));
+            }
+        }
+        self.cues = cue_list.cues;
+        for (kind, cue_id, cue_number) in changes {
+            self.push(JournalEntry {
+                timestamp: now(),
+                kind,
+                cue_id: Some(cue_id),
+                cue_number: Some(cue_number),
+                message: None,
+            })
+            .await;
+        }
+    }
+
+    async fn record(
+        &mut self,
+        kind: JournalEntryKind,
+        cue_id: Option<Uuid>,
+        message: Option<String>,
+    ) {
+        let cue_number = cue_id
+            .and_then(|id| self.cues.get(&id))
+            .map(|cue| cue.number.clone());
+        self.push(JournalEntry {
+            timestamp: now(),
+            kind,
+            cue_id,
+            cue_number,
+            message,
+        })
+        .await;
+    }
+
+    async fn push(&mut self, entry: JournalEntry) {
+        if let Some(path) = &self.path
+            && let Err(e) = append_entries(path, std::slice::from_ref(&entry)).await
+        {
+            log::error!("Failed to append to show journal. path={:?}, e={}", path, e);
+        }
+        self.entries.push(entry);
+    }
+}
+
+fn now() -> String {
+    chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false)
+}
+
+fn describe_error(error: &BackendError) -> String {
+    match error {
+        BackendError::SaveToFile { path, message }
+        | BackendError::LoadFromFile { path, message }
+        | BackendError::ExportToFolder { path, message }
+        | BackendError::ImportCueSheet { path, message }
+        | BackendError::ExportCueSheet { path, message }
+        | BackendError::ExportBundle { path, message }
+        | BackendError::RelinkAssets { path, message } => {
+            format!("{} ({})", message, path.display())
+        }
+        BackendError::CueEdit { message } | BackendError::Custom { message, .. } => message.clone(),
+    }
+}
+
+async fn append_entries(path: &Path, entries: &[JournalEntry]) -> anyhow::Result<()> {
+    let lines = render_json_lines(entries);
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(lines.as_bytes())
+    })
+    .await??;
+    Ok(())
+}
+
+/// Reads a journal file, skipping lines that do not parse. A missing file is an empty journal.
+async fn read_journal(path: &Path) -> Vec<JournalEntry> {
+    let Ok(content) = tokio::fs::read_to_string(path).await else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("Skipping unreadable journal line. path={:?}, e={}", path, e);
+                None
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn render_json_lines(entries: &[JournalEntry]) -> String {
+    entries
+        .iter()
+        .filter_map(|entry| serde_json::to_string(entry).ok())
+        .map(|line| line + "\n")
+        .collect()
+}
+
+pub(crate) fn render_csv(entries: &[JournalEntry]) -> String {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    let mut output = String::from("Time,Event,Cue,Message\r\n");
+    for entry in entries {
+        let kind = serde_json::to_value(entry.kind)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_owned))
+            .unwrap_or_default();
+        let fields = [
+            escape(&entry.timestamp),
+            kind,
+            escape(entry.cue_number.as_deref().unwrap_or_default()),
+            escape(entry.message.as_deref().unwrap_or_default()),
+        ];
+        output.push_str(&fields.join(","));
+        output.push_str("\r\n");
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::{
+        manager::project::ProjectType,
+        model::{
+            ShowModel,
+            cue::{CueChain, CueColor, CueParam, WaitCueParam},
+        },
+    };
+
+    fn cue(number: &str) -> Cue {
+        Cue {
+            id: Uuid::new_v4(),
+            number: number.to_string(),
+            name: None,
+            notes: String::new(),
+            color: CueColor::default(),
+            armed: true,
+            pre_wait: 0.0,
+            timeline_offset: 0.0,
+            chain: CueChain::default(),
+            parent_id: None,
+            params: CueParam::Wait(WaitCueParam { duration: 1.0 }),
+        }
+    }
+
+    async fn wait_for(handle: &ShowJournalHandle, count: usize) -> Vec<JournalEntry> {
+        for _ in 0..100 {
+            let entries = handle.query(JournalQuery::default()).await.unwrap();
+            if entries.len() >= count {
+                return entries;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("journal did not record {} entries", count);
+    }
+
+    #[tokio::test]
+    async fn records_playback_and_edits_with_cue_numbers() {
+        let (event_tx, _) = broadcast::channel(32);
+        let (journal, handle) = ShowJournal::new(&event_tx);
+        tokio::spawn(journal.run());
+
+        let mut edited = cue("1");
+        let mut model = ShowModel::default();
+        model.cue_list.cues.insert(edited.id, edited.clone());
+        let dir = tempdir().unwrap();
+        let project_path = dir.path().join("show.sbsp");
+        event_tx
+            .send(BackendEvent::ShowModelLoaded {
+                model: model.clone(),
+                project_type: ProjectType::SingleFile,
+                path: project_path.clone(),
+            })
+            .unwrap();
+        event_tx
+            .send(BackendEvent::CueStatus(CueStatusEventParam::Triggered {
+                cue_id: edited.id,
+            }))
+            .unwrap();
+
+        let added = cue("2");
+        edited.notes = "changed".to_string();
+        let mut cue_list = model.cue_list.clone();
+        cue_list.cues.insert(edited.id, edited.clone());
+        cue_list.cues.insert(added.id, added.clone());
+        event_tx
+            .send(BackendEvent::CueListUpdated { cue_list })
+            .unwrap();
+
+        let entries = wait_for(&handle, 4).await;
+        assert_eq!(entries[0].kind, JournalEntryKind::ShowLoaded);
+        assert_eq!(entries[1].kind, JournalEntryKind::Triggered);
+        assert_eq!(entries[1].cue_number.as_deref(), Some("1"));
+        assert!(entries[2..].iter().any(|entry| {
+            entry.kind == JournalEntryKind::CueEdited && entry.cue_id == Some(edited.id)
+        }));
+        assert!(entries[2..].iter().any(|entry| {
+            entry.kind == JournalEntryKind::CueAdded && entry.cue_number.as_deref() == Some("2")
+        }));
+
+        let triggered = handle
+            .query(JournalQuery {
+                kinds: vec![JournalEntryKind::Triggered],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(triggered.len(), 1);
+
+        // the journal is persisted beside the project and reloaded with it
+        let persisted = read_journal(&dir.path().join("show.journal.jsonl")).await;
+        assert_eq!(persisted, entries);
+    }
+
+    #[tokio::test]
+    async fn save_as_keeps_the_existing_journal() {
+        let (event_tx, _) = broadcast::channel(32);
+        let (journal, handle) = ShowJournal::new(&event_tx);
+        tokio::spawn(journal.run());
+
+        let dir = tempdir().unwrap();
+        let earlier = JournalEntry {
+            timestamp: now(),
+            kind: JournalEntryKind::ShowSaved,
+            cue_id: None,
+            cue_number: None,
+            message: Some("earlier show".to_string()),
+        };
+        let journal_file = dir.path().join("show.journal.jsonl");
+        append_entries(&journal_file, std::slice::from_ref(&earlier))
+            .await
+            .unwrap();
+
+        let first = cue("1");
+        let mut model = ShowModel::default();
+        model.cue_list.cues.insert(first.id, first.clone());
+        event_tx
+            .send(BackendEvent::ShowModelReset { model })
+            .unwrap();
+        event_tx
+            .send(BackendEvent::GoSuppressed {
+                list_id: Uuid::nil(),
+                cue_id: first.id,
+                reason: GoSuppressedReason::Lockout,
+            })
+            .unwrap();
+        wait_for(&handle, 1).await;
+        event_tx
+            .send(BackendEvent::ShowModelSaved {
+                project_type: ProjectType::SingleFile,
+                path: dir.path().join("show.sbsp"),
+            })
+            .unwrap();
+
+        let entries = wait_for(&handle, 3).await;
+        assert_eq!(entries[0], earlier);
+        assert_eq!(entries[1].kind, JournalEntryKind::GoSuppressed);
+        assert_eq!(entries[1].cue_number.as_deref(), Some("1"));
+        assert_eq!(entries[2].kind, JournalEntryKind::ShowSaved);
+        assert_eq!(read_journal(&journal_file).await, entries);
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// Local wall-clock time in RFC 3339.
+    pub timestamp: String,
+    pub kind: JournalEntryKind,
+    pub cue_id: Option<Uuid>,
+    pub cue_number: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum JournalEntryKind {
+    /// A cue was triggered, either by GO or by its predecessor's chain.
+    Triggered,
+    Paused,
+    Resumed,
+    Stopped,
+    Error,
+    /// A GO press was ignored, by the lockout or while waiting for a repeat confirmation.
+    GoSuppressed,
+    CueAdded,
+    CueEdited,
+    CueRemoved,
+    OperationFailed,
+    ShowLoaded,
+    ShowSaved,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct JournalQuery {
+    /// Kinds to include. Empty includes every kind.
+    #[serde(default)]
+    pub kinds: Vec<JournalEntryKind>,
+    #[serde(default)]
+    pub cue_id: Option<Uuid>,
+    /// Keeps only the most recent entries.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl JournalQuery {
+    pub fn matches(&self, entry: &JournalEntry) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&entry.kind))
+            && (self.cue_id.is_none() || self.cue_id == entry.cue_id)
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::path::Path;
+
+use tokio::sync::{mpsc, oneshot};
+
+use super::{JournalEntry, JournalQuery, render_csv, render_json_lines};
+
+pub(crate) enum JournalCommand {
+    Query {
+        query: JournalQuery,
+        responder: oneshot::Sender<Vec<JournalEntry>>,
+    },
+}
+
+#[derive(Clone)]
+pub struct ShowJournalHandle {
+    pub(crate) command_tx: mpsc::Sender<JournalCommand>,
+}
+
+impl ShowJournalHandle {
+    pub async fn query(&self, query: JournalQuery) -> anyhow::Result<Vec<JournalEntry>> {
+        let (responder, result_rx) = oneshot::channel();
+        self.command_tx
+            .send(JournalCommand::Query { query, responder })
+            .await?;
+        Ok(result_rx.await?)
+    }
+
+    /// Writes the whole journal to `path`, as CSV when the extension is `csv`
+    /// and as JSON Lines otherwise.
+    pub async fn export(&self, path: &Path) -> anyhow::Result<()> {
+        let entries = self.query(JournalQuery::default()).await?;
+        let is_csv = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+        let content = if is_csv {
+            render_csv(&entries)
+        } else {
+            render_json_lines(&entries)
+        };
+        tokio::fs::write(path, content).await?;
+        Ok(())
+    }
+}
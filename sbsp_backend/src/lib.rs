@@ -16,10 +16,15 @@ use crate::{
     },
     event::BackendEvent,
     executor::{Executor, ExecutorCommand, ExecutorEvent},
+    journal::{ShowJournal, ShowJournalHandle},
     manager::{ShowModelHandle, ShowModelManager},
     model::settings::ShowAudioSettings,
 };
-use crate::{controller::state::ShowState, manager::project::ProjectStatus, model::ShowModel};
+use crate::{
+    controller::state::ShowState,
+    manager::{history::HistoryStatus, project::ProjectStatus},
+    model::ShowModel,
+};
 
 #[cfg(feature = "type_export")]
 pub use ts_rs;
@@ -30,14 +35,21 @@ pub mod asset_processor;
 #[cfg(feature = "backend")]
 pub mod controller;
 #[cfg(feature = "backend")]
+pub mod cue_sheet;
+#[cfg(feature = "backend")]
 mod engine;
 pub mod event;
 #[cfg(feature = "backend")]
 mod executor;
 pub mod helper;
 #[cfg(feature = "backend")]
+pub mod importer;
+#[cfg(feature = "backend")]
+pub mod journal;
+#[cfg(feature = "backend")]
 pub mod manager;
 pub mod model;
+pub mod preflight;
 
 #[cfg(any(feature = "apiserver", feature = "apiclient"))]
 pub mod api;
@@ -53,12 +65,19 @@ pub mod asset_processor {
 pub mod controller {
     mod command;
     pub mod state;
-    pub use command::ControllerCommand;
+    pub use command::{ControllerCommand, CueRef};
+}
+#[cfg(feature = "type_export")]
+pub mod journal {
+    mod entry;
+    pub use entry::{JournalEntry, JournalEntryKind, JournalQuery};
 }
 #[cfg(feature = "type_export")]
 pub mod manager {
+    pub mod asset_import;
     mod command;
-    pub use command::{InsertPosition, ModelCommand};
+    pub use command::{CueSheetFormat, InsertPosition, ModelCommand};
+    pub mod history;
     pub mod project;
 }
 #[cfg(feature = "type_export")]
@@ -69,6 +88,7 @@ pub mod api;
 #[serde(rename_all = "camelCase")]
 pub struct FullShowState {
     pub project_status: ProjectStatus,
+    pub history_status: HistoryStatus,
     pub show_model: ShowModel,
     pub show_state: ShowState,
 }
@@ -78,6 +98,10 @@ pub struct FullShowState {
 pub struct BackendSettings {
     pub advance_cursor_when_go: bool,
     pub copy_assets_when_add: bool,
+    /// Number of timestamped backups kept beside a saved project. 0 disables backups.
+    pub backup_count: usize,
+    /// Seconds between autosaves to the recovery file. 0 disables autosave.
+    pub autosave_interval: u64,
     pub audio: BackendAudioSettings,
 }
 
@@ -96,6 +120,7 @@ pub struct BackendHandle {
     pub model_handle: ShowModelHandle,
     pub asset_processor_handle: AssetProcessorHandle,
     pub controller_handle: CueControllerHandle,
+    pub journal_handle: ShowJournalHandle,
     pub level_meter: Option<SharedLevel>,
     request_state_sync_tx: mpsc::Sender<()>,
     request_full_state_tx: mpsc::Sender<oneshot::Sender<FullShowState>>,
@@ -179,6 +204,7 @@ pub fn start_backend(
 
     let (asset_processor, asset_processor_handle) =
         AssetProcessor::new(model_handle.clone(), event_tx.clone());
+    let (journal, journal_handle) = ShowJournal::new(&event_tx);
 
     tokio::spawn(model_manager.run());
     tokio::spawn(controller.run());
@@ -186,6 +212,7 @@ pub fn start_backend(
     tokio::spawn(audio_engine.run());
     tokio::spawn(wait_engine.run());
     tokio::spawn(asset_processor.run());
+    tokio::spawn(journal.run());
 
     let request_state_sync_tx = handle_state_sync(state_rx.clone(), event_tx.clone());
 
@@ -196,6 +223,7 @@ pub fn start_backend(
             model_handle,
             asset_processor_handle,
             controller_handle,
+            journal_handle,
             level_meter,
             request_state_sync_tx,
             request_full_state_tx,
@@ -257,6 +285,7 @@ fn handle_full_state(
             if responder
                 .send(FullShowState {
                     project_status: model_handle.get_project_state().await.clone(),
+                    history_status: model_handle.get_history_status().await,
                     show_model: model_handle.read().await.clone(),
                     show_state: state_rx.borrow().clone(),
                 })
@@ -6,10 +6,13 @@ use std::path::PathBuf;
 use clap::Parser;
 use sbsp_backend::{
     BackendSettings,
-    api::{ApiServerOptions, server::start_apiserver},
+    api::{ApiServerOptions, PermissionInfo, Permissions, server::start_apiserver},
+    controller::CommandRecording,
+    event::BackendEvent,
+    manager::CueSheetFormat,
     start_backend,
 };
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -24,6 +27,26 @@ struct Args {
 
     #[arg(short, long)]
     password: Option<String>,
+
+    /// Write a cue sheet of FILE to this path and exit instead of serving
+    #[arg(long, requires = "file")]
+    cue_sheet: Option<PathBuf>,
+
+    /// Cue sheet format, inferred from the --cue-sheet extension by default
+    #[arg(long, value_parser = ["csv", "markdown", "html"], requires = "cue_sheet")]
+    cue_sheet_format: Option<String>,
+
+    /// Record every controller command while serving and write them to this path on exit
+    #[arg(long, conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Replay commands recorded with --record against FILE and exit when done
+    #[arg(long, requires = "file")]
+    replay: Option<PathBuf>,
+
+    /// Replay speed, 2.0 plays twice as fast
+    #[arg(long, default_value_t = 1.0, requires = "replay")]
+    replay_speed: f64,
 }
 
 #[tokio::main]
@@ -40,10 +63,57 @@ async fn main() -> Result<(), anyhow::Error> {
         }
     };
 
+    if let Some(output) = args.cue_sheet {
+        let format = match args.cue_sheet_format.as_deref() {
+            Some("csv") => CueSheetFormat::Csv,
+            Some("markdown") => CueSheetFormat::Markdown,
+            Some("html") => CueSheetFormat::Html,
+            _ => CueSheetFormat::from_extension(&output).ok_or_else(|| {
+                anyhow::anyhow!("Unknown cue sheet format. Use --cue-sheet-format.")
+            })?,
+        };
+        let mut event_rx = event_tx.subscribe();
+        let model_handle = backend_handle.model_handle;
+        model_handle
+            .load_from_file(args.file.unwrap_or_default())
+            .await?;
+        wait_for_file_operation(&mut event_rx).await?;
+        model_handle.export_cue_sheet(output, format).await?;
+        wait_for_file_operation(&mut event_rx).await?;
+        return Ok(());
+    }
+
+    if let Some(recording_path) = args.replay {
+        let recording: CommandRecording =
+            serde_json::from_str(&tokio::fs::read_to_string(&recording_path).await?)?;
+        let mut event_rx = event_tx.subscribe();
+        backend_handle
+            .model_handle
+            .load_from_file(args.file.unwrap_or_default())
+            .await?;
+        wait_for_file_operation(&mut event_rx).await?;
+        log::info!("Replaying {} commands.", recording.commands.len());
+        backend_handle
+            .controller_handle
+            .replay(recording, args.replay_speed)
+            .finished()
+            .await;
+        let mut state_rx = state_rx;
+        state_rx
+            .wait_for(|state| state.active_cues.is_empty())
+            .await?;
+        return Ok(());
+    }
+
     if let Some(path) = args.file {
         backend_handle.model_handle.load_from_file(path).await?;
     }
 
+    let controller_handle = backend_handle.controller_handle.clone();
+    if args.record.is_some() {
+        controller_handle.start_recording();
+    }
+
     let shutdown_tx = start_apiserver(
         backend_handle,
         state_rx,
@@ -51,13 +121,28 @@ async fn main() -> Result<(), anyhow::Error> {
         ApiServerOptions {
             port: args.port,
             discoverry: args.discovery,
-            password: args.password,
+            // without --password every client gets full access unauthenticated
+            auth_map: vec![PermissionInfo {
+                password: args.password.unwrap_or_default(),
+                permission: Permissions::all(),
+            }],
         },
     )
     .await?;
 
     shutdown_signal().await;
 
+    if let Some(record_path) = args.record
+        && let Some(recording) = controller_handle.stop_recording()
+    {
+        tokio::fs::write(&record_path, serde_json::to_string_pretty(&recording)?).await?;
+        log::info!(
+            "Recorded {} commands to {:?}",
+            recording.commands.len(),
+            record_path
+        );
+    }
+
     shutdown_tx.send(())?;
 
     shutdown_tx.closed().await;
@@ -66,6 +151,27 @@ async fn main() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+async fn wait_for_file_operation(
+    event_rx: &mut broadcast::Receiver<BackendEvent>,
+) -> Result<(), anyhow::Error> {
+    loop {
+        match event_rx.recv().await? {
+            BackendEvent::ShowModelLoaded { path, .. } => {
+                log::info!("Loaded {:?}", path);
+                return Ok(());
+            }
+            BackendEvent::CueSheetExported { path } => {
+                log::info!("Cue sheet written to {:?}", path);
+                return Ok(());
+            }
+            BackendEvent::OperationFailed { error } => {
+                return Err(anyhow::anyhow!("{:?}", error));
+            }
+            _ => {}
+        }
+    }
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         tokio::signal::ctrl_c()
@@ -75,7 +181,7 @@ async fn shutdown_signal() {
 
     #[cfg(unix)]
     let terminate = async {
-        tokio::signal::unix::signal(signal::unix::SignalKind::terminate())
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
             .expect("failed to install signal handler")
             .recv()
             .await;
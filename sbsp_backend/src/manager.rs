@@ -1,41 +1,63 @@
 // SPDX-License-Identifier: Elastic-2.0
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
+pub mod asset_import;
+pub mod bundle;
 mod command;
 mod guard;
 mod handle;
+pub mod history;
+mod migration;
 pub mod project;
 
-use anyhow::anyhow;
-pub use command::{InsertPosition, ModelCommand};
+use anyhow::{anyhow, bail};
+pub use command::{CueSheetFormat, InsertPosition, ModelCommand};
 pub use handle::ShowModelHandle;
 
 use std::collections::HashMap;
+use std::io::Write;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::{
     collections::{HashSet, VecDeque},
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
-use tokio::sync::{RwLock, broadcast, mpsc, watch};
+use tokio::{
+    sync::{RwLock, broadcast, mpsc, watch},
+    time::{Instant, Interval, MissedTickBehavior},
+};
 use uuid::Uuid;
 
+use crate::manager::asset_import::{AssetImportEntry, MissingAsset};
 use crate::manager::guard::RollbackGuard;
-use crate::manager::project::ProjectFile;
+use crate::manager::history::{History, HistoryStatus};
 use crate::manager::project::ProjectStatus;
 use crate::manager::project::ProjectType;
+use crate::manager::project::{PROJECT_FILE_VERSION, ProjectFile};
 use crate::{
     BackendSettings,
+    asset_processor::AssetProcessor,
+    cue_sheet,
     event::{BackendError, BackendEvent},
+    importer,
     model::{
         ShowModel,
-        cue::{Cue, CueParam},
+        cart::{CartSlot, MAX_CART_SLOTS},
+        cue::{
+            Cue, CueChain, CueParam, FadeCueParam, LoadCueParam, MAIN_CUE_LIST_ID, NamedCueList,
+            PauseCueParam, StartCueParam, StopCueParam,
+        },
+        settings::ReferencePolicy,
     },
 };
 
 const DEFAULT_PROJECT_FOLDER_MODEL_FILENAME: &str = "model.sbsp";
+const HISTORY_LIMIT: usize = 100;
+const UNSAVED_RECOVERY_FILENAME: &str = "sbsp_untitled.sbsp.recovery";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%.3f";
 
 pub struct ShowModelManager {
     model: Arc<RwLock<ShowModel>>,
@@ -44,8 +66,14 @@ pub struct ShowModelManager {
     event_tx: broadcast::Sender<BackendEvent>,
 
     copy_assets_when_add: bool,
+    backup_count: usize,
+    autosave_interval: u64,
     project_status: Arc<RwLock<ProjectStatus>>,
     modify_status: Arc<AtomicBool>,
+    history: History,
+    history_status: Arc<RwLock<HistoryStatus>>,
+    last_autosave: Option<ShowModel>,
+    pending_recovery: Option<PathBuf>,
 }
 
 impl ShowModelManager {
@@ -57,57 +85,321 @@ impl ShowModelManager {
         let model = Arc::new(RwLock::new(ShowModel::default()));
         let project_status = Arc::new(RwLock::new(ProjectStatus::Unsaved));
         let modify_status = Arc::new(AtomicBool::new(false));
-        let copy_assets_when_add = settings_rx.borrow().copy_assets_when_add;
+        let history_status = Arc::new(RwLock::new(HistoryStatus::default()));
+        let (copy_assets_when_add, backup_count, autosave_interval) = {
+            let settings = settings_rx.borrow();
+            (
+                settings.copy_assets_when_add,
+                settings.backup_count,
+                settings.autosave_interval,
+            )
+        };
         let manager = Self {
             model: model.clone(),
             settings_rx,
             command_rx,
             event_tx,
             copy_assets_when_add,
+            backup_count,
+            autosave_interval,
             project_status: project_status.clone(),
             modify_status: modify_status.clone(),
+            history: History::new(HISTORY_LIMIT),
+            history_status: history_status.clone(),
+            last_autosave: None,
+            pending_recovery: None,
         };
-        let handle = ShowModelHandle::new(model, command_tx, project_status, modify_status);
+        let handle = ShowModelHandle::new(
+            model,
+            command_tx,
+            project_status,
+            modify_status,
+            history_status,
+        );
 
         (manager, handle)
     }
 
     pub async fn run(mut self) {
+        let mut autosave_timer = Self::autosave_timer(self.autosave_interval);
         loop {
             tokio::select! {
                 Some(command) = self.command_rx.recv() => self.process_command(command).await,
                 Ok(_) = self.settings_rx.changed() => {
-                    self.copy_assets_when_add = self.settings_rx.borrow().copy_assets_when_add;
+                    let autosave_interval = {
+                        let settings = self.settings_rx.borrow();
+                        self.copy_assets_when_add = settings.copy_assets_when_add;
+                        self.backup_count = settings.backup_count;
+                        settings.autosave_interval
+                    };
+                    if autosave_interval != self.autosave_interval {
+                        self.autosave_interval = autosave_interval;
+                        autosave_timer = Self::autosave_timer(autosave_interval);
+                    }
                 }
+                _ = autosave_timer.tick(), if self.autosave_interval > 0 => self.autosave().await,
                 else => break,
             }
         }
     }
 
-    async fn process_command(&self, command: ModelCommand) {
+    fn autosave_timer(interval: u64) -> Interval {
+        let period = Duration::from_secs(interval.max(1));
+        let mut timer = tokio::time::interval_at(Instant::now() + period, period);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        timer
+    }
+
+    async fn process_command(&mut self, command: ModelCommand) {
         log::debug!("Model Manager received command: {:?}", command);
         match command {
-            ModelCommand::UpdateCue(mut cue) => {
-                let model_path_option = self.project_status.read().await.to_model_path_option();
-                if let CueParam::Audio(audio_param) = &mut cue.params
-                    && let Some(model_path) = model_path_option.as_ref()
-                    && self.copy_assets_when_add
-                {
-                    let import_destination = {
-                        let model = self.model.read().await;
-                        model.settings.general.copy_assets_destination.clone()
-                    };
-
-                    let new_target = Self::import_asset_file(
-                        &audio_param.target,
-                        model_path,
-                        &import_destination,
-                    )
-                    .await;
-                    if let Ok(target) = new_target {
-                        audio_param.target = target;
-                    } // ignore failed to import asset. use absolute path
+            ModelCommand::Undo => {
+                let current = self.model.read().await.clone();
+                if let Some(previous) = self.history.undo(current) {
+                    self.restore_snapshot(previous).await;
+                }
+                self.update_history_status().await;
+            }
+            ModelCommand::Redo => {
+                let current = self.model.read().await.clone();
+                if let Some(next) = self.history.redo(current) {
+                    self.restore_snapshot(next).await;
+                }
+                self.update_history_status().await;
+            }
+            ModelCommand::CheckRecovery => self.check_recovery().await,
+            ModelCommand::Recover => {
+                if let Err(error) = self.recover().await {
+                    log::error!("Failed to recover model: {}", error);
+                    if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                        error: BackendError::LoadFromFile {
+                            path: self.pending_recovery.take().unwrap_or_default(),
+                            message: error.to_string(),
+                        },
+                    }) {
+                        log::warn!("Failed to send event, {}", e);
+                    }
+                }
+                self.update_history_status().await;
+            }
+            ModelCommand::DiscardRecovery => {
+                if let Some(path) = self.pending_recovery.take() {
+                    Self::remove_recovery_file(&path).await;
                 }
+            }
+            ModelCommand::Reset
+            | ModelCommand::Save
+            | ModelCommand::SaveToFile(_)
+            | ModelCommand::ExportToFolder(_)
+            | ModelCommand::LoadFromFile(_)
+            | ModelCommand::OpenBundle { .. } => {
+                // the recovery file may move or be removed along with the project file
+                self.last_autosave = None;
+                self.apply_command(command).await;
+                self.update_history_status().await;
+            }
+            // exports only read the show, so there is nothing to undo
+            ModelCommand::ExportCueSheet { .. } | ModelCommand::ExportBundle(_) => {
+                self.apply_command(command).await;
+            }
+            _ => {
+                let snapshot = self.model.read().await.clone();
+                self.apply_command(command).await;
+                if *self.model.read().await != snapshot {
+                    self.history.record(snapshot);
+                    self.update_history_status().await;
+                }
+            }
+        }
+    }
+
+    async fn restore_snapshot(&self, snapshot: ShowModel) {
+        let mut model = self.model.write().await;
+        let previous = std::mem::replace(&mut *model, snapshot);
+        self.modify_status.store(true, Ordering::Release);
+
+        if previous.cue_list != model.cue_list {
+            let cue_ids: HashSet<Uuid> = previous
+                .cue_list
+                .cues
+                .keys()
+                .filter(|cue_id| !model.cue_list.cues.contains_key(cue_id))
+                .copied()
+                .collect();
+            if !cue_ids.is_empty()
+                && let Err(e) = self.event_tx.send(BackendEvent::CueRemoved {
+                    cue_ids,
+                    affected_cue_ids: HashSet::new(),
+                })
+            {
+                log::warn!("Failed to send event, {}", e);
+            }
+            if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                cue_list: model.cue_list.clone(),
+            }) {
+                log::warn!("Failed to send event, {}", e);
+            }
+        }
+        if previous.settings != model.settings
+            && let Err(e) = self.event_tx.send(BackendEvent::SettingsUpdated {
+                new_settings: Box::new(model.settings.clone()),
+            })
+        {
+            log::warn!("Failed to send event, {}", e);
+        }
+        if previous.cart != model.cart
+            && let Err(e) = self.event_tx.send(BackendEvent::CartUpdated {
+                cart: model.cart.clone(),
+            })
+        {
+            log::warn!("Failed to send event, {}", e);
+        }
+        if previous.name != model.name
+            && let Err(e) = self.event_tx.send(BackendEvent::ModelNameUpdated {
+                new_name: model.name.clone(),
+            })
+        {
+            log::warn!("Failed to send event, {}", e);
+        }
+    }
+
+    async fn autosave(&mut self) {
+        if !self.modify_status.load(Ordering::Acquire) {
+            return;
+        }
+        let model = self.model.read().await.clone();
+        if self.last_autosave.as_ref() == Some(&model) {
+            return;
+        }
+        let (recovery_path, project_type) = {
+            let project_status = self.project_status.read().await;
+            let project_type = match &*project_status {
+                ProjectStatus::Saved { project_type, .. } => *project_type,
+                ProjectStatus::Unsaved => ProjectType::SingleFile,
+            };
+            (Self::recovery_path(&project_status), project_type)
+        };
+        let project_file = ProjectFile {
+            version: PROJECT_FILE_VERSION,
+            project_type,
+            model: model.clone().into(),
+        };
+        match Self::write_project_file(&recovery_path, project_file).await {
+            Ok(()) => {
+                log::debug!("Autosaved to: {}", recovery_path.display());
+                self.last_autosave = Some(model);
+            }
+            Err(e) => log::warn!("Failed to write recovery file, {}", e),
+        }
+    }
+
+    async fn check_recovery(&mut self) {
+        // recovery file written by this session, not by a crashed one
+        if self.last_autosave.is_some() {
+            return;
+        }
+        let (recovery_path, project_path) = {
+            let project_status = self.project_status.read().await;
+            (
+                Self::recovery_path(&project_status),
+                project_status.to_model_path_option(),
+            )
+        };
+        let Ok(recovery_modified) = tokio::fs::metadata(&recovery_path)
+            .await
+            .and_then(|metadata| metadata.modified())
+        else {
+            return;
+        };
+        if let Some(project_path) = &project_path
+            && let Ok(project_modified) = tokio::fs::metadata(project_path)
+                .await
+                .and_then(|metadata| metadata.modified())
+            && recovery_modified <= project_modified
+        {
+            return;
+        }
+
+        log::info!("Recovery file found: {}", recovery_path.display());
+        self.pending_recovery = Some(recovery_path.clone());
+        if let Err(e) = self.event_tx.send(BackendEvent::RecoveryAvailable {
+            path: recovery_path,
+            project_path,
+        }) {
+            log::warn!("Failed to send event, {}", e);
+        }
+    }
+
+    async fn recover(&mut self) -> anyhow::Result<()> {
+        let Some(path) = self.pending_recovery.clone() else {
+            return Err(anyhow!("No recovery file is available."));
+        };
+        let project_file = Self::read_project_file(&path).await?;
+        let model: ShowModel = project_file.model.try_into()?;
+        self.pending_recovery = None;
+        {
+            let mut model_lock = self.model.write().await;
+            *model_lock = model.clone();
+        }
+        self.history.clear();
+        // keep the recovered state unsaved until the user saves it
+        self.modify_status.store(true, Ordering::Release);
+
+        let event = match &*self.project_status.read().await {
+            ProjectStatus::Saved { project_type, path } => BackendEvent::ShowModelLoaded {
+                model,
+                project_type: *project_type,
+                path: path.clone(),
+            },
+            ProjectStatus::Unsaved => BackendEvent::ShowModelReset { model },
+        };
+        log::info!("Show recovered from: {}", path.display());
+        if let Err(e) = self.event_tx.send(event) {
+            log::warn!("Failed to send event, {}", e);
+        }
+        Ok(())
+    }
+
+    fn recovery_path(project_status: &ProjectStatus) -> PathBuf {
+        match project_status {
+            ProjectStatus::Saved { path, .. } => Self::recovery_path_for(path),
+            ProjectStatus::Unsaved => std::env::temp_dir().join(UNSAVED_RECOVERY_FILENAME),
+        }
+    }
+
+    fn recovery_path_for(path: &Path) -> PathBuf {
+        let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".recovery");
+        path.with_file_name(file_name)
+    }
+
+    async fn remove_recovery_file(path: &Path) {
+        if let Err(e) = tokio::fs::remove_file(path).await
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            log::warn!("Failed to remove recovery file, {}", e);
+        }
+    }
+
+    async fn update_history_status(&self) {
+        let status = self.history.status();
+        {
+            let mut history_status = self.history_status.write().await;
+            if *history_status == status {
+                return;
+            }
+            *history_status = status;
+        }
+        if let Err(e) = self.event_tx.send(BackendEvent::HistoryUpdated { status }) {
+            log::warn!("Failed to send event, {}", e);
+        }
+    }
+
+    async fn apply_command(&mut self, command: ModelCommand) {
+        match command {
+            ModelCommand::UpdateCue(mut cue) => {
+                let mut imported_assets = Vec::new();
+                self.import_cue_asset(&mut cue, &mut imported_assets).await;
                 if let Err(e) = self.update_cue_by_id(&cue.id, cue.clone()).await {
                     if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
                         error: BackendError::CueEdit {
@@ -124,9 +416,9 @@ impl ShowModelManager {
                 }) {
                     log::warn!("Failed to send event, {}", e);
                 }
+                self.report_imported_assets(imported_assets);
             }
             ModelCommand::AddCue { mut cue, position } => {
-                let model_path_option = self.project_status.read().await.to_model_path_option();
                 if self.is_cue_exists(&cue.id).await {
                     if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
                         error: BackendError::CueEdit {
@@ -137,24 +429,8 @@ impl ShowModelManager {
                     }
                     return;
                 }
-                if let CueParam::Audio(audio_param) = &mut cue.params
-                    && let Some(model_path) = model_path_option.as_ref()
-                    && self.copy_assets_when_add
-                {
-                    let import_destination = {
-                        let model = self.model.read().await;
-                        model.settings.general.copy_assets_destination.clone()
-                    };
-                    let new_target = Self::import_asset_file(
-                        &audio_param.target,
-                        model_path,
-                        &import_destination,
-                    )
-                    .await;
-                    if let Ok(target) = new_target {
-                        audio_param.target = target;
-                    } // ignore failed to import asset. use absolute path
-                }
+                let mut imported_assets = Vec::new();
+                self.import_cue_asset(&mut cue, &mut imported_assets).await;
                 if let Err(e) = self.insert_cues_at_position(vec![cue], position).await {
                     if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
                         error: BackendError::CueEdit {
@@ -171,113 +447,138 @@ impl ShowModelManager {
                 }) {
                     log::warn!("Failed to send event, {}", e);
                 }
+                self.report_imported_assets(imported_assets);
             }
             ModelCommand::AddCues { cues, position } => {
-                let model_path_option = self.project_status.read().await.to_model_path_option();
-                let mut valid_cues = Vec::new();
-                let mut valid_cue_ids = HashSet::new();
-
-                for mut cue in cues {
-                    if self.is_cue_exists(&cue.id).await {
-                        if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
-                            error: BackendError::CueEdit {
-                                message: "Failed to add cue, id already exists.".into(),
-                            },
-                        }) {
-                            log::warn!("Failed to send event, {}", e);
-                        }
-                        continue;
-                    } else if !valid_cue_ids.insert(cue.id) {
+                self.add_cues(cues, position).await;
+            }
+            ModelCommand::RelinkAssets { folder } => {
+                let missing = self.missing_assets().await;
+                let model_dir = self.model_dir().await;
+                let search_folder = folder.clone();
+                let relinked = if missing.is_empty() {
+                    Ok(Vec::new())
+                } else {
+                    tokio::task::spawn_blocking(move || {
+                        asset_import::find_relinks(&missing, &search_folder, model_dir.as_deref())
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(e.into()))
+                };
+                let relinked = match relinked {
+                    Ok(relinked) => relinked,
+                    Err(error) => {
+                        log::error!("Failed to relink assets: {}", error);
                         if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
-                            error: BackendError::CueEdit {
-                                message: "Failed to add cue, duplicate id found.".into(),
+                            error: BackendError::RelinkAssets {
+                                path: folder,
+                                message: error.to_string(),
                             },
                         }) {
                             log::warn!("Failed to send event, {}", e);
                         }
-                        continue;
+                        return;
                     }
-                    if let CueParam::Audio(audio_param) = &mut cue.params
-                        && let Some(model_path) = model_path_option.as_ref()
-                        && self.copy_assets_when_add
+                };
+                if !relinked.is_empty() {
                     {
-                        let import_destination = {
-                            let model = self.model.read().await;
-                            model.settings.general.copy_assets_destination.clone()
-                        };
-                        let new_target = Self::import_asset_file(
-                            &audio_param.target,
-                            model_path,
-                            &import_destination,
-                        )
-                        .await;
-                        if let Ok(target) = new_target {
-                            audio_param.target = target;
-                        } // ignore failed to import asset. use absolute path
+                        let mut model = self.model.write().await;
+                        for relink in &relinked {
+                            if let Some(cue) = model.cue_list.cues.get_mut(&relink.cue_id)
+                                && let CueParam::Audio(param) = &mut cue.params
+                            {
+                                param.target = relink.new_target.clone();
+                            }
+                        }
+                    }
+                    self.modify_status.store(true, Ordering::Release);
+                    if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                        cue_list: self.model.read().await.cue_list.clone(),
+                    }) {
+                        log::warn!("Failed to send event, {}", e);
                     }
-                    valid_cues.push(cue);
-                }
-                if valid_cues.is_empty() {
-                    return;
                 }
+                let missing = self.missing_assets().await;
                 if let Err(e) = self
-                    .insert_cues_at_position(valid_cues, position.clone())
-                    .await
+                    .event_tx
+                    .send(BackendEvent::AssetsRelinked { relinked, missing })
                 {
-                    if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
-                        error: BackendError::CueEdit {
-                            message: format!("Failed to add cues, {}.", e),
-                        },
-                    }) {
-                        log::warn!("Failed to send event, {}", e);
+                    log::warn!("Failed to send event, {}", e);
+                }
+            }
+            ModelCommand::ImportCueSheet { path, position } => {
+                let report = match importer::import_cue_sheet(&path).await {
+                    Ok(report) => report,
+                    Err(error) => {
+                        log::error!("Failed to import cue sheet: {}", error);
+                        if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                            error: BackendError::ImportCueSheet {
+                                path,
+                                message: error.to_string(),
+                            },
+                        }) {
+                            log::warn!("Failed to send event, {}", e);
+                        }
+                        return;
                     }
+                };
+                let unresolved_files = report.unresolved_files.clone();
+                let warnings = report.warnings.clone();
+                let cues = report.into_cues();
+                let cue_count = cues.len();
+                if cue_count > 0 && !self.add_cues(cues, position).await {
                     return;
                 }
-
-                self.modify_status.store(true, Ordering::Release);
-                if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
-                    cue_list: self.model.read().await.cue_list.clone(),
+                if let Err(e) = self.event_tx.send(BackendEvent::CueSheetImported {
+                    path,
+                    cue_count,
+                    unresolved_files,
+                    warnings,
                 }) {
                     log::warn!("Failed to send event, {}", e);
                 }
             }
             ModelCommand::RemoveCue { cue_id } => {
-                let removed_ids = self.remove_cues_by_id(HashSet::from([cue_id])).await;
-                if removed_ids.is_empty() {
-                    if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
-                        error: BackendError::CueEdit {
-                            message: "Failed to remove cue, id not found.".to_string(),
-                        },
-                    }) {
-                        log::warn!("Failed to send event, {}", e);
-                    }
-                } else {
-                    if let Err(e) = self.event_tx.send(BackendEvent::CueRemoved {
-                        cue_ids: removed_ids,
-                    }) {
-                        log::warn!("Failed to send event, {}", e);
+                match self.remove_cues_by_id(HashSet::from([cue_id])).await {
+                    Err(e) => {
+                        if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                            error: BackendError::CueEdit {
+                                message: format!("Failed to remove cue, {}.", e),
+                            },
+                        }) {
+                            log::warn!("Failed to send event, {}", e);
+                        }
                     }
-                    self.modify_status.store(true, Ordering::Release);
-                    if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
-                        cue_list: self.model.read().await.cue_list.clone(),
-                    }) {
-                        log::warn!("Failed to send event, {}", e);
+                    Ok((removed_ids, affected_cue_ids)) => {
+                        if let Err(e) = self.event_tx.send(BackendEvent::CueRemoved {
+                            cue_ids: removed_ids,
+                            affected_cue_ids,
+                        }) {
+                            log::warn!("Failed to send event, {}", e);
+                        }
+                        self.modify_status.store(true, Ordering::Release);
+                        if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                            cue_list: self.model.read().await.cue_list.clone(),
+                        }) {
+                            log::warn!("Failed to send event, {}", e);
+                        }
                     }
                 }
             }
-            ModelCommand::RemoveCues { cue_ids } => {
-                let removed_ids = self.remove_cues_by_id(cue_ids.clone()).await;
-                if removed_ids.is_empty() {
+            ModelCommand::RemoveCues { cue_ids } => match self.remove_cues_by_id(cue_ids).await {
+                Err(e) => {
                     if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
                         error: BackendError::CueEdit {
-                            message: "Failed to remove cues, id not found.".to_string(),
+                            message: format!("Failed to remove cues, {}.", e),
                         },
                     }) {
                         log::warn!("Failed to send event, {}", e);
                     }
-                } else {
+                }
+                Ok((removed_ids, affected_cue_ids)) => {
                     if let Err(e) = self.event_tx.send(BackendEvent::CueRemoved {
                         cue_ids: removed_ids,
+                        affected_cue_ids,
                     }) {
                         log::warn!("Failed to send event, {}", e);
                     }
@@ -288,7 +589,7 @@ impl ShowModelManager {
                         log::warn!("Failed to send event, {}", e);
                     }
                 }
-            }
+            },
             ModelCommand::MoveCue { cue_id, position } => {
                 if let Err(e) = self
                     .move_cues_at_position(HashSet::from([cue_id]), position)
@@ -329,6 +630,86 @@ impl ShowModelManager {
                     log::warn!("Failed to send event, {}", e);
                 }
             }
+            ModelCommand::AddCueList { name } => {
+                let mut model = self.model.write().await;
+                model.cue_list.lists.push(NamedCueList {
+                    id: Uuid::new_v4(),
+                    name,
+                    root_ids: Vec::new(),
+                });
+                self.modify_status.store(true, Ordering::Release);
+                if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                    cue_list: model.cue_list.clone(),
+                }) {
+                    log::warn!("Failed to send event, {}", e);
+                }
+            }
+            ModelCommand::RenameCueList { list_id, name } => {
+                let mut model = self.model.write().await;
+                let Some(list) = model
+                    .cue_list
+                    .lists
+                    .iter_mut()
+                    .find(|list| list.id == list_id)
+                else {
+                    if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                        error: BackendError::CueEdit {
+                            message: format!(
+                                "Failed to rename cue list, id={} not found.",
+                                list_id
+                            ),
+                        },
+                    }) {
+                        log::warn!("Failed to send event, {}", e);
+                    }
+                    return;
+                };
+                list.name = name;
+                self.modify_status.store(true, Ordering::Release);
+                if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                    cue_list: model.cue_list.clone(),
+                }) {
+                    log::warn!("Failed to send event, {}", e);
+                }
+            }
+            ModelCommand::RemoveCueList { list_id } => match self.remove_cue_list(list_id).await {
+                Err(e) => {
+                    if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                        error: BackendError::CueEdit {
+                            message: format!("Failed to remove cue list, {}.", e),
+                        },
+                    }) {
+                        log::warn!("Failed to send event, {}", e);
+                    }
+                }
+                Ok(removed) => {
+                    if let Some((removed_ids, affected_cue_ids)) = removed
+                        && let Err(e) = self.event_tx.send(BackendEvent::CueRemoved {
+                            cue_ids: removed_ids,
+                            affected_cue_ids,
+                        })
+                    {
+                        log::warn!("Failed to send event, {}", e);
+                    }
+                    self.modify_status.store(true, Ordering::Release);
+                    if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+                        cue_list: self.model.read().await.cue_list.clone(),
+                    }) {
+                        log::warn!("Failed to send event, {}", e);
+                    }
+                }
+            },
+            ModelCommand::SetCartSlot { index, slot } => {
+                if let Err(e) = self.set_cart_slot(index, slot).await
+                    && let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                        error: BackendError::CueEdit {
+                            message: format!("Failed to set cart slot, {}.", e),
+                        },
+                    })
+                {
+                    log::warn!("Failed to send event, {}", e);
+                }
+            }
             ModelCommand::RenumberCues {
                 cues,
                 start_from,
@@ -343,7 +724,7 @@ impl ShowModelManager {
                 let suffix = suffix.unwrap_or_default();
 
                 let mut queue: VecDeque<Vec<Uuid>> =
-                    VecDeque::from([model.cue_list.root_ids.clone()]);
+                    VecDeque::from([model.cue_list.all_root_ids().copied().collect()]);
 
                 'outer: while let Some(cue_ids) = queue.pop_front() {
                     for cue_id in cue_ids {
@@ -403,6 +784,7 @@ impl ShowModelManager {
                     let mut model = self.model.write().await;
                     *model = ShowModel::default();
                 }
+                self.history.clear();
                 self.modify_status.store(false, Ordering::Release);
                 {
                     let mut project_status_lock = self.project_status.write().await;
@@ -541,46 +923,79 @@ impl ShowModelManager {
                     log::warn!("Failed to send event, {}", e);
                 }
             }
-            ModelCommand::LoadFromFile(path) => {
-                let event = match self.load_from_file(path.as_path()).await {
+            ModelCommand::ExportCueSheet { path, format } => {
+                let event = match self.export_cue_sheet(&path, format).await {
                     Err(error) => {
-                        log::error!("Failed to load model file: {}", error);
+                        log::error!("Failed to export cue sheet: {}", error);
                         BackendEvent::OperationFailed {
-                            error: BackendError::LoadFromFile {
+                            error: BackendError::ExportCueSheet {
                                 path,
                                 message: error.to_string(),
                             },
                         }
                     }
-                    Ok(project_type) => {
-                        self.modify_status.store(false, Ordering::Release);
-                        {
-                            let mut project_status = self.project_status.write().await;
-                            *project_status = ProjectStatus::Saved {
-                                project_type,
-                                path: path.clone(),
-                            };
-                        }
-                        let model = self.read().await.clone();
-                        BackendEvent::ShowModelLoaded {
-                            model,
-                            project_type,
-                            path,
+                    Ok(()) => BackendEvent::CueSheetExported { path },
+                };
+                if let Err(e) = self.event_tx.send(event) {
+                    log::warn!("Failed to send event, {}", e);
+                }
+            }
+            ModelCommand::LoadFromFile(path) => self.open_project_file(path).await,
+            ModelCommand::ExportBundle(path) => {
+                let event = match self.export_bundle(&path).await {
+                    Err(error) => {
+                        log::error!("Failed to export bundle: {}", error);
+                        BackendEvent::OperationFailed {
+                            error: BackendError::ExportBundle {
+                                path,
+                                message: error.to_string(),
+                            },
                         }
                     }
+                    Ok(()) => BackendEvent::BundleExported { path },
                 };
                 if let Err(e) = self.event_tx.send(event) {
                     log::warn!("Failed to send event, {}", e);
                 }
             }
-        }
-    }
-
-    pub async fn read(&self) -> tokio::sync::RwLockReadGuard<'_, ShowModel> {
-        self.model.read().await
-    }
-
-    #[cfg(test)]
+            ModelCommand::OpenBundle { path, destination } => {
+                let destination = destination.unwrap_or_else(|| bundle::working_folder_for(&path));
+                let bundle_path = path.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    bundle::extract_bundle(&bundle_path, &destination)
+                })
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|result| result);
+                match result {
+                    Ok(model_path) => self.open_project_file(model_path).await,
+                    Err(error) => {
+                        log::error!("Failed to open bundle: {}", error);
+                        if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                            error: BackendError::LoadFromFile {
+                                path,
+                                message: error.to_string(),
+                            },
+                        }) {
+                            log::warn!("Failed to send event, {}", e);
+                        }
+                    }
+                }
+            }
+            // history and recovery are handled in process_command
+            ModelCommand::Undo
+            | ModelCommand::Redo
+            | ModelCommand::CheckRecovery
+            | ModelCommand::Recover
+            | ModelCommand::DiscardRecovery => {}
+        }
+    }
+
+    pub async fn read(&self) -> tokio::sync::RwLockReadGuard<'_, ShowModel> {
+        self.model.read().await
+    }
+
+    #[cfg(test)]
     pub async fn write(&self) -> tokio::sync::RwLockWriteGuard<'_, ShowModel> {
         self.model.write().await
     }
@@ -589,38 +1004,189 @@ impl ShowModelManager {
         self.read().await.cue_list.cues.contains_key(cue_id)
     }
 
-    async fn remove_cues_by_id(&self, cue_ids: HashSet<Uuid>) -> HashSet<Uuid> {
+    /// Removes the cues together with the contents of removed groups and
+    /// returns the removed ids and the remaining cues that referenced them.
+    /// Those references are cleared, or under `ReferencePolicy::Refuse` the
+    /// removal fails and nothing changes.
+    async fn remove_cues_by_id(
+        &self,
+        cue_ids: HashSet<Uuid>,
+    ) -> anyhow::Result<(HashSet<Uuid>, HashSet<Uuid>)> {
         let mut model = self.model.write().await;
         let mut removed_cues = HashSet::new();
         let mut queue: VecDeque<_> = cue_ids.into_iter().collect();
+        while let Some(cue_id) = queue.pop_front() {
+            let Some(cue) = model.cue_list.cues.get(&cue_id) else {
+                continue;
+            };
+            if removed_cues.insert(cue_id)
+                && let CueParam::Group { children, .. } = &cue.params
+            {
+                queue.extend(children);
+            }
+        }
+        if removed_cues.is_empty() {
+            bail!("id not found");
+        }
 
-        while let Some(target_id) = queue.pop_front() {
-            if let Some(parent_id) = model
-                .cue_list
-                .cues
-                .get(&target_id)
-                .and_then(|cue| cue.parent_id)
+        let affected_cues: HashSet<Uuid> = model
+            .cue_list
+            .cues
+            .values()
+            .filter(|cue| !removed_cues.contains(&cue.id))
+            .filter(|cue| {
+                let param_target = match &cue.params {
+                    CueParam::Fade(param) => Some(param.target),
+                    CueParam::Start(param) => Some(param.target),
+                    CueParam::Stop(param) => Some(param.target),
+                    CueParam::Pause(param) => Some(param.target),
+                    CueParam::Load(param) => Some(param.target),
+                    _ => None,
+                };
+                let chain_target = match cue.chain {
+                    CueChain::DoNotChain => None,
+                    CueChain::AfterStart { target_id, .. }
+                    | CueChain::AfterComplete { target_id, .. } => target_id,
+                };
+                [param_target, chain_target]
+                    .into_iter()
+                    .flatten()
+                    .any(|target| removed_cues.contains(&target))
+            })
+            .map(|cue| cue.id)
+            .collect();
+        if !affected_cues.is_empty()
+            && model.settings.general.reference_policy == ReferencePolicy::Refuse
+        {
+            let mut numbers: Vec<_> = affected_cues
+                .iter()
+                .filter_map(|id| model.cue_list.cues.get(id))
+                .map(|cue| cue.number.clone())
+                .collect();
+            numbers.sort();
+            bail!("still referenced by cue {}", numbers.join(", "));
+        }
+
+        for cue_id in &affected_cues {
+            let Some(cue) = model.cue_list.cues.get_mut(cue_id) else {
+                continue;
+            };
+            match &mut cue.params {
+                CueParam::Fade(FadeCueParam { target, .. })
+                | CueParam::Start(StartCueParam { target })
+                | CueParam::Stop(StopCueParam { target, .. })
+                | CueParam::Pause(PauseCueParam { target })
+                | CueParam::Load(LoadCueParam { target })
+                    if removed_cues.contains(target) =>
+                {
+                    *target = Uuid::nil();
+                }
+                _ => {}
+            }
+            // a nil target means "follow the next cue", so drop the chain instead
+            if let CueChain::AfterStart { target_id, .. }
+            | CueChain::AfterComplete { target_id, .. } = &cue.chain
+                && target_id.is_some_and(|target| removed_cues.contains(&target))
             {
+                cue.chain = CueChain::DoNotChain;
+            }
+        }
+
+        for cue_id in &removed_cues {
+            let Some(cue) = model.cue_list.cues.remove(cue_id) else {
+                continue;
+            };
+            if let Some(parent_id) = cue.parent_id {
                 if let Some(parent) = model.cue_list.cues.get_mut(&parent_id)
                     && let CueParam::Group { children, .. } = &mut parent.params
                 {
-                    children.retain(|&id| id != target_id);
+                    children.retain(|id| id != cue_id);
                 }
-            } else {
-                model.cue_list.root_ids.retain(|&id| id != target_id);
+            } else if let Some(root_ids) = model.cue_list.root_ids_containing_mut(cue_id) {
+                root_ids.retain(|id| id != cue_id);
             }
-            let Some(target_cue) = model.cue_list.cues.remove(&target_id) else {
-                continue;
-            };
-            if let CueParam::Group { children, .. } = &target_cue.params {
-                queue.extend(children);
+        }
+
+        // cart slots are not cues, so they are emptied regardless of the reference policy
+        let mut cart_modified = false;
+        for slot in model.cart.iter_mut() {
+            if slot.is_some_and(|slot| removed_cues.contains(&slot.cue_id)) {
+                *slot = None;
+                cart_modified = true;
+            }
+        }
+        if cart_modified {
+            while model.cart.last().is_some_and(Option::is_none) {
+                model.cart.pop();
+            }
+            if let Err(e) = self.event_tx.send(BackendEvent::CartUpdated {
+                cart: model.cart.clone(),
+            }) {
+                log::warn!("Failed to send event, {}", e);
             }
-            removed_cues.insert(target_id);
         }
-        removed_cues
+        Ok((removed_cues, affected_cues))
+    }
+
+    async fn set_cart_slot(&self, index: usize, slot: Option<CartSlot>) -> anyhow::Result<()> {
+        if index >= MAX_CART_SLOTS {
+            bail!("slot index {} is out of range", index);
+        }
+        let mut model = self.model.write().await;
+        if let Some(slot) = &slot
+            && !model.cue_list.cues.contains_key(&slot.cue_id)
+        {
+            bail!("cue id={} not found", slot.cue_id);
+        }
+        if model.cart.len() <= index {
+            model.cart.resize(index + 1, None);
+        }
+        model.cart[index] = slot;
+        while model.cart.last().is_some_and(Option::is_none) {
+            model.cart.pop();
+        }
+        self.modify_status.store(true, Ordering::Release);
+        if let Err(e) = self.event_tx.send(BackendEvent::CartUpdated {
+            cart: model.cart.clone(),
+        }) {
+            log::warn!("Failed to send event, {}", e);
+        }
+        Ok(())
+    }
+
+    /// Removes a list and its cues. The cues go through `remove_cues_by_id`,
+    /// so the reference policy applies to them as well.
+    async fn remove_cue_list(
+        &self,
+        list_id: Uuid,
+    ) -> anyhow::Result<Option<(HashSet<Uuid>, HashSet<Uuid>)>> {
+        if list_id == MAIN_CUE_LIST_ID {
+            anyhow::bail!("the main cue list cannot be removed");
+        }
+        let Some(root_ids) = self.read().await.cue_list.root_ids_of(&list_id).cloned() else {
+            anyhow::bail!("id={} not found", list_id);
+        };
+        let removed = if root_ids.is_empty() {
+            None
+        } else {
+            Some(
+                self.remove_cues_by_id(root_ids.into_iter().collect())
+                    .await?,
+            )
+        };
+        self.model
+            .write()
+            .await
+            .cue_list
+            .lists
+            .retain(|list| list.id != list_id);
+        Ok(removed)
     }
 
     async fn update_cue_by_id(&self, cue_id: &Uuid, new_cue: Cue) -> anyhow::Result<()> {
+        if !new_cue.timeline_offset.is_finite() || new_cue.timeline_offset < 0.0 {
+            bail!("invalid timeline offset {}", new_cue.timeline_offset);
+        }
         let mut model = self.model.write().await;
 
         if let Some(cue) = model.cue_list.cues.get_mut(cue_id) {
@@ -629,7 +1195,9 @@ impl ShowModelManager {
             cue.name = new_cue.name;
             cue.notes = new_cue.notes;
             cue.color = new_cue.color;
+            cue.armed = new_cue.armed;
             cue.pre_wait = new_cue.pre_wait;
+            cue.timeline_offset = new_cue.timeline_offset;
             cue.chain = new_cue.chain;
             match (&mut cue.params, new_cue.params) {
                 (CueParam::Audio(p), CueParam::Audio(new_p)) => {
@@ -674,7 +1242,7 @@ impl ShowModelManager {
         let mut model = RollbackGuard::from(&mut model_guard.cue_list);
         let mut move_ids = Vec::new();
 
-        let mut queue: VecDeque<_> = model.cue_list.root_ids.iter().rev().copied().collect();
+        let mut queue: VecDeque<_> = model.cue_list.all_root_ids().rev().copied().collect();
         while let Some(target_id) = queue.pop_back() {
             if cue_ids.remove(&target_id) {
                 move_ids.push(target_id);
@@ -701,13 +1269,15 @@ impl ShowModelManager {
                 {
                     children.retain(|x| !move_set.contains(x));
                 }
-            } else {
-                model.cue_list.root_ids.retain(|x| !move_set.contains(x));
+            } else if let Some(root_ids) = model.cue_list.root_ids_containing_mut(id) {
+                root_ids.retain(|x| !move_set.contains(x));
             }
         }
 
-        let (new_parent_id, start_idx) = match position {
-            InsertPosition::Before { target } => {
+        // top-level destinations carry the list they belong to
+        let (new_parent_id, list_id, start_idx) = match position {
+            InsertPosition::Before { target } | InsertPosition::After { target } => {
+                let after = matches!(position, InsertPosition::After { .. });
                 if let Some(parent_id) = model
                     .cue_list
                     .cues
@@ -718,69 +1288,51 @@ impl ShowModelManager {
                         && let CueParam::Group { children, .. } = &parent.params
                         && let Some(index) = children.iter().position(|&id| id == target)
                     {
-                        (Some(parent_id), index)
-                    } else {
-                        return Err(anyhow::anyhow!("Invalid tree structure"));
-                    }
-                } else {
-                    if let Some(index) = model.cue_list.root_ids.iter().position(|&id| id == target)
-                    {
-                        (None, index)
+                        (Some(parent_id), MAIN_CUE_LIST_ID, index + after as usize)
                     } else {
                         return Err(anyhow::anyhow!("Invalid tree structure"));
                     }
-                }
-            }
-            InsertPosition::After { target } => {
-                if let Some(parent_id) = model
-                    .cue_list
-                    .cues
-                    .get(&target)
-                    .and_then(|cue| cue.parent_id)
+                } else if let Some(list_id) = model.cue_list.list_id_of(&target)
+                    && let Some(index) = model
+                        .cue_list
+                        .root_ids_of(&list_id)
+                        .and_then(|root_ids| root_ids.iter().position(|&id| id == target))
                 {
-                    if let Some(parent) = model.cue_list.cues.get_mut(&parent_id)
-                        && let CueParam::Group { children, .. } = &mut parent.params
-                        && let Some(mut index) = children.iter().position(|&id| id == target)
-                    {
-                        index += 1;
-                        (Some(parent_id), index)
-                    } else {
-                        return Err(anyhow::anyhow!("Invalid tree structure"));
-                    }
+                    (None, list_id, index + after as usize)
                 } else {
-                    if let Some(mut index) =
-                        model.cue_list.root_ids.iter().position(|&id| id == target)
-                    {
-                        index += 1;
-                        (None, index)
-                    } else {
-                        return Err(anyhow::anyhow!("Invalid tree structure"));
-                    }
+                    return Err(anyhow::anyhow!("Invalid tree structure"));
                 }
             }
-            InsertPosition::Inside { target, index } => {
-                if let Some(parent_id) = target {
-                    if let Some(parent) = model.cue_list.cues.get_mut(&parent_id)
-                        && let CueParam::Group { children, .. } = &mut parent.params
-                    {
-                        let idx = match index {
-                            Some(idx) if idx <= children.len() => idx,
-                            Some(_) => return Err(anyhow::anyhow!("insert index out of range.")),
-                            None => children.len(),
-                        };
-                        (Some(parent_id), idx)
-                    } else {
-                        return Err(anyhow::anyhow!("target id not found."));
-                    }
-                } else {
+            InsertPosition::Inside {
+                target: Some(parent_id),
+                index,
+            } => {
+                if let Some(parent) = model.cue_list.cues.get_mut(&parent_id)
+                    && let CueParam::Group { children, .. } = &mut parent.params
+                {
                     let idx = match index {
-                        Some(idx) if idx <= model.cue_list.root_ids.len() => idx,
+                        Some(idx) if idx <= children.len() => idx,
                         Some(_) => return Err(anyhow::anyhow!("insert index out of range.")),
-                        None => model.cue_list.root_ids.len(),
+                        None => children.len(),
                     };
-                    (None, idx)
+                    (Some(parent_id), MAIN_CUE_LIST_ID, idx)
+                } else {
+                    return Err(anyhow::anyhow!("target id not found."));
                 }
             }
+            InsertPosition::Inside {
+                target: None,
+                index,
+            } => (
+                None,
+                MAIN_CUE_LIST_ID,
+                model.cue_list.root_insert_index(&MAIN_CUE_LIST_ID, index)?,
+            ),
+            InsertPosition::List { list_id, index } => (
+                None,
+                list_id,
+                model.cue_list.root_insert_index(&list_id, index)?,
+            ),
         };
 
         let mut ancestor_id = new_parent_id;
@@ -809,17 +1361,265 @@ impl ShowModelManager {
             {
                 children.splice(start_idx..start_idx, move_ids);
             }
-        } else {
-            model
-                .cue_list
-                .root_ids
-                .splice(start_idx..start_idx, move_ids);
+        } else if let Some(root_ids) = model.cue_list.root_ids_of_mut(&list_id) {
+            root_ids.splice(start_idx..start_idx, move_ids);
         }
 
         model.success = true;
         Ok(())
     }
 
+    /// Loads a project file and notifies listeners, replacing the current show.
+    async fn open_project_file(&mut self, path: PathBuf) {
+        let event = match self.load_from_file(path.as_path()).await {
+            Err(error) => {
+                log::error!("Failed to load model file: {}", error);
+                BackendEvent::OperationFailed {
+                    error: BackendError::LoadFromFile {
+                        path,
+                        message: error.to_string(),
+                    },
+                }
+            }
+            Ok(project_type) => {
+                self.history.clear();
+                self.modify_status.store(false, Ordering::Release);
+                {
+                    let mut project_status = self.project_status.write().await;
+                    *project_status = ProjectStatus::Saved {
+                        project_type,
+                        path: path.clone(),
+                    };
+                }
+                let model = self.read().await.clone();
+                BackendEvent::ShowModelLoaded {
+                    model,
+                    project_type,
+                    path,
+                }
+            }
+        };
+        let loaded = matches!(event, BackendEvent::ShowModelLoaded { .. });
+        if let Err(e) = self.event_tx.send(event) {
+            log::warn!("Failed to send event, {}", e);
+        }
+        if loaded {
+            let missing = self.missing_assets().await;
+            if !missing.is_empty()
+                && let Err(e) = self.event_tx.send(BackendEvent::AssetsMissing { missing })
+            {
+                log::warn!("Failed to send event, {}", e);
+            }
+            self.check_recovery().await;
+        }
+    }
+
+    /// Folder holding the project file, which relative targets resolve against.
+    async fn model_dir(&self) -> Option<PathBuf> {
+        self.project_status
+            .read()
+            .await
+            .to_model_path_option()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+    }
+
+    async fn missing_assets(&self) -> Vec<MissingAsset> {
+        let model_dir = self.model_dir().await;
+        asset_import::find_missing_assets(&*self.model.read().await, model_dir.as_deref())
+    }
+
+    /// Packs the project file and every audio asset into a single bundle.
+    /// Asset targets are rewritten to their place inside the bundle.
+    async fn export_bundle(&self, path: &Path) -> anyhow::Result<()> {
+        let mut model = self.model.read().await.clone();
+        let model_dir = self
+            .project_status
+            .read()
+            .await
+            .to_model_path_option()
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+        let resolve = |target: &PathBuf| match &model_dir {
+            Some(model_dir) => model_dir.join(target),
+            None => target.clone(),
+        };
+
+        let sources: Vec<PathBuf> = model
+            .cue_list
+            .cues
+            .values()
+            .filter_map(|cue| match &cue.params {
+                CueParam::Audio(param) => Some(resolve(&param.target)),
+                _ => None,
+            })
+            .collect();
+        if let Some(missing) = sources.iter().find(|source| !source.is_file()) {
+            bail!("Asset not found: {}", missing.display());
+        }
+        let entries = bundle::asset_entries(&sources);
+        for cue in model.cue_list.cues.values_mut() {
+            if let CueParam::Audio(param) = &mut cue.params {
+                param.target = PathBuf::from(&entries[&resolve(&param.target)]);
+            }
+        }
+
+        let project_file = ProjectFile {
+            version: PROJECT_FILE_VERSION,
+            project_type: ProjectType::ProjectFolder,
+            model: model.into(),
+        };
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || bundle::write_bundle(&path, &project_file, &entries))
+            .await??;
+        log::info!("Show bundle exported.");
+        Ok(())
+    }
+
+    /// Writes a printable cue sheet. Audio durations are read from the file
+    /// headers; missing or unreadable files leave the duration blank.
+    async fn export_cue_sheet(&self, path: &Path, format: CueSheetFormat) -> anyhow::Result<()> {
+        let model = self.model.read().await.clone();
+        let model_dir = self
+            .project_status
+            .read()
+            .await
+            .to_model_path_option()
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+        let targets: HashSet<PathBuf> = model
+            .cue_list
+            .cues
+            .values()
+            .filter_map(|cue| match &cue.params {
+                CueParam::Audio(param) => Some(param.target.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let content = tokio::task::spawn_blocking(move || {
+            let durations: HashMap<PathBuf, f64> = targets
+                .into_iter()
+                .filter_map(|target| {
+                    let actual_path = match &model_dir {
+                        Some(model_dir) => model_dir.join(&target),
+                        None => target.clone(),
+                    };
+                    let duration = AssetProcessor::read_metadata(&actual_path)
+                        .inspect_err(|e| {
+                            log::warn!("Failed to read metadata of {:?}: {}", actual_path, e)
+                        })
+                        .ok()?
+                        .duration?;
+                    Some((target, duration))
+                })
+                .collect();
+            cue_sheet::render(&model, &durations, format)
+        })
+        .await?;
+
+        Self::write_atomically(path, content.into_bytes()).await
+    }
+
+    /// Adds cues that do not exist yet, importing their assets like `AddCue`.
+    /// Returns whether any cue was inserted.
+    async fn add_cues(&self, cues: Vec<Cue>, position: InsertPosition) -> bool {
+        let mut imported_assets = Vec::new();
+        let mut valid_cues = Vec::new();
+        let mut valid_cue_ids = HashSet::new();
+
+        for mut cue in cues {
+            if self.is_cue_exists(&cue.id).await {
+                if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                    error: BackendError::CueEdit {
+                        message: "Failed to add cue, id already exists.".into(),
+                    },
+                }) {
+                    log::warn!("Failed to send event, {}", e);
+                }
+                continue;
+            } else if !valid_cue_ids.insert(cue.id) {
+                if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                    error: BackendError::CueEdit {
+                        message: "Failed to add cue, duplicate id found.".into(),
+                    },
+                }) {
+                    log::warn!("Failed to send event, {}", e);
+                }
+                continue;
+            }
+            self.import_cue_asset(&mut cue, &mut imported_assets).await;
+            valid_cues.push(cue);
+        }
+        if valid_cues.is_empty() {
+            return false;
+        }
+        if let Err(e) = self.insert_cues_at_position(valid_cues, position).await {
+            if let Err(e) = self.event_tx.send(BackendEvent::OperationFailed {
+                error: BackendError::CueEdit {
+                    message: format!("Failed to add cues, {}.", e),
+                },
+            }) {
+                log::warn!("Failed to send event, {}", e);
+            }
+            return false;
+        }
+
+        self.modify_status.store(true, Ordering::Release);
+        if let Err(e) = self.event_tx.send(BackendEvent::CueListUpdated {
+            cue_list: self.model.read().await.cue_list.clone(),
+        }) {
+            log::warn!("Failed to send event, {}", e);
+        }
+        self.report_imported_assets(imported_assets);
+        true
+    }
+
+    /// Copies the file of an audio cue into the project when asset copying is
+    /// enabled and the project is saved, rewriting the target. Failures are
+    /// logged and leave the absolute target in place.
+    async fn import_cue_asset(&self, cue: &mut Cue, report: &mut Vec<AssetImportEntry>) {
+        let CueParam::Audio(audio_param) = &mut cue.params else {
+            return;
+        };
+        if !self.copy_assets_when_add {
+            return;
+        }
+        let Some(model_path) = self.project_status.read().await.to_model_path_option() else {
+            return;
+        };
+        let import_destination = {
+            let model = self.model.read().await;
+            model.settings.general.copy_assets_destination.clone()
+        };
+        match asset_import::import_asset_file(&audio_param.target, &model_path, &import_destination)
+            .await
+        {
+            Ok((target, outcome)) => {
+                if let Some(outcome) = outcome {
+                    report.push(AssetImportEntry {
+                        source: audio_param.target.clone(),
+                        target: target.clone(),
+                        outcome,
+                    });
+                    audio_param.original_path = Some(audio_param.target.clone());
+                }
+                audio_param.target = target;
+            }
+            Err(e) => log::warn!(
+                "Failed to import asset {}, {}",
+                audio_param.target.display(),
+                e
+            ),
+        }
+    }
+
+    fn report_imported_assets(&self, entries: Vec<AssetImportEntry>) {
+        if entries.is_empty() {
+            return;
+        }
+        if let Err(e) = self.event_tx.send(BackendEvent::AssetsImported { entries }) {
+            log::warn!("Failed to send event, {}", e);
+        }
+    }
+
     async fn insert_cues_at_position(
         &self,
         insert_cues: Vec<Cue>,
@@ -852,22 +1652,13 @@ impl ShowModelManager {
                             }));
                         return Ok(());
                     }
-                } else {
-                    if let Some(index) = model.cue_list.root_ids.iter().position(|&id| id == target)
-                    {
-                        model.cue_list.root_ids.splice(index..index, insert_ids);
-                        model
-                            .cue_list
-                            .cues
-                            .extend(insert_cues.into_iter().map(|mut cue| {
-                                cue.parent_id = None;
-                                if let CueParam::Group { children, .. } = &mut cue.params {
-                                    children.clear();
-                                }
-                                (cue.id, cue)
-                            }));
-                        return Ok(());
-                    }
+                } else if let Some(list_id) = model.cue_list.list_id_of(&target)
+                    && let Some(index) = model
+                        .cue_list
+                        .root_ids_of(&list_id)
+                        .and_then(|root_ids| root_ids.iter().position(|&id| id == target))
+                {
+                    return Self::insert_root_cues(&mut model, insert_cues, list_id, Some(index));
                 }
 
                 Err(anyhow::anyhow!("target id not found."))
@@ -897,24 +1688,18 @@ impl ShowModelManager {
                             }));
                         return Ok(());
                     }
-                } else {
-                    if let Some(mut index) =
-                        model.cue_list.root_ids.iter().position(|&id| id == target)
-                    {
-                        index += 1;
-                        model.cue_list.root_ids.splice(index..index, insert_ids);
-                        model
-                            .cue_list
-                            .cues
-                            .extend(insert_cues.into_iter().map(|mut cue| {
-                                cue.parent_id = None;
-                                if let CueParam::Group { children, .. } = &mut cue.params {
-                                    children.clear();
-                                }
-                                (cue.id, cue)
-                            }));
-                        return Ok(());
-                    }
+                } else if let Some(list_id) = model.cue_list.list_id_of(&target)
+                    && let Some(index) = model
+                        .cue_list
+                        .root_ids_of(&list_id)
+                        .and_then(|root_ids| root_ids.iter().position(|&id| id == target))
+                {
+                    return Self::insert_root_cues(
+                        &mut model,
+                        insert_cues,
+                        list_id,
+                        Some(index + 1),
+                    );
                 }
                 Err(anyhow::anyhow!("target id not found."))
             }
@@ -944,33 +1729,40 @@ impl ShowModelManager {
                         Err(anyhow::anyhow!("target id not found."))
                     }
                 } else {
-                    let idx = match index {
-                        Some(idx) if idx <= model.cue_list.root_ids.len() => idx,
-                        Some(_) => return Err(anyhow::anyhow!("insert index out of range.")),
-                        None => model.cue_list.root_ids.len(),
-                    };
-                    model.cue_list.root_ids.splice(idx..idx, insert_ids);
-                    model
-                        .cue_list
-                        .cues
-                        .extend(insert_cues.into_iter().map(|mut cue| {
-                            cue.parent_id = None;
-                            if let CueParam::Group { children, .. } = &mut cue.params {
-                                children.clear();
-                            }
-                            (cue.id, cue)
-                        }));
-                    Ok(())
+                    Self::insert_root_cues(&mut model, insert_cues, MAIN_CUE_LIST_ID, index)
                 }
             }
+            InsertPosition::List { list_id, index } => {
+                Self::insert_root_cues(&mut model, insert_cues, list_id, index)
+            }
         }
     }
 
-    pub async fn load_from_file(&self, path: &Path) -> Result<ProjectType, anyhow::Error> {
-        let content = tokio::fs::read_to_string(path).await?;
+    fn insert_root_cues(
+        model: &mut ShowModel,
+        insert_cues: Vec<Cue>,
+        list_id: Uuid,
+        index: Option<usize>,
+    ) -> anyhow::Result<()> {
+        let idx = model.cue_list.root_insert_index(&list_id, index)?;
+        if let Some(root_ids) = model.cue_list.root_ids_of_mut(&list_id) {
+            root_ids.splice(idx..idx, insert_cues.iter().map(|cue| cue.id));
+        }
+        model
+            .cue_list
+            .cues
+            .extend(insert_cues.into_iter().map(|mut cue| {
+                cue.parent_id = None;
+                if let CueParam::Group { children, .. } = &mut cue.params {
+                    children.clear();
+                }
+                (cue.id, cue)
+            }));
+        Ok(())
+    }
 
-        let project_file: ProjectFile =
-            tokio::task::spawn_blocking(move || serde_json::from_str(&content)).await??;
+    pub async fn load_from_file(&self, path: &Path) -> Result<ProjectType, anyhow::Error> {
+        let project_file = Self::read_project_file(path).await?;
 
         {
             let mut model = self.model.write().await;
@@ -1047,9 +1839,12 @@ impl ShowModelManager {
 
                     for target in targets.values_mut() {
                         let asset_path = parent.join(&*target);
-                        let new_path =
-                            Self::import_asset_file(&asset_path, project_dir, &import_destination)
-                                .await?;
+                        let (new_path, _) = asset_import::import_asset_file(
+                            &asset_path,
+                            project_dir,
+                            &import_destination,
+                        )
+                        .await?;
                         *target = new_path;
                     }
 
@@ -1080,21 +1875,40 @@ impl ShowModelManager {
                             .collect()
                     };
 
-                    for target in targets.values_mut() {
-                        let new_path =
-                            Self::import_asset_file(&*target, project_dir, &import_destination)
-                                .await?;
+                    let mut imported_assets = Vec::new();
+                    let mut original_paths = HashMap::new();
+                    for (id, target) in targets.iter_mut() {
+                        let (new_path, outcome) = asset_import::import_asset_file(
+                            &*target,
+                            project_dir,
+                            &import_destination,
+                        )
+                        .await?;
+                        if let Some(outcome) = outcome {
+                            imported_assets.push(AssetImportEntry {
+                                source: target.clone(),
+                                target: new_path.clone(),
+                                outcome,
+                            });
+                            original_paths.insert(*id, target.clone());
+                        }
                         *target = new_path;
                     }
 
-                    let mut model = self.model.write().await;
-                    for (id, target) in targets {
-                        if let Some(cue) = model.cue_list.cues.get_mut(&id)
-                            && let CueParam::Audio(params) = &mut cue.params
-                        {
-                            params.target = target;
-                        }
-                    }
+                    {
+                        let mut model = self.model.write().await;
+                        for (id, target) in targets {
+                            if let Some(cue) = model.cue_list.cues.get_mut(&id)
+                                && let CueParam::Audio(params) = &mut cue.params
+                            {
+                                params.target = target;
+                                if let Some(original_path) = original_paths.remove(&id) {
+                                    params.original_path = Some(original_path);
+                                }
+                            }
+                        }
+                    }
+                    self.report_imported_assets(imported_assets);
                 }
                 model_modified = true;
             } else {
@@ -1105,40 +1919,123 @@ impl ShowModelManager {
         let project_file = {
             let model = self.model.read().await;
             ProjectFile {
+                version: PROJECT_FILE_VERSION,
                 project_type: *project_type,
                 model: model.clone().into(),
             }
         };
 
+        if let Err(e) = Self::backup_file(path, self.backup_count).await {
+            log::warn!("Failed to back up {}, {}", path.display(), e);
+        }
+        Self::write_project_file(path, project_file).await?;
+        // the saved file supersedes any autosaved state
+        Self::remove_recovery_file(&Self::recovery_path_for(path)).await;
+        Self::remove_recovery_file(&Self::recovery_path(&project_status)).await;
+        log::info!("Show saved to: {}", path.display());
+        Ok(model_modified)
+    }
+
+    async fn read_project_file(path: &Path) -> anyhow::Result<ProjectFile> {
+        let content = tokio::fs::read_to_string(path).await?;
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<ProjectFile> {
+            let mut document: serde_json::Value = serde_json::from_str(&content)?;
+            migration::migrate(&mut document)?;
+            Ok(serde_json::from_value(document)?)
+        })
+        .await?
+    }
+
+    async fn write_project_file(path: &Path, project_file: ProjectFile) -> anyhow::Result<()> {
         let content =
             tokio::task::spawn_blocking(move || serde_json::to_string_pretty(&project_file))
                 .await??;
+        Self::write_atomically(path, content.into_bytes()).await
+    }
 
-        tokio::fs::write(&path, content).await?;
-        log::info!("Show saved to: {}", path.display());
-        Ok(model_modified)
+    /// Writes to a temporary file beside `path` and renames it into place,
+    /// so a crash never leaves a half-written file behind.
+    async fn write_atomically(path: &Path, content: Vec<u8>) -> anyhow::Result<()> {
+        let mut temp_file_name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("Invalid file path."))?
+            .to_os_string();
+        temp_file_name.push(".tmp");
+        let temp_path = path.with_file_name(temp_file_name);
+        let path = path.to_path_buf();
+
+        tokio::task::spawn_blocking(move || -> std::io::Result<()> {
+            let write = || {
+                let mut file = std::fs::File::create(&temp_path)?;
+                file.write_all(&content)?;
+                file.sync_all()?;
+                drop(file);
+                std::fs::rename(&temp_path, &path)
+            };
+            let result = write();
+            if result.is_err() {
+                let _ = std::fs::remove_file(&temp_path);
+            }
+            result
+        })
+        .await??;
+        Ok(())
     }
 
-    async fn import_asset_file(
-        asset_path: &PathBuf,
-        model_path: &Path,
-        import_destination: &String,
-    ) -> anyhow::Result<PathBuf> {
-        log::info!("Import asset file started. file={:?}", asset_path);
-        let audio_dir = model_path.join(import_destination);
-        if !audio_dir.exists() {
-            tokio::fs::create_dir_all(&audio_dir).await?;
-        } else if audio_dir.is_file() {
-            anyhow::bail!("Failed to copy asset to destination. destination is not directory");
-        }
-        let asset_file_name = asset_path.file_name().unwrap().to_str().unwrap();
-        let dest_path = audio_dir.join(asset_file_name);
-        if !dest_path.exists() {
-            tokio::fs::copy(asset_path, &dest_path).await?;
-        }
-        Ok([import_destination.clone(), asset_file_name.to_string()]
-            .iter()
-            .collect())
+    /// Copies the current file to `<name>.<timestamp>.bak` and prunes all but
+    /// the newest `backup_count` backups.
+    async fn backup_file(path: &Path, backup_count: usize) -> anyhow::Result<()> {
+        if backup_count == 0 || !tokio::fs::try_exists(path).await? {
+            return Ok(());
+        }
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("Invalid file path."))?
+            .to_string_lossy()
+            .into_owned();
+        let mut timestamp = chrono::Local::now().naive_local();
+        let mut backup_path;
+        loop {
+            backup_path = path.with_file_name(format!(
+                "{}.{}.bak",
+                file_name,
+                timestamp.format(BACKUP_TIMESTAMP_FORMAT)
+            ));
+            // saving twice within a millisecond must not overwrite the earlier backup
+            if !tokio::fs::try_exists(&backup_path).await? {
+                break;
+            }
+            timestamp += chrono::TimeDelta::milliseconds(1);
+        }
+        tokio::fs::copy(path, backup_path).await?;
+
+        let directory = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let prefix = format!("{}.", file_name);
+        let mut backups = Vec::new();
+        let mut entries = tokio::fs::read_dir(directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let entry_name = entry.file_name().to_string_lossy().into_owned();
+            // only prune backups written here, never other files that happen to match
+            if let Some(timestamp) = entry_name
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(".bak"))
+                && let Ok(timestamp) =
+                    chrono::NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+            {
+                backups.push((timestamp, entry.path()));
+            }
+        }
+        // oldest first
+        backups.sort();
+        let excess = backups.len().saturating_sub(backup_count);
+        for (_, backup) in backups.into_iter().take(excess) {
+            tokio::fs::remove_file(backup).await?;
+        }
+        Ok(())
     }
 
     #[cfg(test)]
@@ -1150,26 +2047,34 @@ impl ShowModelManager {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{
+        collections::{HashMap, HashSet},
+        path::PathBuf,
+    };
 
     use crate::{
         BackendSettings,
-        event::BackendEvent,
-        manager::{ProjectStatus, ProjectType, command::InsertPosition},
+        event::{BackendError, BackendEvent},
+        manager::{
+            ProjectStatus, ProjectType, asset_import::AssetImportOutcome, command::InsertPosition,
+            project::ProjectShowModel,
+        },
         model::{
             ShowModel,
+            cart::{CartSlot, CartSlotMode, MAX_CART_SLOTS},
             cue::{
-                Cue, CueChain, CueColor, CueList, CueParam,
+                Cue, CueChain, CueColor, CueList, CueParam, MAIN_CUE_LIST_ID, StopCueParam,
+                WaitCueParam,
                 audio::{AudioCueParam, Decibels, SoundType},
             },
-            settings::ShowSettings,
+            settings::{ReferencePolicy, ShowSettings},
         },
     };
     use tempfile::{NamedTempFile, tempdir};
     use tokio::sync::{broadcast, watch};
     use uuid::Uuid;
 
-    use super::{ShowModelHandle, ShowModelManager};
+    use super::{PROJECT_FILE_VERSION, ProjectFile, ShowModelHandle, ShowModelManager};
 
     async fn setup_manager(
         initial_model: Option<ShowModel>,
@@ -1209,7 +2114,9 @@ mod tests {
                             name: Some("test cue".into()),
                             notes: "note".into(),
                             color: CueColor::None,
+                            armed: true,
                             pre_wait: 0.0,
+                            timeline_offset: 0.0,
                             chain: CueChain::DoNotChain,
                             parent_id: None,
                             params: CueParam::Audio(AudioCueParam {
@@ -1223,12 +2130,15 @@ mod tests {
                                 repeat: false,
                                 sound_type: SoundType::Streaming,
                                 envelope: Vec::new(),
+                                original_path: None,
                             }),
                         },
                     )]),
                     root_ids: vec![cue_id],
+                    lists: Vec::new(),
                 },
                 settings: ShowSettings::default(),
+                cart: Vec::new(),
             }),
             ProjectStatus::Saved {
                 project_type: ProjectType::ProjectFolder,
@@ -1243,7 +2153,9 @@ mod tests {
             name: Some("test cue".into()),
             notes: "note".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Audio(AudioCueParam {
@@ -1257,6 +2169,7 @@ mod tests {
                 repeat: false,
                 sound_type: SoundType::Streaming,
                 envelope: Vec::new(),
+                original_path: None,
             }),
         };
         model_handle.update_cue(new_cue.clone()).await.unwrap();
@@ -1271,6 +2184,7 @@ mod tests {
         let mut estimated_new_cue = new_cue.clone();
         if let CueParam::Audio(audio_param) = &mut estimated_new_cue.params {
             audio_param.target = [".", estimated_audio_filename].iter().collect();
+            audio_param.original_path = Some(temp_target_after.path().to_path_buf());
         }
 
         loop {
@@ -1300,6 +2214,7 @@ mod tests {
                 name: "test".into(),
                 cue_list: CueList::default(),
                 settings: ShowSettings::default(),
+                cart: Vec::new(),
             }),
             ProjectStatus::Saved {
                 project_type: ProjectType::ProjectFolder,
@@ -1314,7 +2229,9 @@ mod tests {
             name: Some("test cue".into()),
             notes: "note".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Audio(AudioCueParam {
@@ -1328,6 +2245,7 @@ mod tests {
                 repeat: false,
                 sound_type: SoundType::Streaming,
                 envelope: Vec::new(),
+                original_path: None,
             }),
         };
         model_handle
@@ -1346,6 +2264,7 @@ mod tests {
         let mut estimated_new_cue = new_cue.clone();
         if let CueParam::Audio(audio_param) = &mut estimated_new_cue.params {
             audio_param.target = [".", estimated_audio_filename].iter().collect();
+            audio_param.original_path = Some(temp_target.path().to_path_buf());
         }
 
         loop {
@@ -1364,4 +2283,620 @@ mod tests {
         drop(temp_target);
         drop(temp_dir);
     }
+
+    #[tokio::test]
+    async fn relink_missing_assets() {
+        let project_dir = tempdir().unwrap();
+        let asset_path = project_dir.path().join("audio").join("kick.wav");
+        std::fs::create_dir_all(asset_path.parent().unwrap()).unwrap();
+        std::fs::write(&asset_path, b"kick").unwrap();
+        let cue_id = Uuid::new_v4();
+        let mut cue_list = CueList::default();
+        cue_list.root_ids.push(cue_id);
+        cue_list.cues.insert(
+            cue_id,
+            Cue {
+                id: cue_id,
+                number: "1".into(),
+                name: None,
+                notes: String::new(),
+                color: CueColor::None,
+                armed: true,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Audio(AudioCueParam {
+                    target: project_dir.path().join("old machine").join("kick.wav"),
+                    start_time: None,
+                    fade_in_param: None,
+                    end_time: None,
+                    fade_out_param: None,
+                    volume: Decibels::IDENTITY,
+                    pan: 0.0,
+                    repeat: false,
+                    sound_type: SoundType::Streaming,
+                    envelope: Vec::new(),
+                    original_path: None,
+                }),
+            },
+        );
+        let (model_handle, mut event_rx) = setup_manager(
+            Some(ShowModel {
+                name: "test".into(),
+                cue_list,
+                settings: ShowSettings::default(),
+                cart: Vec::new(),
+            }),
+            ProjectStatus::Saved {
+                project_type: ProjectType::ProjectFolder,
+                path: project_dir.path().join("model.sbsp"),
+            },
+        )
+        .await;
+
+        model_handle
+            .relink_assets(project_dir.path().to_path_buf())
+            .await
+            .unwrap();
+
+        loop {
+            if let Ok(BackendEvent::AssetsRelinked { relinked, missing }) = event_rx.recv().await {
+                assert_eq!(relinked.len(), 1);
+                assert!(missing.is_empty());
+                break;
+            }
+        }
+        let model = model_handle.read().await;
+        let CueParam::Audio(param) = &model.cue_list.cues[&cue_id].params else {
+            panic!("audio cue expected");
+        };
+        assert_eq!(param.target, PathBuf::from("audio").join("kick.wav"));
+    }
+
+    #[tokio::test]
+    async fn add_cues_deduplicates_assets() {
+        let project_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let sources: Vec<_> = [("a", "first"), ("b", "second"), ("c", "first")]
+            .iter()
+            .map(|(folder, content)| {
+                let path = source_dir.path().join(folder).join("intro.wav");
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+        let (model_handle, mut event_rx) = setup_manager(
+            None,
+            ProjectStatus::Saved {
+                project_type: ProjectType::ProjectFolder,
+                path: project_dir.path().to_path_buf(),
+            },
+        )
+        .await;
+
+        let cues = sources
+            .iter()
+            .enumerate()
+            .map(|(index, source)| Cue {
+                id: Uuid::new_v4(),
+                number: (index + 1).to_string(),
+                name: None,
+                notes: String::new(),
+                color: CueColor::None,
+                armed: true,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Audio(AudioCueParam {
+                    target: source.clone(),
+                    start_time: None,
+                    fade_in_param: None,
+                    end_time: None,
+                    fade_out_param: None,
+                    volume: Decibels::IDENTITY,
+                    pan: 0.0,
+                    repeat: false,
+                    sound_type: SoundType::Streaming,
+                    envelope: Vec::new(),
+                    original_path: None,
+                }),
+            })
+            .collect();
+        model_handle
+            .add_cues(cues, InsertPosition::LAST)
+            .await
+            .unwrap();
+
+        let entries = loop {
+            if let Ok(BackendEvent::AssetsImported { entries }) = event_rx.recv().await {
+                break entries;
+            }
+        };
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.target.clone(), entry.outcome))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    [".", "intro.wav"].iter().collect(),
+                    AssetImportOutcome::Copied
+                ),
+                (
+                    [".", "intro (2).wav"].iter().collect(),
+                    AssetImportOutcome::Renamed
+                ),
+                (
+                    [".", "intro.wav"].iter().collect(),
+                    AssetImportOutcome::Reused
+                ),
+            ]
+        );
+        assert_eq!(entries[1].source, sources[1]);
+        assert_eq!(
+            std::fs::read(project_dir.path().join("intro (2).wav")).unwrap(),
+            b"second"
+        );
+        assert!(!project_dir.path().join("intro (3).wav").exists());
+    }
+
+    fn referenced_show(reference_policy: ReferencePolicy) -> (ShowModel, [Uuid; 3]) {
+        let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let cue = |index: usize, chain: CueChain, params: CueParam| Cue {
+            id: ids[index],
+            number: (index + 1).to_string(),
+            name: None,
+            notes: String::new(),
+            color: CueColor::None,
+            armed: true,
+            pre_wait: 0.0,
+            timeline_offset: 0.0,
+            chain,
+            parent_id: None,
+            params,
+        };
+        let mut model = ShowModel::default();
+        model.settings.general.reference_policy = reference_policy;
+        for cue in [
+            cue(
+                0,
+                CueChain::DoNotChain,
+                CueParam::Wait(WaitCueParam { duration: 5.0 }),
+            ),
+            cue(
+                1,
+                CueChain::DoNotChain,
+                CueParam::Stop(StopCueParam {
+                    target: ids[0],
+                    hard: false,
+                }),
+            ),
+            cue(
+                2,
+                CueChain::AfterComplete {
+                    target_id: Some(ids[0]),
+                    post_wait: 0.0,
+                },
+                CueParam::Wait(WaitCueParam { duration: 1.0 }),
+            ),
+        ] {
+            model.cue_list.root_ids.push(cue.id);
+            model.cue_list.cues.insert(cue.id, cue);
+        }
+        (model, ids)
+    }
+
+    #[tokio::test]
+    async fn remove_cue_clears_references() {
+        let (model, [removed, stop, chained]) = referenced_show(ReferencePolicy::Clear);
+        let (model_handle, mut event_rx) = setup_manager(Some(model), ProjectStatus::Unsaved).await;
+
+        model_handle.remove_cue(removed).await.unwrap();
+        loop {
+            if let Ok(BackendEvent::CueRemoved {
+                cue_ids,
+                affected_cue_ids,
+            }) = event_rx.recv().await
+            {
+                assert_eq!(cue_ids, HashSet::from([removed]));
+                assert_eq!(affected_cue_ids, HashSet::from([stop, chained]));
+                break;
+            }
+        }
+
+        let model = model_handle.read().await;
+        assert!(matches!(
+            &model.cue_list.cues[&stop].params,
+            CueParam::Stop(param) if param.target.is_nil()
+        ));
+        assert!(matches!(
+            model.cue_list.cues[&chained].chain,
+            CueChain::DoNotChain
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_timeline_offset() {
+        let (model, [cue_id, ..]) = referenced_show(ReferencePolicy::Clear);
+        let (model_handle, mut event_rx) = setup_manager(Some(model), ProjectStatus::Unsaved).await;
+
+        let mut cue = model_handle.get_cue_by_id(&cue_id).await.unwrap();
+        cue.timeline_offset = 2.5;
+        model_handle.update_cue(cue.clone()).await.unwrap();
+        loop {
+            if let Ok(BackendEvent::CueListUpdated { .. }) = event_rx.recv().await {
+                break;
+            }
+        }
+        assert_eq!(
+            model_handle
+                .get_cue_by_id(&cue_id)
+                .await
+                .unwrap()
+                .timeline_offset,
+            2.5
+        );
+
+        for invalid in [-1.0, f64::NAN, f64::INFINITY] {
+            cue.timeline_offset = invalid;
+            model_handle.update_cue(cue.clone()).await.unwrap();
+            loop {
+                if let Ok(BackendEvent::OperationFailed {
+                    error: BackendError::CueEdit { message },
+                }) = event_rx.recv().await
+                {
+                    assert!(message.contains("invalid timeline offset"));
+                    break;
+                }
+            }
+        }
+        assert_eq!(
+            model_handle
+                .get_cue_by_id(&cue_id)
+                .await
+                .unwrap()
+                .timeline_offset,
+            2.5
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_referenced_cue_refused() {
+        let (model, [removed, ..]) = referenced_show(ReferencePolicy::Refuse);
+        let (model_handle, mut event_rx) = setup_manager(Some(model), ProjectStatus::Unsaved).await;
+
+        model_handle.remove_cue(removed).await.unwrap();
+        loop {
+            match event_rx.recv().await {
+                Ok(BackendEvent::OperationFailed {
+                    error: BackendError::CueEdit { message },
+                }) => {
+                    assert!(message.contains("referenced by cue 2, 3"));
+                    break;
+                }
+                Ok(BackendEvent::CueRemoved { .. }) => panic!("referenced cue was removed"),
+                _ => {}
+            }
+        }
+        assert!(
+            model_handle
+                .read()
+                .await
+                .cue_list
+                .cues
+                .contains_key(&removed)
+        );
+    }
+
+    #[tokio::test]
+    async fn named_cue_lists() {
+        let (model, [first, second, third]) = referenced_show(ReferencePolicy::Clear);
+        let (model_handle, mut event_rx) = setup_manager(Some(model), ProjectStatus::Unsaved).await;
+
+        model_handle.add_cue_list("Sound FX".into()).await.unwrap();
+        let list_id = loop {
+            if let Ok(BackendEvent::CueListUpdated { cue_list }) = event_rx.recv().await {
+                assert_eq!(cue_list.lists.len(), 1);
+                assert_eq!(cue_list.lists[0].name, "Sound FX");
+                break cue_list.lists[0].id;
+            }
+        };
+
+        model_handle
+            .move_cues(
+                HashSet::from([third, second]),
+                InsertPosition::List {
+                    list_id,
+                    index: None,
+                },
+            )
+            .await
+            .unwrap();
+        model_handle
+            .move_cue(third, InsertPosition::Before { target: second })
+            .await
+            .unwrap();
+        loop {
+            if let Ok(BackendEvent::CueListUpdated { cue_list }) = event_rx.recv().await
+                && cue_list.root_ids.len() == 1
+                && cue_list.lists[0].root_ids == vec![third, second]
+            {
+                break;
+            }
+        }
+        assert_eq!(model_handle.get_cue_list_id(&second).await, Some(list_id));
+        assert_eq!(
+            model_handle.get_cue_list_id(&first).await,
+            Some(MAIN_CUE_LIST_ID)
+        );
+
+        let model = model_handle.read().await.clone();
+        let restored: ShowModel = ProjectShowModel::from(model.clone()).try_into().unwrap();
+        assert_eq!(restored, model);
+
+        model_handle
+            .remove_cue_list(MAIN_CUE_LIST_ID)
+            .await
+            .unwrap();
+        model_handle.remove_cue_list(list_id).await.unwrap();
+        loop {
+            if let Ok(BackendEvent::CueRemoved { cue_ids, .. }) = event_rx.recv().await {
+                assert_eq!(cue_ids, HashSet::from([second, third]));
+                break;
+            }
+        }
+        let model = model_handle.read().await;
+        assert!(model.cue_list.lists.is_empty());
+        assert_eq!(model.cue_list.root_ids, vec![first]);
+    }
+
+    #[tokio::test]
+    async fn cart_slots_follow_removed_cues() {
+        let (model, [first, second, _]) = referenced_show(ReferencePolicy::Clear);
+        let (model_handle, mut event_rx) = setup_manager(Some(model), ProjectStatus::Unsaved).await;
+
+        for (index, cue_id) in [(0, first), (2, second)] {
+            model_handle
+                .set_cart_slot(
+                    index,
+                    Some(CartSlot {
+                        cue_id,
+                        mode: CartSlotMode::Latch,
+                    }),
+                )
+                .await
+                .unwrap();
+        }
+        model_handle
+            .set_cart_slot(MAX_CART_SLOTS, None)
+            .await
+            .unwrap();
+        loop {
+            match event_rx.recv().await {
+                Ok(BackendEvent::OperationFailed {
+                    error: BackendError::CueEdit { message },
+                }) => {
+                    assert!(message.contains("out of range"));
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => panic!("{}", e),
+            }
+        }
+        assert_eq!(model_handle.read().await.cart.len(), 3);
+
+        model_handle.remove_cue(second).await.unwrap();
+        loop {
+            if let Ok(BackendEvent::CartUpdated { cart }) = event_rx.recv().await
+                && cart.len() == 1
+            {
+                assert_eq!(cart[0].map(|slot| slot.cue_id), Some(first));
+                break;
+            }
+        }
+        assert_eq!(model_handle.get_cart_slot(2).await, None);
+    }
+
+    #[tokio::test]
+    async fn undo_redo_model_name() {
+        let (model_handle, mut event_rx) = setup_manager(None, ProjectStatus::Unsaved).await;
+        let original_name = model_handle.read().await.name.clone();
+
+        model_handle
+            .update_model_name("renamed".into())
+            .await
+            .unwrap();
+        loop {
+            if let Ok(BackendEvent::HistoryUpdated { status }) = event_rx.recv().await {
+                assert!(status.can_undo);
+                assert!(!status.can_redo);
+                break;
+            }
+        }
+
+        model_handle.undo().await.unwrap();
+        loop {
+            if let Ok(BackendEvent::ModelNameUpdated { new_name }) = event_rx.recv().await {
+                assert_eq!(new_name, original_name);
+                break;
+            }
+        }
+        loop {
+            if let Ok(BackendEvent::HistoryUpdated { status }) = event_rx.recv().await {
+                assert!(!status.can_undo);
+                assert!(status.can_redo);
+                break;
+            }
+        }
+
+        model_handle.redo().await.unwrap();
+        loop {
+            if let Ok(BackendEvent::ModelNameUpdated { new_name }) = event_rx.recv().await {
+                assert_eq!(new_name, "renamed");
+                break;
+            }
+        }
+        assert_eq!(model_handle.read().await.name, "renamed");
+        assert!(model_handle.get_history_status().await.can_undo);
+    }
+
+    #[tokio::test]
+    async fn backup_file_prunes_old_backups() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("show.sbsp");
+        std::fs::write(&path, "{}").unwrap();
+        for old in [
+            "show.sbsp.20000101-000000.000.bak",
+            "show.sbsp.20000102-000000.000.bak",
+            // not written by backup_file, so never pruned
+            "show.sbsp.manual.bak",
+            "show.sbsp.before-premiere.20000101-000000.000.bak",
+        ] {
+            std::fs::write(temp_dir.path().join(old), "{}").unwrap();
+        }
+
+        ShowModelManager::backup_file(&path, 3).await.unwrap();
+        ShowModelManager::backup_file(&path, 3).await.unwrap();
+
+        let mut backups: Vec<String> = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.ends_with(".bak"))
+            .collect();
+        backups.sort();
+        // both new backups survive even when written within the same millisecond
+        assert_eq!(backups.len(), 5);
+        assert_eq!(backups[0], "show.sbsp.20000102-000000.000.bak");
+        assert!(backups.contains(&"show.sbsp.manual.bak".to_string()));
+        assert!(backups.contains(&"show.sbsp.before-premiere.20000101-000000.000.bak".to_string()));
+    }
+
+    #[tokio::test]
+    async fn recover_from_recovery_file() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("show.sbsp");
+        let recovered = ShowModel {
+            name: "recovered".into(),
+            ..Default::default()
+        };
+        ShowModelManager::write_project_file(
+            &ShowModelManager::recovery_path_for(&path),
+            ProjectFile {
+                version: PROJECT_FILE_VERSION,
+                project_type: ProjectType::SingleFile,
+                model: recovered.clone().into(),
+            },
+        )
+        .await
+        .unwrap();
+        let (model_handle, mut event_rx) = setup_manager(
+            None,
+            ProjectStatus::Saved {
+                project_type: ProjectType::SingleFile,
+                path: path.clone(),
+            },
+        )
+        .await;
+
+        model_handle.check_recovery().await.unwrap();
+        loop {
+            if let Ok(BackendEvent::RecoveryAvailable { project_path, .. }) = event_rx.recv().await
+            {
+                assert_eq!(project_path, Some(path.clone()));
+                break;
+            }
+        }
+
+        model_handle.recover().await.unwrap();
+        loop {
+            if let Ok(BackendEvent::ShowModelLoaded { model, .. }) = event_rx.recv().await {
+                assert_eq!(model, recovered);
+                break;
+            }
+        }
+        assert!(model_handle.is_modified());
+    }
+
+    #[tokio::test]
+    async fn export_and_open_bundle() {
+        let temp_dir = tempdir().unwrap();
+        let asset = temp_dir.path().join("media/intro.wav");
+        std::fs::create_dir_all(asset.parent().unwrap()).unwrap();
+        std::fs::write(&asset, b"audio").unwrap();
+        let cue_id = Uuid::new_v4();
+        let (model_handle, mut event_rx) = setup_manager(
+            Some(ShowModel {
+                name: "bundled".into(),
+                cue_list: CueList {
+                    cues: HashMap::from([(
+                        cue_id,
+                        Cue {
+                            id: cue_id,
+                            number: "1".into(),
+                            name: None,
+                            notes: String::new(),
+                            color: CueColor::None,
+                            armed: true,
+                            pre_wait: 0.0,
+                            timeline_offset: 0.0,
+                            chain: CueChain::DoNotChain,
+                            parent_id: None,
+                            params: CueParam::Audio(AudioCueParam {
+                                target: asset.clone(),
+                                start_time: None,
+                                fade_in_param: None,
+                                end_time: None,
+                                fade_out_param: None,
+                                volume: Decibels::IDENTITY,
+                                pan: 0.0,
+                                repeat: false,
+                                sound_type: SoundType::Streaming,
+                                envelope: Vec::new(),
+                                original_path: None,
+                            }),
+                        },
+                    )]),
+                    root_ids: vec![cue_id],
+                    lists: Vec::new(),
+                },
+                settings: ShowSettings::default(),
+                cart: Vec::new(),
+            }),
+            ProjectStatus::Unsaved,
+        )
+        .await;
+
+        let bundle_path = temp_dir.path().join("show.sbspz");
+        model_handle
+            .export_bundle(bundle_path.clone())
+            .await
+            .unwrap();
+        loop {
+            if let Ok(BackendEvent::BundleExported { path }) = event_rx.recv().await {
+                assert_eq!(path, bundle_path);
+                break;
+            }
+        }
+
+        model_handle.open_bundle(bundle_path, None).await.unwrap();
+        loop {
+            if let Ok(BackendEvent::ShowModelLoaded { model, path, .. }) = event_rx.recv().await {
+                assert_eq!(path, temp_dir.path().join("show/model.sbsp"));
+                let CueParam::Audio(param) = &model.cue_list.cues[&cue_id].params else {
+                    panic!("expected an audio cue");
+                };
+                assert_eq!(param.target, std::path::Path::new("assets/intro.wav"));
+                break;
+            }
+        }
+        let asset_path = model_handle
+            .get_asset_standard_path(&"assets/intro.wav".into())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(asset_path).unwrap(), b"audio");
+    }
 }
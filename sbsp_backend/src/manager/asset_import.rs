@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::path::PathBuf;
+#[cfg(feature = "backend")]
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::BufReader,
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use crate::model::{ShowModel, cue::CueParam};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum AssetImportOutcome {
+    /// Copied under the source file name.
+    Copied,
+    /// Copied under a numbered name because a different file already had the name.
+    Renamed,
+    /// An identical file was already in the project and is used instead.
+    Reused,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct AssetImportEntry {
+    pub source: PathBuf,
+    pub target: PathBuf,
+    pub outcome: AssetImportOutcome,
+}
+
+/// An audio cue whose target file cannot be found.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct MissingAsset {
+    pub cue_id: Uuid,
+    pub target: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct AssetRelink {
+    pub cue_id: Uuid,
+    pub old_target: PathBuf,
+    pub new_target: PathBuf,
+}
+
+/// Lists audio cues whose target does not exist. Relative targets are
+/// resolved against `model_dir`, the folder holding the project file.
+#[cfg(feature = "backend")]
+pub fn find_missing_assets(model: &ShowModel, model_dir: Option<&Path>) -> Vec<MissingAsset> {
+    let mut missing: Vec<_> = model
+        .cue_list
+        .cues
+        .values()
+        .filter_map(|cue| match &cue.params {
+            CueParam::Audio(param) => {
+                let path = match model_dir {
+                    Some(model_dir) => model_dir.join(&param.target),
+                    None => param.target.clone(),
+                };
+                (!path.is_file()).then(|| MissingAsset {
+                    cue_id: cue.id,
+                    target: param.target.clone(),
+                })
+            }
+            _ => None,
+        })
+        .collect();
+    missing.sort_by(|a, b| a.target.cmp(&b.target).then(a.cue_id.cmp(&b.cue_id)));
+    missing
+}
+
+/// Searches `folder` recursively for files named like the missing targets.
+/// When several files share the name they must have identical content,
+/// otherwise the match is ambiguous and the cue is left alone. Matches
+/// inside `model_dir` become relative targets.
+#[cfg(feature = "backend")]
+pub fn find_relinks(
+    missing: &[MissingAsset],
+    folder: &Path,
+    model_dir: Option<&Path>,
+) -> anyhow::Result<Vec<AssetRelink>> {
+    if !folder.is_dir() {
+        anyhow::bail!("{} is not a folder.", folder.display());
+    }
+    let mut files: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    let mut folders = vec![folder.to_path_buf()];
+    while let Some(current) = folders.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            log::warn!("Failed to read folder {}", current.display());
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                folders.push(entry.path());
+            } else if let Some(name) = entry.file_name().to_str() {
+                files
+                    .entry(name.to_lowercase())
+                    .or_default()
+                    .push(entry.path());
+            }
+        }
+    }
+
+    let mut hashes = HashMap::new();
+    let mut resolved: HashMap<String, Option<PathBuf>> = HashMap::new();
+    let mut relinks = Vec::new();
+    for asset in missing {
+        let Some(name) = asset.target.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let name = name.to_lowercase();
+        if !resolved.contains_key(&name) {
+            let found = match files.get_mut(&name) {
+                Some(candidates) if candidates.len() == 1 => candidates.first().cloned(),
+                Some(candidates) => {
+                    candidates.sort();
+                    let mut candidate_hashes = HashSet::new();
+                    for candidate in candidates.iter() {
+                        if !hashes.contains_key(candidate) {
+                            hashes.insert(candidate.clone(), hash_file(candidate)?);
+                        }
+                        candidate_hashes.insert(hashes[candidate].clone());
+                    }
+                    if candidate_hashes.len() == 1 {
+                        candidates.first().cloned()
+                    } else {
+                        log::warn!("Multiple different files named {} were found.", name);
+                        None
+                    }
+                }
+                None => None,
+            };
+            resolved.insert(name.clone(), found);
+        }
+        if let Some(Some(found)) = resolved.get(&name) {
+            let new_target = model_dir
+                .and_then(|model_dir| found.strip_prefix(model_dir).ok())
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| found.clone());
+            relinks.push(AssetRelink {
+                cue_id: asset.cue_id,
+                old_target: asset.target.clone(),
+                new_target,
+            });
+        }
+    }
+    Ok(relinks)
+}
+
+/// Brings `asset_path` into `import_destination` of the project at
+/// `model_path` and returns the project relative target. Files sharing a name
+/// are compared by content: identical ones are reused, different ones are
+/// copied as `name (2).ext` and so on. The outcome is `None` when the asset
+/// already lives in the project and nothing had to be done.
+#[cfg(feature = "backend")]
+pub async fn import_asset_file(
+    asset_path: &Path,
+    model_path: &Path,
+    import_destination: &str,
+) -> anyhow::Result<(PathBuf, Option<AssetImportOutcome>)> {
+    if asset_path.is_relative() {
+        return Ok((asset_path.to_path_buf(), None));
+    }
+    log::info!("Import asset file started. file={:?}", asset_path);
+    let audio_dir = model_path.join(import_destination);
+    if !audio_dir.exists() {
+        tokio::fs::create_dir_all(&audio_dir).await?;
+    } else if audio_dir.is_file() {
+        anyhow::bail!("Failed to copy asset to destination. destination is not directory");
+    }
+
+    let Some(file_name) = asset_path.file_name().and_then(|name| name.to_str()) else {
+        anyhow::bail!("Invalid asset file name.");
+    };
+    let stem = Path::new(file_name)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(file_name);
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| format!(".{}", extension))
+        .unwrap_or_default();
+
+    let source = tokio::fs::canonicalize(asset_path).await?;
+    let mut source_hash = None;
+    let mut count = 1;
+    loop {
+        let candidate_name = if count == 1 {
+            file_name.to_string()
+        } else {
+            format!("{} ({}){}", stem, count, extension)
+        };
+        let dest_path = audio_dir.join(&candidate_name);
+        let target: PathBuf = [import_destination, candidate_name.as_str()]
+            .iter()
+            .collect();
+
+        if !dest_path.exists() {
+            tokio::fs::copy(&source, &dest_path).await?;
+            let outcome = if count == 1 {
+                AssetImportOutcome::Copied
+            } else {
+                AssetImportOutcome::Renamed
+            };
+            return Ok((target, Some(outcome)));
+        }
+        if tokio::fs::canonicalize(&dest_path).await? == source {
+            return Ok((target, None));
+        }
+        if source_hash.is_none() {
+            source_hash = Some(file_hash(source.clone()).await?);
+        }
+        if source_hash == Some(file_hash(dest_path).await?) {
+            return Ok((target, Some(AssetImportOutcome::Reused)));
+        }
+        count += 1;
+    }
+}
+
+#[cfg(feature = "backend")]
+async fn file_hash(path: PathBuf) -> anyhow::Result<String> {
+    Ok(tokio::task::spawn_blocking(move || hash_file(&path)).await??)
+}
+
+/// Hex SHA-256 of a file's content.
+#[cfg(feature = "backend")]
+fn hash_file(path: &Path) -> std::io::Result<String> {
+    let mut reader = BufReader::new(File::open(path)?);
+    super::bundle::copy_hashed(&mut reader, &mut std::io::sink())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use uuid::Uuid;
+
+    use super::{MissingAsset, find_relinks};
+
+    #[test]
+    fn relink_by_name_and_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in [
+            ("show/audio/intro.wav", "intro"),
+            ("backup/intro.wav", "intro"),
+            ("show/a/outro.wav", "outro"),
+            ("show/b/outro.wav", "outro, remastered"),
+        ] {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let missing: Vec<_> = ["/old/machine/intro.wav", "/old/machine/outro.wav"]
+            .into_iter()
+            .map(|target| MissingAsset {
+                cue_id: Uuid::new_v4(),
+                target: PathBuf::from(target),
+            })
+            .collect();
+
+        let model_dir = dir.path().join("show");
+        let relinks = find_relinks(&missing, dir.path(), Some(&model_dir)).unwrap();
+
+        // identical copies resolve to the first path; differing outros are ambiguous
+        assert_eq!(relinks.len(), 1);
+        assert_eq!(relinks[0].cue_id, missing[0].cue_id);
+        assert_eq!(relinks[0].new_target, dir.path().join("backup/intro.wav"));
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use super::DEFAULT_PROJECT_FOLDER_MODEL_FILENAME as PROJECT_NAME;
+use crate::manager::project::ProjectFile;
+
+pub const BUNDLE_EXTENSION: &str = "sbspz";
+pub const BUNDLE_ASSET_FOLDER: &str = "assets";
+const BUNDLE_VERSION: u32 = 1;
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// Index of a bundle. `files` maps every archive entry except the manifest
+/// itself to the hex SHA-256 of its content.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct BundleManifest {
+    version: u32,
+    project: String,
+    files: BTreeMap<String, String>,
+}
+
+/// Assigns each asset file an entry name under `assets/`. File names are
+/// kept where possible and numbered when two sources share a name.
+pub fn asset_entries<'a>(
+    sources: impl IntoIterator<Item = &'a PathBuf>,
+) -> HashMap<PathBuf, String> {
+    let mut entries = HashMap::new();
+    let mut used_names = HashSet::new();
+    for source in sources {
+        if entries.contains_key(source) {
+            continue;
+        }
+        let stem = source
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "asset".into());
+        let extension = source
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_default();
+        let mut name = format!("{}{}", stem, extension);
+        let mut count = 1;
+        while !used_names.insert(name.to_lowercase()) {
+            count += 1;
+            name = format!("{} ({}){}", stem, count, extension);
+        }
+        entries.insert(source.clone(), format!("{}/{}", BUNDLE_ASSET_FOLDER, name));
+    }
+    entries
+}
+
+/// Writes a bundle holding `project_file` and the given assets, keyed by
+/// their source path. The archive is stored uncompressed, since audio
+/// rarely shrinks, and replaces `path` only once complete.
+pub fn write_bundle(
+    path: &Path,
+    project_file: &ProjectFile,
+    assets: &HashMap<PathBuf, String>,
+) -> anyhow::Result<()> {
+    let mut temp_file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid file path."))?
+        .to_os_string();
+    temp_file_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_file_name);
+
+    let write = || -> anyhow::Result<()> {
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(true);
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(&temp_path)?));
+        let mut files = BTreeMap::new();
+
+        let project = serde_json::to_vec_pretty(project_file)?;
+        zip.start_file(PROJECT_NAME, options)?;
+        files.insert(
+            PROJECT_NAME.to_string(),
+            copy_hashed(&mut &project[..], &mut zip)?,
+        );
+
+        let mut assets: Vec<_> = assets.iter().collect();
+        assets.sort_by(|a, b| a.1.cmp(b.1));
+        for (source, entry) in assets {
+            let mut reader = BufReader::new(
+                File::open(source)
+                    .with_context(|| format!("Failed to open asset {}", source.display()))?,
+            );
+            zip.start_file(entry.as_str(), options)?;
+            files.insert(entry.clone(), copy_hashed(&mut reader, &mut zip)?);
+        }
+
+        let manifest = BundleManifest {
+            version: BUNDLE_VERSION,
+            project: PROJECT_NAME.to_string(),
+            files,
+        };
+        zip.start_file(MANIFEST_NAME, options)?;
+        serde_json::to_writer_pretty(&mut zip, &manifest)?;
+
+        let file = zip.finish()?.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    };
+    let result = write();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Extracts a bundle into `destination`, verifying every checksum, and
+/// returns the path of the extracted project file. A destination folder
+/// created here is removed again if extraction fails.
+pub fn extract_bundle(path: &Path, destination: &Path) -> anyhow::Result<PathBuf> {
+    let created = !destination.exists();
+    let result = extract(path, destination);
+    if result.is_err() && created {
+        let _ = std::fs::remove_dir_all(destination);
+    }
+    result
+}
+
+fn extract(path: &Path, destination: &Path) -> anyhow::Result<PathBuf> {
+    let mut archive = ZipArchive::new(BufReader::new(File::open(path)?))?;
+    let manifest: BundleManifest = serde_json::from_reader(
+        archive
+            .by_name(MANIFEST_NAME)
+            .context("Bundle has no manifest")?,
+    )?;
+    if manifest.version > BUNDLE_VERSION {
+        bail!(
+            "Bundle version {} is newer than the supported version {}. Please update the application.",
+            manifest.version,
+            BUNDLE_VERSION
+        );
+    }
+    if !manifest.files.contains_key(&manifest.project) {
+        bail!("Bundle manifest does not list the project file.");
+    }
+
+    std::fs::create_dir_all(destination)?;
+    for (name, checksum) in &manifest.files {
+        let mut entry = archive
+            .by_name(name)
+            .with_context(|| format!("Bundle is missing {}", name))?;
+        let Some(relative_path) = entry.enclosed_name() else {
+            bail!("Bundle entry {} has an unsafe path.", name);
+        };
+        let output_path = destination.join(relative_path);
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut output = BufWriter::new(File::create(&output_path)?);
+        let actual = copy_hashed(&mut entry, &mut output)?;
+        output.flush()?;
+        if !actual.eq_ignore_ascii_case(checksum) {
+            bail!("Checksum mismatch for {}. The bundle is corrupted.", name);
+        }
+    }
+    Ok(destination.join(&manifest.project))
+}
+
+/// Picks a folder next to the bundle, named after it, that does not exist yet.
+pub fn working_folder_for(path: &Path) -> PathBuf {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "bundle".into());
+    let mut folder = parent.join(&stem);
+    let mut count = 1;
+    while folder.exists() {
+        count += 1;
+        folder = parent.join(format!("{} ({})", stem, count));
+    }
+    folder
+}
+
+pub(super) fn copy_hashed(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        writer.write_all(&buffer[..read])?;
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use crate::manager::project::ProjectFile;
+
+    use super::{asset_entries, extract_bundle, write_bundle};
+
+    #[test]
+    fn bundle_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("a/kick.wav");
+        let second = dir.path().join("b/kick.wav");
+        std::fs::create_dir_all(first.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(second.parent().unwrap()).unwrap();
+        std::fs::write(&first, b"first").unwrap();
+        std::fs::write(&second, b"second").unwrap();
+
+        let entries = asset_entries([&first, &second]);
+        let mut names: Vec<_> = entries.values().cloned().collect();
+        names.sort();
+        assert_eq!(names, vec!["assets/kick (2).wav", "assets/kick.wav"]);
+
+        let bundle_path = dir.path().join("show.sbspz");
+        write_bundle(&bundle_path, &ProjectFile::default(), &entries).unwrap();
+        let working_folder = dir.path().join("extracted");
+        let project_path = extract_bundle(&bundle_path, &working_folder).unwrap();
+
+        assert!(project_path.is_file());
+        let extracted = std::fs::read(working_folder.join(&entries[&second])).unwrap();
+        assert_eq!(extracted, b"second");
+    }
+
+    #[test]
+    fn reject_corrupted_bundle() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset = dir.path().join("cue.wav");
+        std::fs::write(&asset, b"original audio content").unwrap();
+        let bundle_path = dir.path().join("show.sbspz");
+        let entries: HashMap<PathBuf, String> = asset_entries([&asset]);
+        write_bundle(&bundle_path, &ProjectFile::default(), &entries).unwrap();
+
+        // entries are stored uncompressed, so the content can be patched in place
+        let mut bytes = std::fs::read(&bundle_path).unwrap();
+        let offset = bytes
+            .windows(8)
+            .position(|window| window == b"original")
+            .unwrap();
+        bytes[offset..offset + 8].copy_from_slice(b"tampered");
+        std::fs::write(&bundle_path, bytes).unwrap();
+
+        let working_folder = dir.path().join("extracted");
+        assert!(extract_bundle(&bundle_path, &working_folder).is_err());
+        assert!(!working_folder.exists());
+    }
+}
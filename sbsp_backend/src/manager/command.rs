@@ -6,7 +6,7 @@ use std::{collections::HashSet, path::PathBuf};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::model::{cue::Cue, settings::ShowSettings};
+use crate::model::{cart::CartSlot, cue::Cue, settings::ShowSettings};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
@@ -26,6 +26,11 @@ pub enum InsertPosition {
         target: Option<Uuid>,
         index: Option<usize>,
     },
+    /// Top level of a cue list. `MAIN_CUE_LIST_ID` addresses the main list.
+    List {
+        list_id: Uuid,
+        index: Option<usize>,
+    },
 }
 
 impl InsertPosition {
@@ -35,6 +40,15 @@ impl InsertPosition {
     };
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum CueSheetFormat {
+    Csv,
+    Markdown,
+    Html,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(
@@ -68,6 +82,24 @@ pub enum ModelCommand {
         position: InsertPosition,
     },
 
+    AddCueList {
+        name: String,
+    },
+    RenameCueList {
+        list_id: Uuid,
+        name: String,
+    },
+    /// Removes a cue list other than the main one together with its cues.
+    RemoveCueList {
+        list_id: Uuid,
+    },
+
+    /// Assigns a cart slot, or empties it with `None`.
+    SetCartSlot {
+        index: usize,
+        slot: Option<CartSlot>,
+    },
+
     RenumberCues {
         cues: Vec<Uuid>,
         start_from: usize,
@@ -76,12 +108,37 @@ pub enum ModelCommand {
         suffix: Option<String>,
     },
 
+    ImportCueSheet {
+        path: PathBuf,
+        position: InsertPosition,
+    },
+    RelinkAssets {
+        folder: PathBuf,
+    },
+
     UpdateModelName(String),
     UpdateSettings(Box<ShowSettings>),
 
+    Undo,
+    Redo,
+
+    CheckRecovery,
+    Recover,
+    DiscardRecovery,
+
     Reset,
     Save,
     SaveToFile(PathBuf),
     ExportToFolder(PathBuf),
+    ExportCueSheet {
+        path: PathBuf,
+        format: CueSheetFormat,
+    },
     LoadFromFile(PathBuf),
+    ExportBundle(PathBuf),
+    /// Extracts a bundle into `destination`, or a new folder next to it, and opens it.
+    OpenBundle {
+        path: PathBuf,
+        destination: Option<PathBuf>,
+    },
 }
@@ -15,10 +15,17 @@ use tokio::sync::{RwLock, mpsc};
 use uuid::Uuid;
 
 use crate::{
-    manager::{ModelCommand, command::InsertPosition, project::ProjectStatus},
+    controller::CueRef,
+    manager::{
+        ModelCommand,
+        command::{CueSheetFormat, InsertPosition},
+        history::HistoryStatus,
+        project::ProjectStatus,
+    },
     model::{
         ShowModel,
-        cue::{Cue, CueChain, CueParam, group::GroupMode},
+        cart::CartSlot,
+        cue::{Cue, CueChain, CueParam, audio::Decibels, group::GroupMode},
         settings::ShowSettings,
     },
 };
@@ -29,6 +36,7 @@ pub struct ShowModelHandle {
     command_tx: mpsc::Sender<ModelCommand>,
     project_status: Arc<RwLock<ProjectStatus>>,
     modify_status: Arc<AtomicBool>,
+    history_status: Arc<RwLock<HistoryStatus>>,
 }
 
 impl ShowModelHandle {
@@ -37,12 +45,14 @@ impl ShowModelHandle {
         command_tx: mpsc::Sender<ModelCommand>,
         project_status: Arc<RwLock<ProjectStatus>>,
         modify_status: Arc<AtomicBool>,
+        history_status: Arc<RwLock<HistoryStatus>>,
     ) -> Self {
         Self {
             model,
             command_tx,
             project_status,
             modify_status,
+            history_status,
         }
     }
 
@@ -68,6 +78,29 @@ impl ShowModelHandle {
         Ok(())
     }
 
+    pub async fn add_cue_list(&self, name: String) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::AddCueList { name }).await?;
+        Ok(())
+    }
+
+    pub async fn rename_cue_list(&self, list_id: Uuid, name: String) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::RenameCueList { list_id, name })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_cue_list(&self, list_id: Uuid) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::RemoveCueList { list_id })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_cart_slot(&self, index: usize, slot: Option<CartSlot>) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::SetCartSlot { index, slot })
+            .await?;
+        Ok(())
+    }
+
     pub async fn remove_cue(&self, cue_id: Uuid) -> anyhow::Result<()> {
         self.send_command(ModelCommand::RemoveCue { cue_id })
             .await?;
@@ -115,6 +148,16 @@ impl ShowModelHandle {
         Ok(())
     }
 
+    pub async fn undo(&self) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::Undo).await?;
+        Ok(())
+    }
+
+    pub async fn redo(&self) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::Redo).await?;
+        Ok(())
+    }
+
     pub async fn update_model_name(&self, new_name: String) -> anyhow::Result<()> {
         self.send_command(ModelCommand::UpdateModelName(new_name))
             .await?;
@@ -148,11 +191,67 @@ impl ShowModelHandle {
         Ok(())
     }
 
+    pub async fn export_cue_sheet(
+        &self,
+        path: PathBuf,
+        format: CueSheetFormat,
+    ) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::ExportCueSheet { path, format })
+            .await?;
+        Ok(())
+    }
+
     pub async fn load_from_file(&self, path: PathBuf) -> anyhow::Result<()> {
         self.send_command(ModelCommand::LoadFromFile(path)).await?;
         Ok(())
     }
 
+    pub async fn import_cue_sheet(
+        &self,
+        path: PathBuf,
+        position: InsertPosition,
+    ) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::ImportCueSheet { path, position })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn relink_assets(&self, folder: PathBuf) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::RelinkAssets { folder })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn export_bundle(&self, path: PathBuf) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::ExportBundle(path)).await?;
+        Ok(())
+    }
+
+    pub async fn open_bundle(
+        &self,
+        path: PathBuf,
+        destination: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::OpenBundle { path, destination })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn check_recovery(&self) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::CheckRecovery).await?;
+        Ok(())
+    }
+
+    pub async fn recover(&self) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::Recover).await?;
+        Ok(())
+    }
+
+    pub async fn discard_recovery(&self) -> anyhow::Result<()> {
+        self.send_command(ModelCommand::DiscardRecovery).await?;
+        Ok(())
+    }
+
     pub async fn is_cue_exists(&self, cue_id: &Uuid) -> bool {
         let model = self.read().await;
         model.cue_list.cues.contains_key(cue_id)
@@ -195,38 +294,60 @@ impl ShowModelHandle {
     }
 
     pub async fn get_next_cue_id_by_id(&self, cue_id: &Uuid) -> Option<Uuid> {
-        let model = self.model.read().await;
-        let mut current_id = *cue_id;
+        self.model.read().await.cue_list.next_cue_id(cue_id)
+    }
 
-        loop {
-            let cue = model.cue_list.cues.get(&current_id)?;
+    /// Cue the playback cursor steps back to from `cue_id`, undoing a GO advance.
+    pub async fn get_previous_cue_id_by_id(&self, cue_id: &Uuid) -> Option<Uuid> {
+        self.model.read().await.cue_list.previous_cue_id(cue_id)
+    }
 
-            if let Some(parent_id) = cue.parent_id {
-                let parent = model.cue_list.cues.get(&parent_id)?;
-                if let CueParam::Group { children, .. } = &parent.params
-                    && let Some(idx) = children.iter().position(|id| *id == current_id)
-                {
-                    if let Some(next_id) = children.get(idx + 1) {
-                        return Some(*next_id);
-                    }
+    pub async fn get_group_trim(&self, cue_id: &Uuid) -> Decibels {
+        self.model.read().await.cue_list.group_trim_of(cue_id)
+    }
 
-                    current_id = parent_id;
-                    continue;
-                }
+    pub async fn get_last_cue_id(&self, list_id: &Uuid) -> Option<Uuid> {
+        self.model.read().await.cue_list.last_cue_id(list_id)
+    }
 
-                return None;
-            }
+    pub async fn get_cart_slot(&self, index: usize) -> Option<CartSlot> {
+        self.model.read().await.cart.get(index).copied().flatten()
+    }
 
-            if let Some(idx) = model
-                .cue_list
-                .root_ids
-                .iter()
-                .position(|id| *id == current_id)
-            {
-                return model.cue_list.root_ids.get(idx + 1).copied();
+    pub async fn get_cue_list_id(&self, cue_id: &Uuid) -> Option<Uuid> {
+        self.model.read().await.cue_list.list_id_of(cue_id)
+    }
+
+    pub async fn resolve_cue_ref(&self, cue_ref: &CueRef) -> anyhow::Result<Uuid> {
+        match cue_ref {
+            CueRef::Id(cue_id) => Ok(*cue_id),
+            CueRef::Number(number) => {
+                let model = self.read().await;
+                let mut matched = model
+                    .cue_list
+                    .cues
+                    .values()
+                    .filter(|cue| cue.number == *number);
+                match (matched.next(), matched.next()) {
+                    (Some(cue), None) => Ok(cue.id),
+                    (None, _) => anyhow::bail!("cue number '{}' not found.", number),
+                    (Some(_), Some(_)) => anyhow::bail!(
+                        "cue number '{}' is ambiguous. {} cues share it.",
+                        number,
+                        2 + matched.count()
+                    ),
+                }
             }
+        }
+    }
 
-            return None;
+    pub async fn get_armed_cue_id_from(&self, cue_id: &Uuid) -> Option<Uuid> {
+        let mut current_id = *cue_id;
+        loop {
+            if self.read().await.cue_list.cues.get(&current_id)?.armed {
+                return Some(current_id);
+            }
+            current_id = self.get_next_cue_id_by_id(&current_id).await?;
         }
     }
 
@@ -246,15 +367,21 @@ impl ShowModelHandle {
                                 if repeat {
                                     return Some(CueChain::AfterComplete {
                                         target_id: Some(*first_id),
+                                        post_wait: 0.0,
                                     });
                                 } else {
                                     return Some(CueChain::DoNotChain);
                                 }
                             } else {
-                                return Some(CueChain::AfterComplete { target_id: None });
+                                return Some(CueChain::AfterComplete {
+                                    target_id: None,
+                                    post_wait: 0.0,
+                                });
                             }
                         }
-                        GroupMode::Concurrency | GroupMode::StartFirst { .. } => {
+                        GroupMode::Concurrency
+                        | GroupMode::StartFirst { .. }
+                        | GroupMode::Timeline => {
                             return Some(cue.chain);
                         }
                     }
@@ -332,6 +459,10 @@ impl ShowModelHandle {
         self.project_status.read().await
     }
 
+    pub async fn get_history_status(&self) -> HistoryStatus {
+        *self.history_status.read().await
+    }
+
     pub fn is_modified(&self) -> bool {
         self.modify_status.load(Ordering::Acquire)
     }
@@ -348,11 +479,13 @@ mod tests {
     use tokio::sync::{RwLock, mpsc};
 
     use crate::{
-        manager::{ShowModelHandle, project::ProjectStatus},
+        controller::CueRef,
+        manager::{ShowModelHandle, history::HistoryStatus, project::ProjectStatus},
         model::{
             ShowModel,
             cue::{
                 Cue, CueChain, CueColor, CueParam, WaitCueParam,
+                audio::Decibels,
                 group::{GroupCueParamBase, GroupMode},
             },
         },
@@ -367,7 +500,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -378,7 +513,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -396,6 +533,7 @@ mod tests {
             command_tx,
             project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
             modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
         };
 
         assert_eq!(
@@ -416,7 +554,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: Some(group_id),
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -428,7 +568,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: Some(group_id),
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -442,12 +584,15 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Group {
                 base: GroupCueParamBase {
                     mode: GroupMode::Playlist { repeat: false },
+                    volume: Decibels::IDENTITY,
                 },
                 children: vec![current_id, next_id],
             },
@@ -464,6 +609,7 @@ mod tests {
             command_tx,
             project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
             modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
         };
 
         assert_eq!(handle.get_next_cue_id_by_id(&group_id).await, None);
@@ -485,7 +631,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: Some(group_id),
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -497,7 +645,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -511,12 +661,15 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Group {
                 base: GroupCueParamBase {
                     mode: GroupMode::Playlist { repeat: false },
+                    volume: Decibels::IDENTITY,
                 },
                 children: vec![current_id],
             },
@@ -534,6 +687,7 @@ mod tests {
             command_tx,
             project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
             modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
         };
 
         assert_eq!(handle.get_next_cue_id_by_id(&group_id).await, Some(next_id));
@@ -556,7 +710,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: Some(group1_id),
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -568,7 +724,9 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -582,12 +740,15 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: Some(group2_id),
             params: CueParam::Group {
                 base: GroupCueParamBase {
                     mode: GroupMode::Playlist { repeat: false },
+                    volume: Decibels::IDENTITY,
                 },
                 children: vec![current_id],
             },
@@ -599,12 +760,15 @@ mod tests {
             name: None,
             notes: "".into(),
             color: CueColor::None,
+            armed: true,
             pre_wait: 0.0,
+            timeline_offset: 0.0,
             chain: CueChain::DoNotChain,
             parent_id: None,
             params: CueParam::Group {
                 base: GroupCueParamBase {
                     mode: GroupMode::Playlist { repeat: false },
+                    volume: Decibels::IDENTITY,
                 },
                 children: vec![group1_id],
             },
@@ -623,6 +787,7 @@ mod tests {
             command_tx,
             project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
             modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
         };
 
         assert_eq!(
@@ -639,4 +804,108 @@ mod tests {
         );
         assert_eq!(handle.get_next_cue_id_by_id(&next_id).await, None);
     }
+
+    #[tokio::test]
+    async fn get_armed_cue_skips_disarmed() {
+        let mut model = ShowModel::default();
+        let mut ids = Vec::new();
+        for armed in [false, false, true] {
+            let cue = Cue {
+                id: uuid::Uuid::new_v4(),
+                number: "".into(),
+                name: None,
+                notes: "".into(),
+                color: CueColor::None,
+                armed,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
+            };
+            ids.push(cue.id);
+            model.cue_list.root_ids.push(cue.id);
+            model.cue_list.cues.insert(cue.id, cue);
+        }
+
+        let (command_tx, _command_rx) = mpsc::channel(1);
+        let handle = ShowModelHandle {
+            model: Arc::new(RwLock::new(model)),
+            command_tx,
+            project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
+            modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
+        };
+
+        assert_eq!(handle.get_armed_cue_id_from(&ids[0]).await, Some(ids[2]));
+        assert_eq!(handle.get_armed_cue_id_from(&ids[2]).await, Some(ids[2]));
+
+        handle
+            .model
+            .write()
+            .await
+            .cue_list
+            .cues
+            .get_mut(&ids[2])
+            .unwrap()
+            .armed = false;
+        assert_eq!(handle.get_armed_cue_id_from(&ids[0]).await, None);
+    }
+
+    #[tokio::test]
+    async fn resolve_cue_number() {
+        let mut model = ShowModel::default();
+        let mut ids = Vec::new();
+        for number in ["1", "2", "2"] {
+            let cue = Cue {
+                id: uuid::Uuid::new_v4(),
+                number: number.into(),
+                name: None,
+                notes: "".into(),
+                color: CueColor::None,
+                armed: true,
+                pre_wait: 0.0,
+                timeline_offset: 0.0,
+                chain: CueChain::DoNotChain,
+                parent_id: None,
+                params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
+            };
+            ids.push(cue.id);
+            model.cue_list.root_ids.push(cue.id);
+            model.cue_list.cues.insert(cue.id, cue);
+        }
+
+        let (command_tx, _command_rx) = mpsc::channel(1);
+        let handle = ShowModelHandle {
+            model: Arc::new(RwLock::new(model)),
+            command_tx,
+            project_status: Arc::new(RwLock::new(ProjectStatus::Unsaved)),
+            modify_status: Arc::new(AtomicBool::new(false)),
+            history_status: Arc::new(RwLock::new(HistoryStatus::default())),
+        };
+
+        assert_eq!(
+            handle
+                .resolve_cue_ref(&CueRef::Number("1".into()))
+                .await
+                .unwrap(),
+            ids[0]
+        );
+        assert_eq!(
+            handle.resolve_cue_ref(&CueRef::Id(ids[1])).await.unwrap(),
+            ids[1]
+        );
+        assert!(
+            handle
+                .resolve_cue_ref(&CueRef::Number("2".into()))
+                .await
+                .is_err()
+        );
+        assert!(
+            handle
+                .resolve_cue_ref(&CueRef::Number("3".into()))
+                .await
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+#[cfg(feature = "backend")]
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "backend")]
+use crate::model::ShowModel;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryStatus {
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
+/// Bounded undo/redo stack of whole-model snapshots.
+#[cfg(feature = "backend")]
+pub struct History {
+    undo_stack: VecDeque<ShowModel>,
+    redo_stack: Vec<ShowModel>,
+    limit: usize,
+}
+
+#[cfg(feature = "backend")]
+impl History {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Records the model state from before an edit. Discards the redo branch.
+    pub fn record(&mut self, snapshot: ShowModel) {
+        self.redo_stack.clear();
+        self.undo_stack.push_back(snapshot);
+        while self.undo_stack.len() > self.limit {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    pub fn undo(&mut self, current: ShowModel) -> Option<ShowModel> {
+        let previous = self.undo_stack.pop_back()?;
+        self.redo_stack.push(current);
+        Some(previous)
+    }
+
+    pub fn redo(&mut self, current: ShowModel) -> Option<ShowModel> {
+        let next = self.redo_stack.pop()?;
+        self.undo_stack.push_back(current);
+        Some(next)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    pub fn status(&self) -> HistoryStatus {
+        HistoryStatus {
+            can_undo: !self.undo_stack.is_empty(),
+            can_redo: !self.redo_stack.is_empty(),
+        }
+    }
+}
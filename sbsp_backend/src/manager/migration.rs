@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use anyhow::bail;
+use serde_json::{Map, Value, json};
+
+use crate::manager::project::PROJECT_FILE_VERSION;
+
+type Migration = fn(&mut Value) -> anyhow::Result<()>;
+
+/// `MIGRATIONS[n]` upgrades a document from version `n + 1` to `n + 2`.
+const MIGRATIONS: [Migration; PROJECT_FILE_VERSION as usize - 1] =
+    [migrate_v1_to_v2, migrate_v2_to_v3, migrate_v3_to_v4];
+
+/// Upgrades a raw project document to `PROJECT_FILE_VERSION` in place.
+/// Documents without a version field are treated as version 1.
+pub fn migrate(document: &mut Value) -> anyhow::Result<()> {
+    let Some(root) = document.as_object_mut() else {
+        bail!("Project file root must be an object.");
+    };
+    let version = match root.get("version") {
+        None => 1,
+        Some(value) => match value.as_u64() {
+            Some(version) if version >= 1 => version,
+            _ => bail!("Invalid project file version: {}.", value),
+        },
+    };
+    if version > PROJECT_FILE_VERSION as u64 {
+        bail!(
+            "Project file version {} is newer than the supported version {}. Please update the application.",
+            version,
+            PROJECT_FILE_VERSION
+        );
+    }
+    if version < PROJECT_FILE_VERSION as u64 {
+        log::info!(
+            "Migrating project file from version {} to {}.",
+            version,
+            PROJECT_FILE_VERSION
+        );
+    }
+
+    for migration in &MIGRATIONS[version as usize - 1..] {
+        migration(document)?;
+    }
+    document["version"] = json!(PROJECT_FILE_VERSION);
+    Ok(())
+}
+
+/// Writes out the cue fields that version 1 files left to serde defaults.
+fn migrate_v1_to_v2(document: &mut Value) -> anyhow::Result<()> {
+    fn migrate_cue(cue: &mut Map<String, Value>) {
+        cue.entry("color").or_insert_with(|| json!("none"));
+        cue.entry("armed").or_insert_with(|| json!(true));
+        cue.entry("timelineOffset").or_insert_with(|| json!(0.0));
+        let chain = cue
+            .entry("chain")
+            .or_insert_with(|| json!({ "type": "doNotChain" }));
+        if let Some(chain) = chain.as_object_mut()
+            && chain.get("type").and_then(Value::as_str) != Some("doNotChain")
+        {
+            chain.entry("postWait").or_insert_with(|| json!(0.0));
+        }
+
+        let Some(params) = cue.get_mut("params").and_then(Value::as_object_mut) else {
+            return;
+        };
+        match params.get("type").and_then(Value::as_str) {
+            Some("audio") => {
+                params.entry("envelope").or_insert_with(|| json!([]));
+            }
+            Some("stop") => {
+                params.entry("hard").or_insert_with(|| json!(false));
+            }
+            Some("group") => {
+                if let Some(children) = params.get_mut("children").and_then(Value::as_array_mut) {
+                    children
+                        .iter_mut()
+                        .filter_map(Value::as_object_mut)
+                        .for_each(migrate_cue);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some(cues) = document
+        .pointer_mut("/model/cues")
+        .and_then(Value::as_array_mut)
+    else {
+        bail!("Project file has no cue list.");
+    };
+    cues.iter_mut()
+        .filter_map(Value::as_object_mut)
+        .for_each(migrate_cue);
+    Ok(())
+}
+
+/// Adds the list of additional named cue lists.
+fn migrate_v2_to_v3(document: &mut Value) -> anyhow::Result<()> {
+    let Some(model) = document.get_mut("model").and_then(Value::as_object_mut) else {
+        bail!("Project file has no model.");
+    };
+    model.entry("cueLists").or_insert_with(|| json!([]));
+    Ok(())
+}
+
+/// Adds the cart slots, the group volume trim and the imported audio path.
+fn migrate_v3_to_v4(document: &mut Value) -> anyhow::Result<()> {
+    fn migrate_cue(cue: &mut Map<String, Value>) {
+        let Some(params) = cue.get_mut("params").and_then(Value::as_object_mut) else {
+            return;
+        };
+        match params.get("type").and_then(Value::as_str) {
+            Some("audio") => {
+                params.entry("originalPath").or_insert(Value::Null);
+            }
+            Some("group") => {
+                params.entry("volume").or_insert_with(|| json!(0.0));
+                if let Some(children) = params.get_mut("children").and_then(Value::as_array_mut) {
+                    children
+                        .iter_mut()
+                        .filter_map(Value::as_object_mut)
+                        .for_each(migrate_cue);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some(model) = document.get_mut("model").and_then(Value::as_object_mut) else {
+        bail!("Project file has no model.");
+    };
+    model.entry("cart").or_insert_with(|| json!([]));
+    for_each_cue(model, migrate_cue);
+    Ok(())
+}
+
+/// Runs `migrate_cue` on the top-level cues of the main list and of every named list.
+fn for_each_cue(model: &mut Map<String, Value>, migrate_cue: fn(&mut Map<String, Value>)) {
+    if let Some(cues) = model.get_mut("cues").and_then(Value::as_array_mut) {
+        cues.iter_mut()
+            .filter_map(Value::as_object_mut)
+            .for_each(migrate_cue);
+    }
+    if let Some(lists) = model.get_mut("cueLists").and_then(Value::as_array_mut) {
+        lists
+            .iter_mut()
+            .filter_map(|list| list.get_mut("cues").and_then(Value::as_array_mut))
+            .flatten()
+            .filter_map(Value::as_object_mut)
+            .for_each(migrate_cue);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::manager::project::{PROJECT_FILE_VERSION, ProjectFile};
+
+    use super::migrate;
+
+    #[test]
+    fn migrate_unversioned_file() {
+        let mut document = json!({
+            "project_type": "singleFile",
+            "model": {
+                "name": "legacy",
+                "cues": [{
+                    "id": "8f0f4a8e-63a5-4c8e-9a43-1c2d3e4f5a6b",
+                    "number": "1",
+                    "name": null,
+                    "notes": "",
+                    "preWait": 0.0,
+                    "params": {
+                        "type": "group",
+                        "mode": { "type": "concurrency" },
+                        "children": [{
+                            "id": "0c1d2e3f-4a5b-4c6d-8e7f-8091a2b3c4d5",
+                            "number": "2",
+                            "name": null,
+                            "notes": "",
+                            "preWait": 0.0,
+                            "chain": { "type": "afterComplete", "targetId": null },
+                            "params": { "type": "wait", "duration": 1.0 }
+                        }]
+                    }
+                }],
+                "settings": {}
+            }
+        });
+
+        migrate(&mut document).unwrap();
+
+        assert_eq!(document["version"], json!(PROJECT_FILE_VERSION));
+        let child = &document["model"]["cues"][0]["params"]["children"][0];
+        assert_eq!(child["armed"], json!(true));
+        assert_eq!(child["chain"]["postWait"], json!(0.0));
+        assert_eq!(document["model"]["cueLists"], json!([]));
+        assert_eq!(document["model"]["cart"], json!([]));
+        serde_json::from_value::<ProjectFile>(document).unwrap();
+    }
+
+    #[test]
+    fn migrate_v3_file() {
+        let audio = json!({
+            "id": "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+            "number": "3",
+            "name": null,
+            "notes": "",
+            "preWait": 0.0,
+            "chain": { "type": "doNotChain" },
+            "params": {
+                "type": "audio",
+                "target": "audio/bell.wav",
+                "startTime": null,
+                "fadeInParam": null,
+                "endTime": null,
+                "fadeOutParam": null,
+                "volume": 0.0,
+                "pan": 0.0,
+                "repeat": false,
+                "soundType": "streaming",
+                "envelope": []
+            }
+        });
+        let mut document = json!({
+            "version": 3,
+            "project_type": "singleFile",
+            "model": {
+                "name": "v3",
+                "cues": [],
+                "cueLists": [{
+                    "id": "5b6c7d8e-9fa0-4b1c-8d2e-3f4a5b6c7d8e",
+                    "name": "Sound FX",
+                    "cues": [{
+                        "id": "8f0f4a8e-63a5-4c8e-9a43-1c2d3e4f5a6b",
+                        "number": "1",
+                        "name": null,
+                        "notes": "",
+                        "preWait": 0.0,
+                        "chain": { "type": "doNotChain" },
+                        "params": {
+                            "type": "group",
+                            "mode": { "type": "concurrency" },
+                            "children": [{
+                                "id": "0c1d2e3f-4a5b-4c6d-8e7f-8091a2b3c4d5",
+                                "number": "2",
+                                "name": null,
+                                "notes": "",
+                                "preWait": 0.0,
+                                "chain": { "type": "doNotChain" },
+                                "params": {
+                                    "type": "group",
+                                    "mode": { "type": "concurrency" },
+                                    "children": [audio]
+                                }
+                            }]
+                        }
+                    }]
+                }],
+                "settings": {}
+            }
+        });
+
+        migrate(&mut document).unwrap();
+
+        assert_eq!(document["version"], json!(PROJECT_FILE_VERSION));
+        assert_eq!(document["model"]["cart"], json!([]));
+        let group = &document["model"]["cueLists"][0]["cues"][0]["params"];
+        assert_eq!(group["volume"], json!(0.0));
+        let nested = &group["children"][0]["params"];
+        assert_eq!(nested["volume"], json!(0.0));
+        let audio = nested["children"][0]["params"].as_object().unwrap();
+        assert_eq!(audio.get("originalPath"), Some(&json!(null)));
+        serde_json::from_value::<ProjectFile>(document).unwrap();
+    }
+
+    #[test]
+    fn refuse_newer_file() {
+        let mut document = json!({
+            "version": PROJECT_FILE_VERSION + 1,
+            "project_type": "singleFile",
+            "model": { "name": "future", "cues": [], "settings": {} }
+        });
+
+        let error = migrate(&mut document).unwrap_err();
+        assert!(error.to_string().contains("newer"));
+    }
+}
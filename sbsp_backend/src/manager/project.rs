@@ -7,11 +7,12 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[cfg(feature = "backend")]
-use crate::model::ShowModel;
+use crate::model::{ShowModel, cue::CueList};
 use crate::model::{
+    cart::CartSlot,
     cue::{
         CueChain, CueColor, FadeCueParam, LoadCueParam, PauseCueParam, StartCueParam, StopCueParam,
-        WaitCueParam, audio::AudioCueParam, group::GroupCueParamBase,
+        WaitCueParam, audio::AudioCueParam, default_armed, group::GroupCueParamBase,
     },
     settings::ShowSettings,
 };
@@ -50,18 +51,37 @@ impl ProjectStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// Current project file format version. Bump it together with a new entry in
+/// `manager::migration::MIGRATIONS` whenever the file schema changes.
+pub const PROJECT_FILE_VERSION: u32 = 4;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProjectFile {
+    pub version: u32,
     pub project_type: ProjectType,
     pub model: ProjectShowModel,
 }
 
+impl Default for ProjectFile {
+    fn default() -> Self {
+        Self {
+            version: PROJECT_FILE_VERSION,
+            project_type: ProjectType::default(),
+            model: ProjectShowModel::default(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectShowModel {
     name: String,
     cues: Vec<ProjectCue>,
+    #[serde(default)]
+    cue_lists: Vec<ProjectCueList>,
     settings: ShowSettings,
+    #[serde(default)]
+    cart: Vec<Option<CartSlot>>,
 }
 
 impl Default for ProjectShowModel {
@@ -69,7 +89,9 @@ impl Default for ProjectShowModel {
         Self {
             name: "Untitled".into(),
             cues: Vec::new(),
+            cue_lists: Vec::new(),
             settings: ShowSettings::default(),
+            cart: Vec::new(),
         }
     }
 }
@@ -79,8 +101,10 @@ impl From<ShowModel> for ProjectShowModel {
     fn from(value: ShowModel) -> Self {
         Self {
             name: value.name,
+            cue_lists: value.cue_list.project_lists(),
             cues: value.cue_list.into(),
             settings: value.settings,
+            cart: value.cart,
         }
     }
 }
@@ -90,14 +114,28 @@ impl TryFrom<ProjectShowModel> for ShowModel {
     type Error = anyhow::Error;
 
     fn try_from(value: ProjectShowModel) -> Result<Self, Self::Error> {
+        let mut cue_list: CueList = value.cues.try_into()?;
+        for list in value.cue_lists {
+            cue_list.insert_project_list(list)?;
+        }
         Ok(Self {
             name: value.name,
-            cue_list: value.cues.try_into()?,
+            cue_list,
             settings: value.settings,
+            cart: value.cart,
         })
     }
 }
 
+/// A named cue list besides the main one, stored as a cue tree.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCueList {
+    pub id: Uuid,
+    pub name: String,
+    pub cues: Vec<ProjectCue>,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectCue {
@@ -107,8 +145,12 @@ pub struct ProjectCue {
     pub notes: String,
     #[serde(default)]
     pub color: CueColor,
+    #[serde(default = "default_armed")]
+    pub armed: bool,
     pub pre_wait: f64,
     #[serde(default)]
+    pub timeline_offset: f64,
+    #[serde(default)]
     pub chain: CueChain,
     pub params: ProjectCueParam,
 }
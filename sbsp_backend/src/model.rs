@@ -3,8 +3,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::model::{cue::CueList, settings::ShowSettings};
+use crate::model::{cart::CartSlot, cue::CueList, settings::ShowSettings};
 
+pub mod cart;
 pub mod cue;
 pub mod settings;
 
@@ -16,6 +17,9 @@ pub struct ShowModel {
     #[serde(flatten)]
     pub cue_list: CueList,
     pub settings: ShowSettings,
+    /// Cart slots by index. `None` is an empty slot.
+    #[serde(default)]
+    pub cart: Vec<Option<CartSlot>>,
 }
 
 impl Default for ShowModel {
@@ -24,6 +28,7 @@ impl Default for ShowModel {
             name: "Untitled".into(),
             cue_list: CueList::default(),
             settings: ShowSettings::default(),
+            cart: Vec::new(),
         }
     }
 }
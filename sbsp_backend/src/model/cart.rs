@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Upper bound for slot indices, so a stray index cannot grow the cart without limit.
+pub const MAX_CART_SLOTS: usize = 128;
+
+/// A cart slot, firing its cue without touching the playback cursor.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct CartSlot {
+    pub cue_id: Uuid,
+    #[serde(default)]
+    pub mode: CartSlotMode,
+}
+
+/// What firing a slot does while its cue is already running.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum CartSlotMode {
+    /// Stop the cue.
+    #[default]
+    Toggle,
+    /// Play the cue again from the beginning.
+    Restart,
+    /// Keep playing. The slot fires again only once the cue has ended.
+    Latch,
+}
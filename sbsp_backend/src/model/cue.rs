@@ -11,7 +11,9 @@ use ts_rs::TS;
 pub use uuid::Uuid;
 
 #[cfg(feature = "backend")]
-use crate::manager::project::{ProjectCue, ProjectCueParam};
+use crate::manager::project::{ProjectCue, ProjectCueList, ProjectCueParam};
+#[cfg(feature = "backend")]
+use crate::model::cue::group::GroupMode;
 use crate::model::cue::{
     audio::{AudioCueParam, Decibels, FadeParam},
     group::GroupCueParamBase,
@@ -23,10 +25,193 @@ use crate::model::cue::{
 pub struct CueList {
     pub cues: HashMap<Uuid, Cue>,
     pub root_ids: Vec<Uuid>,
+    /// Cue lists besides the main one. Their cues are stored in `cues` too.
+    #[serde(default)]
+    pub lists: Vec<NamedCueList>,
+}
+
+/// Id addressing the main cue list, whose top-level cues are `CueList::root_ids`.
+pub const MAIN_CUE_LIST_ID: Uuid = Uuid::nil();
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct NamedCueList {
+    pub id: Uuid,
+    pub name: String,
+    pub root_ids: Vec<Uuid>,
 }
 
 #[cfg(feature = "backend")]
 impl CueList {
+    pub fn root_ids_of(&self, list_id: &Uuid) -> Option<&Vec<Uuid>> {
+        if *list_id == MAIN_CUE_LIST_ID {
+            return Some(&self.root_ids);
+        }
+        self.lists
+            .iter()
+            .find(|list| list.id == *list_id)
+            .map(|list| &list.root_ids)
+    }
+
+    pub fn root_ids_of_mut(&mut self, list_id: &Uuid) -> Option<&mut Vec<Uuid>> {
+        if *list_id == MAIN_CUE_LIST_ID {
+            return Some(&mut self.root_ids);
+        }
+        self.lists
+            .iter_mut()
+            .find(|list| list.id == *list_id)
+            .map(|list| &mut list.root_ids)
+    }
+
+    /// Resolves an optional top-level insert index of a list, `None` meaning the end.
+    pub fn root_insert_index(
+        &self,
+        list_id: &Uuid,
+        index: Option<usize>,
+    ) -> Result<usize, anyhow::Error> {
+        let Some(root_ids) = self.root_ids_of(list_id) else {
+            anyhow::bail!("cue list not found.");
+        };
+        match index {
+            Some(idx) if idx <= root_ids.len() => Ok(idx),
+            Some(_) => anyhow::bail!("insert index out of range."),
+            None => Ok(root_ids.len()),
+        }
+    }
+
+    /// Top-level cues of every list, main list first.
+    pub fn all_root_ids(&self) -> impl DoubleEndedIterator<Item = &Uuid> {
+        self.root_ids
+            .iter()
+            .chain(self.lists.iter().flat_map(|list| list.root_ids.iter()))
+    }
+
+    /// Id of the list holding `cue_id`, or of its top-level ancestor.
+    pub fn list_id_of(&self, cue_id: &Uuid) -> Option<Uuid> {
+        let mut current_id = *cue_id;
+        while let Some(parent_id) = self.cues.get(&current_id)?.parent_id {
+            current_id = parent_id;
+        }
+        if self.root_ids.contains(&current_id) {
+            return Some(MAIN_CUE_LIST_ID);
+        }
+        self.lists
+            .iter()
+            .find(|list| list.root_ids.contains(&current_id))
+            .map(|list| list.id)
+    }
+
+    /// Top-level order that contains `cue_id`.
+    pub fn root_ids_containing_mut(&mut self, cue_id: &Uuid) -> Option<&mut Vec<Uuid>> {
+        if self.root_ids.contains(cue_id) {
+            return Some(&mut self.root_ids);
+        }
+        self.lists
+            .iter_mut()
+            .map(|list| &mut list.root_ids)
+            .find(|root_ids| root_ids.contains(cue_id))
+    }
+
+    /// Cue that follows `cue_id` in playback order. The last cue of a group
+    /// is followed by the group's next sibling.
+    pub fn next_cue_id(&self, cue_id: &Uuid) -> Option<Uuid> {
+        let mut current_id = *cue_id;
+
+        loop {
+            let cue = self.cues.get(&current_id)?;
+
+            if let Some(parent_id) = cue.parent_id {
+                let parent = self.cues.get(&parent_id)?;
+                if let CueParam::Group { children, .. } = &parent.params
+                    && let Some(idx) = children.iter().position(|id| *id == current_id)
+                {
+                    if let Some(next_id) = children.get(idx + 1) {
+                        return Some(*next_id);
+                    }
+
+                    current_id = parent_id;
+                    continue;
+                }
+
+                return None;
+            }
+
+            let list_id = self.list_id_of(&current_id)?;
+            let root_ids = self.root_ids_of(&list_id)?;
+            let idx = root_ids.iter().position(|id| *id == current_id)?;
+            return root_ids.get(idx + 1).copied();
+        }
+    }
+
+    /// Cue the playback cursor steps back to from `cue_id`. This undoes a
+    /// cursor advance: a `StartFirst { enter }` group is left from its second
+    /// child, since GO on the group fires the first one, and the cue after such
+    /// a group steps back to the group's last child.
+    pub fn previous_cue_id(&self, cue_id: &Uuid) -> Option<Uuid> {
+        let cue = self.cues.get(cue_id)?;
+        let siblings = match cue.parent_id {
+            Some(parent_id) => match &self.cues.get(&parent_id)?.params {
+                CueParam::Group { children, .. } => children,
+                _ => return None,
+            },
+            None => self.root_ids_of(&self.list_id_of(cue_id)?)?,
+        };
+        let idx = siblings.iter().position(|id| id == cue_id)?;
+        if let Some(parent_id) = cue.parent_id
+            && (idx == 0 || idx == 1 && self.is_entered_group(&parent_id))
+        {
+            return Some(parent_id);
+        }
+        let previous_id = siblings.get(idx.checked_sub(1)?)?;
+        Some(self.last_cursor_id_in(previous_id))
+    }
+
+    /// Last cue the cursor stops at in the list `list_id`.
+    pub fn last_cue_id(&self, list_id: &Uuid) -> Option<Uuid> {
+        let last_id = self.root_ids_of(list_id)?.last()?;
+        Some(self.last_cursor_id_in(last_id))
+    }
+
+    /// Last cue the cursor stops at while stepping through `cue_id`, which is
+    /// the cue itself unless it is a `StartFirst { enter }` group.
+    fn last_cursor_id_in(&self, cue_id: &Uuid) -> Uuid {
+        let mut current_id = *cue_id;
+        while self.is_entered_group(&current_id)
+            && let Some(CueParam::Group { children, .. }) =
+                self.cues.get(&current_id).map(|cue| &cue.params)
+            && children.len() > 1
+            && let Some(last_id) = children.last()
+        {
+            current_id = *last_id;
+        }
+        current_id
+    }
+
+    /// Sum of the volume trims of the groups containing `cue_id`.
+    pub fn group_trim_of(&self, cue_id: &Uuid) -> Decibels {
+        let mut trim = Decibels::IDENTITY;
+        let mut current_id = *cue_id;
+        while let Some(parent_id) = self.cues.get(&current_id).and_then(|cue| cue.parent_id) {
+            if let Some(CueParam::Group { base, .. }) =
+                self.cues.get(&parent_id).map(|cue| &cue.params)
+            {
+                trim = trim + base.volume;
+            }
+            current_id = parent_id;
+        }
+        trim
+    }
+
+    fn is_entered_group(&self, cue_id: &Uuid) -> bool {
+        self.cues.get(cue_id).is_some_and(|cue| {
+            matches!(
+                &cue.params,
+                CueParam::Group { base, .. } if base.mode == GroupMode::StartFirst { enter: true }
+            )
+        })
+    }
+
     fn flatten_cue(
         cue: ProjectCue,
         parent_id: Option<Uuid>,
@@ -58,7 +243,9 @@ impl CueList {
             name: cue.name,
             notes: cue.notes,
             color: cue.color,
+            armed: cue.armed,
             pre_wait: cue.pre_wait,
+            timeline_offset: cue.timeline_offset,
             chain: cue.chain,
             parent_id,
             params: flat_params,
@@ -107,13 +294,45 @@ impl CueList {
                     name: flat_cue.name.clone(),
                     notes: flat_cue.notes.clone(),
                     color: flat_cue.color,
+                    armed: flat_cue.armed,
                     pre_wait: flat_cue.pre_wait,
+                    timeline_offset: flat_cue.timeline_offset,
                     chain: flat_cue.chain,
                     params: cue_params,
                 });
             }
         }
     }
+
+    /// Adds a list read from a project file. Its cues join the shared map.
+    pub fn insert_project_list(&mut self, list: ProjectCueList) -> Result<(), anyhow::Error> {
+        let mut root_ids = Vec::with_capacity(list.cues.len());
+        for cue in list.cues {
+            root_ids.push(cue.id);
+            Self::flatten_cue(cue, None, &mut self.cues)?;
+        }
+        self.lists.push(NamedCueList {
+            id: list.id,
+            name: list.name,
+            root_ids,
+        });
+        Ok(())
+    }
+
+    pub fn project_lists(&self) -> Vec<ProjectCueList> {
+        self.lists
+            .iter()
+            .map(|list| {
+                let mut cues = Vec::with_capacity(list.root_ids.len());
+                Self::reconstruct_cue(&self.cues, &list.root_ids, &mut cues);
+                ProjectCueList {
+                    id: list.id,
+                    name: list.name.clone(),
+                    cues,
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(feature = "backend")]
@@ -146,12 +365,20 @@ pub struct Cue {
     pub name: Option<String>,
     pub notes: String,
     pub color: CueColor,
+    #[serde(default = "default_armed")]
+    pub armed: bool,
     pub pre_wait: f64,
+    #[serde(default)]
+    pub timeline_offset: f64,
     pub chain: CueChain,
     pub parent_id: Option<Uuid>,
     pub params: CueParam,
 }
 
+pub(crate) fn default_armed() -> bool {
+    true
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, TS)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum CueColor {
@@ -178,9 +405,13 @@ pub enum CueChain {
     DoNotChain,
     AfterStart {
         target_id: Option<Uuid>,
+        #[serde(default)]
+        post_wait: f64,
     },
     AfterComplete {
         target_id: Option<Uuid>,
+        #[serde(default)]
+        post_wait: f64,
     },
 }
 
@@ -86,6 +86,9 @@ pub struct AudioCueParam {
     pub sound_type: SoundType,
     #[serde(default)]
     pub envelope: Vec<EnvelopeSegment>,
+    /// Where the file was imported from, when it was copied into the project.
+    #[serde(default)]
+    pub original_path: Option<PathBuf>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, TS)]
@@ -4,6 +4,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::audio::Decibels;
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
 #[serde(
     tag = "type",
@@ -14,10 +16,14 @@ pub enum GroupMode {
     Playlist { repeat: bool },
     Concurrency,
     StartFirst { enter: bool },
+    Timeline,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
 #[serde(rename_all = "camelCase")]
 pub struct GroupCueParamBase {
     pub mode: GroupMode,
+    /// Trim added to the volume of every audio cue inside the group, nested groups included.
+    #[serde(default)]
+    pub volume: Decibels,
 }
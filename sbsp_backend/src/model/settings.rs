@@ -17,22 +17,44 @@ pub struct ShowSettings {
 #[serde(rename_all = "camelCase", default)]
 pub struct ShowGeneralSettings {
     pub copy_assets_destination: String,
+    pub reference_policy: ReferencePolicy,
+    /// Seconds after a GO during which further GO presses are ignored. 0 disables the lockout.
+    pub go_lockout: f64,
+    /// Ask for a second GO before restarting a cue that is still running.
+    pub confirm_repeat_go: bool,
 }
 
 impl Default for ShowGeneralSettings {
     fn default() -> Self {
         Self {
             copy_assets_destination: ".".to_string(),
+            reference_policy: ReferencePolicy::default(),
+            go_lockout: 0.0,
+            confirm_repeat_go: false,
         }
     }
 }
 
+/// What removing a cue does to other cues that target it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum ReferencePolicy {
+    /// Clear the references: targets become nil and chains to them are dropped.
+    #[default]
+    Clear,
+    /// Keep the cue and fail the removal.
+    Refuse,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase", default)]
 pub struct ShowAudioSettings {
     pub mono_output: bool,
     pub lufs_target: f64,
+    /// Seconds `Panic` takes to fade all audio out.
+    pub panic_fade_duration: f64,
 }
 
 impl Default for ShowAudioSettings {
@@ -40,6 +62,7 @@ impl Default for ShowAudioSettings {
         Self {
             mono_output: false,
             lufs_target: -14.0,
+            panic_fade_duration: 3.0,
         }
     }
 }
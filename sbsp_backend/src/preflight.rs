@@ -0,0 +1,417 @@
+// SPDX-License-Identifier: Elastic-2.0
+// Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
+
+#[cfg(feature = "backend")]
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use crate::{
+    asset_processor::AssetData,
+    manager::asset_import::MissingAsset,
+    model::{
+        ShowModel,
+        cue::{CueChain, CueList, CueParam, audio::AudioCueParam},
+    },
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightWarning {
+    pub cue_id: Uuid,
+    pub issue: PreflightIssue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "type_export", derive(ts_rs::TS))]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum PreflightIssue {
+    MissingAsset {
+        target: PathBuf,
+    },
+    /// A Fade, Start, Stop, Pause or Load cue targets a cue that does not exist.
+    DanglingTarget {
+        target: Uuid,
+    },
+    DanglingChainTarget {
+        target: Uuid,
+    },
+    /// Following the chains from this cue comes back to it. `cue_ids` lists
+    /// the loop in chain order, starting with this cue.
+    ChainLoop {
+        cue_ids: Vec<Uuid>,
+    },
+    EmptyNumber,
+    DuplicateNumber {
+        number: String,
+    },
+    FadeLongerThanTarget {
+        fade_duration: f64,
+        target_duration: f64,
+    },
+    /// Predicted peak in dBFS, from the file peak plus the playback gain.
+    Clipping {
+        level: f32,
+    },
+}
+
+/// Checks the show for problems worth fixing before a performance. `assets`
+/// maps audio targets, as written in the model, to their analysis; targets
+/// that were not analyzed skip the duration and level checks. Warnings come
+/// back in playback order.
+#[cfg(feature = "backend")]
+pub fn analyze(
+    model: &ShowModel,
+    missing: &[MissingAsset],
+    assets: &HashMap<PathBuf, AssetData>,
+) -> Vec<PreflightWarning> {
+    let cue_list = &model.cue_list;
+    let order = playback_order(cue_list);
+    let loops = chain_loops(cue_list, &order);
+    let mut number_counts: HashMap<&str, usize> = HashMap::new();
+    for cue in cue_list.cues.values() {
+        *number_counts.entry(cue.number.trim()).or_default() += 1;
+    }
+
+    let mut warnings = Vec::new();
+    for cue in order.iter().filter_map(|id| cue_list.cues.get(id)) {
+        let mut warn = |issue| {
+            warnings.push(PreflightWarning {
+                cue_id: cue.id,
+                issue,
+            })
+        };
+
+        if let Some(asset) = missing.iter().find(|asset| asset.cue_id == cue.id) {
+            warn(PreflightIssue::MissingAsset {
+                target: asset.target.clone(),
+            });
+        }
+        let number = cue.number.trim();
+        if number.is_empty() {
+            warn(PreflightIssue::EmptyNumber);
+        } else if number_counts.get(number).is_some_and(|count| *count > 1) {
+            warn(PreflightIssue::DuplicateNumber {
+                number: number.to_string(),
+            });
+        }
+
+        let target = match &cue.params {
+            CueParam::Fade(param) => Some(param.target),
+            CueParam::Start(param) => Some(param.target),
+            CueParam::Stop(param) => Some(param.target),
+            CueParam::Pause(param) => Some(param.target),
+            CueParam::Load(param) => Some(param.target),
+            _ => None,
+        };
+        if let Some(target) = target
+            && !cue_list.cues.contains_key(&target)
+        {
+            warn(PreflightIssue::DanglingTarget { target });
+        }
+        if let CueChain::AfterStart {
+            target_id: Some(target),
+            ..
+        }
+        | CueChain::AfterComplete {
+            target_id: Some(target),
+            ..
+        } = cue.chain
+            && !cue_list.cues.contains_key(&target)
+        {
+            warn(PreflightIssue::DanglingChainTarget { target });
+        }
+        if let Some(cue_ids) = loops.get(&cue.id) {
+            warn(PreflightIssue::ChainLoop {
+                cue_ids: cue_ids.clone(),
+            });
+        }
+
+        match &cue.params {
+            CueParam::Audio(param) => {
+                if let Some(data) = assets.get(&param.target) {
+                    let level = data.peak + f32::from(param.volume) + envelope_gain(param);
+                    if level > 0.0 {
+                        warn(PreflightIssue::Clipping { level });
+                    }
+                }
+            }
+            CueParam::Fade(param) => {
+                if let Some(target) = cue_list.cues.get(&param.target)
+                    && let CueParam::Audio(target_param) = &target.params
+                    && let Some(data) = assets.get(&target_param.target)
+                {
+                    let level = data.peak + f32::from(param.volume) + envelope_gain(target_param);
+                    if level > 0.0 {
+                        warn(PreflightIssue::Clipping { level });
+                    }
+                    if let Some(target_duration) = play_length(target_param, data)
+                        && param.fade_param.duration > target_duration
+                    {
+                        warn(PreflightIssue::FadeLongerThanTarget {
+                            fade_duration: param.fade_param.duration,
+                            target_duration,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
+#[cfg(feature = "backend")]
+fn playback_order(cue_list: &CueList) -> Vec<Uuid> {
+    fn visit(cue_list: &CueList, ids: &[Uuid], order: &mut Vec<Uuid>) {
+        for id in ids {
+            order.push(*id);
+            if let Some(cue) = cue_list.cues.get(id)
+                && let CueParam::Group { children, .. } = &cue.params
+            {
+                visit(cue_list, children, order);
+            }
+        }
+    }
+    let mut order = Vec::with_capacity(cue_list.cues.len());
+    visit(cue_list, &cue_list.root_ids, &mut order);
+    for list in &cue_list.lists {
+        visit(cue_list, &list.root_ids, &mut order);
+    }
+    order
+}
+
+/// Finds cycles in the chain graph. Every cue chains to at most one other,
+/// so each cycle is found by walking forward until a cue repeats. The loop is
+/// reported once, on its first cue in playback order.
+#[cfg(feature = "backend")]
+fn chain_loops(cue_list: &CueList, order: &[Uuid]) -> HashMap<Uuid, Vec<Uuid>> {
+    let chain_target = |id: &Uuid| match cue_list.cues.get(id)?.chain {
+        CueChain::DoNotChain => None,
+        CueChain::AfterStart { target_id, .. } | CueChain::AfterComplete { target_id, .. } => {
+            target_id.or_else(|| cue_list.next_cue_id(id))
+        }
+    };
+    let position: HashMap<Uuid, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(index, id)| (*id, index))
+        .collect();
+
+    let mut visited = HashMap::new();
+    let mut loops = HashMap::new();
+    for (walk, start) in order.iter().enumerate() {
+        let mut path = Vec::new();
+        let mut current = Some(*start);
+        while let Some(id) = current {
+            if let Some(seen_in) = visited.get(&id) {
+                if *seen_in == walk
+                    && let Some(index) = path.iter().position(|path_id| *path_id == id)
+                {
+                    let mut cycle: Vec<Uuid> = path[index..].to_vec();
+                    let first = (0..cycle.len())
+                        .min_by_key(|i| position.get(&cycle[*i]).copied().unwrap_or(usize::MAX))
+                        .unwrap_or(0);
+                    cycle.rotate_left(first);
+                    loops.insert(cycle[0], cycle);
+                }
+                break;
+            }
+            visited.insert(id, walk);
+            path.push(id);
+            current = chain_target(&id);
+        }
+    }
+    loops
+}
+
+/// Loudest envelope gain on top of the cue volume.
+#[cfg(feature = "backend")]
+fn envelope_gain(param: &AudioCueParam) -> f32 {
+    param
+        .envelope
+        .iter()
+        .map(|segment| f32::from(segment.volume))
+        .reduce(f32::max)
+        .unwrap_or(0.0)
+}
+
+#[cfg(feature = "backend")]
+fn play_length(param: &AudioCueParam, data: &AssetData) -> Option<f64> {
+    let duration = data.metadata.duration?;
+    let end = param.end_time.unwrap_or(duration).min(duration);
+    Some((end - param.start_time.unwrap_or(0.0)).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use crate::{
+        asset_processor::{AssetData, AssetMetadata},
+        model::{
+            ShowModel,
+            cue::{
+                Cue, CueChain, CueParam, FadeCueParam, StartCueParam, Uuid, WaitCueParam,
+                audio::{AudioCueParam, FadeParam},
+            },
+        },
+    };
+
+    use super::{PreflightIssue, analyze};
+
+    fn cue(number: &str, chain: CueChain, params: CueParam) -> Cue {
+        Cue {
+            id: Uuid::new_v4(),
+            number: number.into(),
+            name: None,
+            notes: String::new(),
+            color: Default::default(),
+            armed: true,
+            pre_wait: 0.0,
+            timeline_offset: 0.0,
+            chain,
+            parent_id: None,
+            params,
+        }
+    }
+
+    #[test]
+    fn analyze_show() {
+        let audio = cue(
+            "1",
+            CueChain::DoNotChain,
+            CueParam::Audio(AudioCueParam {
+                target: PathBuf::from("audio/loud.wav"),
+                start_time: None,
+                fade_in_param: None,
+                end_time: Some(4.0),
+                fade_out_param: None,
+                volume: 3.0.into(),
+                pan: 0.0,
+                repeat: false,
+                sound_type: Default::default(),
+                envelope: vec![],
+                original_path: None,
+            }),
+        );
+        let fade = cue(
+            "2",
+            CueChain::DoNotChain,
+            CueParam::Fade(FadeCueParam {
+                target: audio.id,
+                volume: (-20.0).into(),
+                fade_param: FadeParam::default(),
+            }),
+        );
+        let mut fade_too_long = fade.clone();
+        fade_too_long.id = Uuid::new_v4();
+        if let CueParam::Fade(param) = &mut fade_too_long.params {
+            param.fade_param.duration = 10.0;
+        }
+        let dangling = cue(
+            "",
+            CueChain::DoNotChain,
+            CueParam::Start(StartCueParam {
+                target: Uuid::new_v4(),
+            }),
+        );
+        // 5 chains to 6 by default and 6 chains back to 5
+        let first_wait = cue(
+            "5",
+            CueChain::AfterComplete {
+                target_id: None,
+                post_wait: 0.0,
+            },
+            CueParam::Wait(WaitCueParam { duration: 1.0 }),
+        );
+        let second_wait = cue(
+            "6",
+            CueChain::AfterComplete {
+                target_id: Some(first_wait.id),
+                post_wait: 0.0,
+            },
+            CueParam::Wait(WaitCueParam { duration: 1.0 }),
+        );
+
+        let mut model = ShowModel::default();
+        for cue in [
+            &audio,
+            &fade,
+            &fade_too_long,
+            &dangling,
+            &first_wait,
+            &second_wait,
+        ] {
+            model.cue_list.root_ids.push(cue.id);
+            model.cue_list.cues.insert(cue.id, cue.clone());
+        }
+        let assets = HashMap::from([(
+            PathBuf::from("audio/loud.wav"),
+            AssetData {
+                metadata: AssetMetadata {
+                    path: PathBuf::from("audio/loud.wav"),
+                    duration: Some(30.0),
+                    channel_count: Some(2),
+                    sample_rate: 48000,
+                },
+                waveform: vec![],
+                integrated_lufs: None,
+                peak: -1.0,
+                start_time: None,
+                end_time: None,
+            },
+        )]);
+
+        let warnings = analyze(&model, &[], &assets);
+        let issues: Vec<_> = warnings
+            .iter()
+            .map(|warning| (warning.cue_id, warning.issue.clone()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (audio.id, PreflightIssue::Clipping { level: 2.0 }),
+                (
+                    fade.id,
+                    PreflightIssue::DuplicateNumber { number: "2".into() }
+                ),
+                (
+                    fade_too_long.id,
+                    PreflightIssue::DuplicateNumber { number: "2".into() }
+                ),
+                (
+                    fade_too_long.id,
+                    PreflightIssue::FadeLongerThanTarget {
+                        fade_duration: 10.0,
+                        target_duration: 4.0
+                    }
+                ),
+                (dangling.id, PreflightIssue::EmptyNumber),
+                (
+                    dangling.id,
+                    PreflightIssue::DanglingTarget {
+                        target: match dangling.params {
+                            CueParam::Start(param) => param.target,
+                            _ => unreachable!(),
+                        }
+                    }
+                ),
+                (
+                    first_wait.id,
+                    PreflightIssue::ChainLoop {
+                        cue_ids: vec![first_wait.id, second_wait.id]
+                    }
+                ),
+            ]
+        );
+    }
+}
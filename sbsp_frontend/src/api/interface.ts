@@ -14,6 +14,11 @@ import type { FullShowState } from '../types/FullShowState';
 import type { SupportedHardware } from '../types/SupportedHardware';
 import type { Permissions } from '../types/Permissions';
 import type { InsertPosition } from '../types/InsertPosition';
+import type { CartSlot } from '../types/CartSlot';
+import type { FadeParam } from '../types/FadeParam';
+import type { EnvelopeSegment } from '../types/EnvelopeSegment';
+import type { JournalEntry } from '../types/JournalEntry';
+import type { JournalQuery } from '../types/JournalQuery';
 
 type UnlistenFn = () => void;
 
@@ -40,12 +45,23 @@ export interface IBackendAdapter {
   requestStateSync(): void;
   getFullState(): Promise<FullShowState>;
 
+  // show journal
+  queryJournal(query: JournalQuery): Promise<JournalEntry[]>;
+
   // asset processor
   processAsset(path: string): Promise<void>;
+  runPreflight(): Promise<void>;
 
   // controller commands
   setPlaybackCursor(cueId: string | null): Promise<void>;
+  setListCursor(listId: string, cueId: string | null): Promise<void>;
   sendGo(): Promise<void>;
+  sendGoList(listId: string): Promise<void>;
+  sendGoFrom(cueId: string): Promise<void>;
+  sendCursorNext(listId: string): Promise<void>;
+  sendCursorPrevious(listId: string): Promise<void>;
+  sendCursorToFirst(listId: string): Promise<void>;
+  fireCartSlot(index: number): Promise<void>;
   sendLoad(cueId: string): Promise<void>;
   sendPause(cueId: string): Promise<void>;
   sendResume(cueId: string): Promise<void>;
@@ -53,22 +69,45 @@ export interface IBackendAdapter {
   sendPauseAll(): Promise<void>;
   sendResumeAll(): Promise<void>;
   sendStopAll(): Promise<void>;
+  sendPanic(): Promise<void>;
   sendSeekTo(cueId: string, position: number): Promise<void>;
   sendSeekBy(cueId: string, amount: number): Promise<void>;
   sendToggleRepeat(cueId: string): Promise<void>;
   sendSetVolume(cueId: string, volume: number): Promise<void>;
+  sendSetPan(cueId: string, pan: number): Promise<void>;
+  sendSetRate(cueId: string, rate: number): Promise<void>;
+  sendSetStartEnd(cueId: string, startTime: number | null, endTime: number | null): Promise<void>;
+  sendSetFadeOut(cueId: string, fadeParam: FadeParam | null): Promise<void>;
+  sendReplaceEnvelope(cueId: string, envelope: EnvelopeSegment[]): Promise<void>;
+  sendSetMasterVolume(volume: number, fadeParam: FadeParam | null): Promise<void>;
+  sendToggleArmed(cueId: string): Promise<void>;
+  sendStoreLiveState(cueId: string): Promise<void>;
 
   // Model getter
   isModified(): Promise<boolean>;
 
   // Model commands
   updateCue(cue: Cue): Promise<void>;
-  addCue(cue: Cue, targetId: string | null, toBefore: boolean): Promise<string>;
-  addCues(cues: Cue[], targetId: string | null, toBefore: boolean): Promise<string[]>;
+  addCue(
+    cue: Cue,
+    targetId: string | null,
+    toBefore: boolean,
+    listId?: string | null,
+  ): Promise<string>;
+  addCues(
+    cues: Cue[],
+    targetId: string | null,
+    toBefore: boolean,
+    listId?: string | null,
+  ): Promise<string[]>;
   removeCue(cueId: string, confirm_remove?: boolean): Promise<void>;
   removeCues(cueIds: string[], confirm_remove?: boolean): Promise<void>;
   moveCue(cueId: string, position: InsertPosition): Promise<void>;
   moveCues(cueIds: string[], position: InsertPosition): Promise<void>;
+  addCueList(name: string): Promise<void>;
+  renameCueList(listId: string, name: string): Promise<void>;
+  removeCueList(listId: string, confirm_remove?: boolean): Promise<void>;
+  setCartSlot(index: number, slot: CartSlot | null): Promise<void>;
   renumberCues(
     cues: string[],
     startFrom: number,
@@ -76,6 +115,8 @@ export interface IBackendAdapter {
     prefix: string | null,
     suffix: string | null,
   ): Promise<void>;
+  undo(): Promise<void>;
+  redo(): Promise<void>;
   updateModelName(newName: string): Promise<void>;
   updateShowSettings(newSettings: ShowSettings): Promise<void>;
 
@@ -96,9 +137,18 @@ export interface IBackendHostAdapter {
   // file pick
   fileNew(): void;
   fileOpen(): void;
+  fileImport(): void;
+  openBundle(): void;
+  relinkAssets(): void;
   fileSave(): Promise<boolean>;
   fileSaveAs(): Promise<boolean>;
   exportToFolder(): Promise<boolean>;
+  exportCueSheet(): Promise<boolean>;
+  exportJournal(): Promise<boolean>;
+  exportBundle(): Promise<boolean>;
+  checkRecovery(): Promise<void>;
+  recover(): Promise<void>;
+  discardRecovery(): Promise<void>;
 
   // Server Specific
   isServerRunning(): Promise<boolean>;
@@ -27,6 +27,11 @@ import type { FullShowState } from '../types/FullShowState';
 import type { SupportedHardware } from '../types/SupportedHardware';
 import type { Permissions } from '../types/Permissions';
 import type { InsertPosition } from '../types/InsertPosition';
+import type { CartSlot } from '../types/CartSlot';
+import type { FadeParam } from '../types/FadeParam';
+import type { EnvelopeSegment } from '../types/EnvelopeSegment';
+import type { JournalEntry } from '../types/JournalEntry';
+import type { JournalQuery } from '../types/JournalQuery';
 import { v4 } from 'uuid';
 import { AUDIO_EXTENSIONS } from '.';
 
@@ -49,6 +54,15 @@ export function useTauriApi(): IBackendAdapter {
           fileOpen: function (): void {
             invoke('file_open').catch((e) => console.error(e));
           },
+          fileImport: function (): void {
+            invoke('file_import').catch((e) => console.error(e));
+          },
+          openBundle: function (): void {
+            invoke('open_bundle').catch((e) => console.error(e));
+          },
+          relinkAssets: function (): void {
+            invoke('relink_assets').catch((e) => console.error(e));
+          },
           fileSave: function (): Promise<boolean> {
             return invoke<boolean>('file_save');
           },
@@ -58,6 +72,24 @@ export function useTauriApi(): IBackendAdapter {
           exportToFolder: function (): Promise<boolean> {
             return invoke<boolean>('export_to_folder');
           },
+          exportBundle: function (): Promise<boolean> {
+            return invoke<boolean>('export_bundle');
+          },
+          exportCueSheet: function (): Promise<boolean> {
+            return invoke<boolean>('export_cue_sheet');
+          },
+          exportJournal: function (): Promise<boolean> {
+            return invoke<boolean>('export_journal');
+          },
+          checkRecovery: function (): Promise<void> {
+            return invoke('check_recovery');
+          },
+          recover: function (): Promise<void> {
+            return invoke('recover');
+          },
+          discardRecovery: function (): Promise<void> {
+            return invoke('discard_recovery');
+          },
           isServerRunning: function (): Promise<boolean> {
             return invoke<boolean>('is_server_running');
           },
@@ -140,6 +172,10 @@ export function useTauriApi(): IBackendAdapter {
       return invoke<FullShowState>('get_full_state');
     },
 
+    queryJournal: function (query: JournalQuery): Promise<JournalEntry[]> {
+      return invoke<JournalEntry[]>('query_journal', { query });
+    },
+
     getThirdPartyNotices: function (): Promise<string> {
       return invoke<string>('get_third_party_notices');
     },
@@ -187,12 +223,36 @@ export function useTauriApi(): IBackendAdapter {
     processAsset: function (path: string): Promise<void> {
       return invoke('process_asset', { path: path });
     },
+    runPreflight: function (): Promise<void> {
+      return invoke('run_preflight');
+    },
     setPlaybackCursor: function (cueId: string | null): Promise<void> {
       return invoke('set_playback_cursor', { cueId: cueId });
     },
+    setListCursor: function (listId: string, cueId: string | null): Promise<void> {
+      return invoke('set_list_cursor', { listId: listId, cueId: cueId });
+    },
     sendGo: function (): Promise<void> {
       return invoke('go');
     },
+    sendGoList: function (listId: string): Promise<void> {
+      return invoke('go_list', { listId: listId });
+    },
+    sendGoFrom: function (cueId: string): Promise<void> {
+      return invoke('go_from', { cueId: cueId });
+    },
+    sendCursorNext: function (listId: string): Promise<void> {
+      return invoke('cursor_next', { listId: listId });
+    },
+    sendCursorPrevious: function (listId: string): Promise<void> {
+      return invoke('cursor_previous', { listId: listId });
+    },
+    sendCursorToFirst: function (listId: string): Promise<void> {
+      return invoke('cursor_to_first', { listId: listId });
+    },
+    fireCartSlot: function (index: number): Promise<void> {
+      return invoke('fire_cart_slot', { index: index });
+    },
     sendLoad: function (cueId: string): Promise<void> {
       return invoke('load', { cueId: cueId });
     },
@@ -214,6 +274,9 @@ export function useTauriApi(): IBackendAdapter {
     sendStopAll: function (): Promise<void> {
       return invoke('stop_all');
     },
+    sendPanic: function (): Promise<void> {
+      return invoke('panic');
+    },
     sendSeekTo: function (cueId: string, position: number): Promise<void> {
       return invoke('seek_to', { cueId: cueId, position: position });
     },
@@ -226,6 +289,34 @@ export function useTauriApi(): IBackendAdapter {
     sendSetVolume: function (cueId: string, volume: number): Promise<void> {
       return invoke('set_volume', { cueId: cueId, volume: volume });
     },
+    sendSetPan: function (cueId: string, pan: number): Promise<void> {
+      return invoke('set_pan', { cueId: cueId, pan: pan });
+    },
+    sendSetRate: function (cueId: string, rate: number): Promise<void> {
+      return invoke('set_rate', { cueId: cueId, rate: rate });
+    },
+    sendSetStartEnd: function (
+      cueId: string,
+      startTime: number | null,
+      endTime: number | null,
+    ): Promise<void> {
+      return invoke('set_start_end', { cueId: cueId, startTime: startTime, endTime: endTime });
+    },
+    sendSetFadeOut: function (cueId: string, fadeParam: FadeParam | null): Promise<void> {
+      return invoke('set_fade_out', { cueId: cueId, fadeParam: fadeParam });
+    },
+    sendReplaceEnvelope: function (cueId: string, envelope: EnvelopeSegment[]): Promise<void> {
+      return invoke('replace_envelope', { cueId: cueId, envelope: envelope });
+    },
+    sendSetMasterVolume: function (volume: number, fadeParam: FadeParam | null): Promise<void> {
+      return invoke('set_master_volume', { volume: volume, fadeParam: fadeParam });
+    },
+    sendToggleArmed: function (cueId: string): Promise<void> {
+      return invoke('toggle_armed', { cueId: cueId });
+    },
+    sendStoreLiveState: function (cueId: string): Promise<void> {
+      return invoke('store_live_state', { cueId: cueId });
+    },
 
     isModified: function (): Promise<boolean> {
       return invoke<boolean>('is_modified');
@@ -233,21 +324,32 @@ export function useTauriApi(): IBackendAdapter {
     updateCue: function (cue: Cue): Promise<void> {
       return invoke('update_cue', { cue: cue });
     },
-    addCue: async function (cue: Cue, targetId: string | null, toBefore: boolean): Promise<string> {
+    addCue: async function (
+      cue: Cue,
+      targetId: string | null,
+      toBefore: boolean,
+      listId: string | null = null,
+    ): Promise<string> {
       cue.id = v4();
-      await invoke('add_cue', { cue: cue, targetId: targetId, toBefore: toBefore });
+      await invoke('add_cue', { cue: cue, targetId: targetId, toBefore: toBefore, listId: listId });
       return cue.id;
     },
     addCues: async function (
       cues: Cue[],
       targetId: string | null,
       toBefore: boolean,
+      listId: string | null = null,
     ): Promise<string[]> {
       const cueIds = cues.map((cue) => {
         cue.id = v4();
         return cue.id;
       });
-      await invoke('add_cues', { cues: cues, targetId: targetId, toBefore: toBefore });
+      await invoke('add_cues', {
+        cues: cues,
+        targetId: targetId,
+        toBefore: toBefore,
+        listId: listId,
+      });
       return cueIds;
     },
     removeCue: async function (cueId: string, confirm_remove: boolean = true) {
@@ -285,6 +387,28 @@ export function useTauriApi(): IBackendAdapter {
     moveCues: function (cueIds: string[], position: InsertPosition): Promise<void> {
       return invoke('move_cues', { cueIds: cueIds, position: position });
     },
+    addCueList: function (name: string): Promise<void> {
+      return invoke('add_cue_list', { name: name });
+    },
+    renameCueList: function (listId: string, name: string): Promise<void> {
+      return invoke('rename_cue_list', { listId: listId, name: name });
+    },
+    removeCueList: async function (listId: string, confirm_remove: boolean = true) {
+      if (confirm_remove) {
+        const removeOk = await message(t('dialog.message.removeCueList'), {
+          title: t('dialog.message.confirmation'),
+          kind: 'warning',
+          buttons: 'OkCancel',
+        });
+        if (removeOk !== 'Ok') {
+          return;
+        }
+      }
+      await invoke('remove_cue_list', { listId: listId });
+    },
+    setCartSlot: function (index: number, slot: CartSlot | null): Promise<void> {
+      return invoke('set_cart_slot', { index: index, slot: slot });
+    },
     renumberCues: function (
       cues: string[],
       startFrom: number,
@@ -294,6 +418,12 @@ export function useTauriApi(): IBackendAdapter {
     ): Promise<void> {
       return invoke('renumber_cues', { cues, startFrom, increment, prefix, suffix });
     },
+    undo: function (): Promise<void> {
+      return invoke('undo');
+    },
+    redo: function (): Promise<void> {
+      return invoke('redo');
+    },
     updateModelName: function (newName: string): Promise<void> {
       return invoke('update_model_name', { newName: newName });
     },
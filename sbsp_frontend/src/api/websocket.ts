@@ -23,6 +23,11 @@ import type { FullShowState } from '../types/FullShowState';
 import type { Permissions } from '../types/Permissions';
 import type { BackendError } from '../types/BackendError';
 import type { InsertPosition } from '../types/InsertPosition';
+import type { CartSlot } from '../types/CartSlot';
+import type { FadeParam } from '../types/FadeParam';
+import type { EnvelopeSegment } from '../types/EnvelopeSegment';
+import type { JournalEntry } from '../types/JournalEntry';
+import type { JournalQuery } from '../types/JournalQuery';
 import { i18n } from '../i18n';
 import { settingsParser, settingsValidator } from '../typia';
 import { DEFAULT_SETTINGS } from '@/stores/uiSettings';
@@ -65,6 +70,7 @@ const websocketApiState: {
     [key: string]: (isConnected: boolean, perm: Permissions | null) => void;
   };
   fullStateResolver: [(fullState: FullShowState) => void, () => void] | null;
+  journalResolver: [(entries: JournalEntry[]) => void, () => void] | null;
 } = {
   address: null,
   permission: null,
@@ -75,6 +81,7 @@ const websocketApiState: {
   assetListListeners: {},
   connectionStatusListeners: {},
   fullStateResolver: null,
+  journalResolver: null,
 };
 
 interface IWebsocketBackendAdapter extends IBackendAdapter {
@@ -186,6 +193,12 @@ export function useWebsocketApi(): IBackendAdapter {
             }
             websocketApiState.projectStatus = msg.data.projectStatus;
             break;
+          case 'journal':
+            if (websocketApiState.journalResolver != null) {
+              websocketApiState.journalResolver[0](msg.data);
+              websocketApiState.journalResolver = null;
+            }
+            break;
           case 'error': {
             let error: BackendError = {
               type: 'custom',
@@ -295,6 +308,16 @@ export function useWebsocketApi(): IBackendAdapter {
       });
     },
 
+    queryJournal(query: JournalQuery): Promise<JournalEntry[]> {
+      return new Promise((resolve, reject) => {
+        if (websocketApiState.journalResolver != null) {
+          websocketApiState.journalResolver[1]();
+        }
+        websocketApiState.journalResolver = [resolve, reject];
+        websocketApi.sendCommand({ type: 'requestJournal', ...query });
+      });
+    },
+
     getThirdPartyNotices: async function (): Promise<string> {
       return 'Not Available. To read third party notices, please use host app.';
     },
@@ -321,12 +344,40 @@ export function useWebsocketApi(): IBackendAdapter {
     processAsset: async function (path: string): Promise<void> {
       this.sendCommand({ type: 'assetProcessor', command: 'requestFileAssetData', path: path });
     },
+    runPreflight: async function (): Promise<void> {
+      this.sendCommand({ type: 'assetProcessor', command: 'requestPreflight' });
+    },
     setPlaybackCursor: async function (cueId: string | null): Promise<void> {
       this.sendCommand({ type: 'control', command: 'setPlaybackCursor', params: { cueId: cueId } });
     },
+    setListCursor: async function (listId: string, cueId: string | null): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'setListCursor',
+        params: { listId: listId, cueId: cueId },
+      });
+    },
     sendGo: async function (): Promise<void> {
       this.sendCommand({ type: 'control', command: 'go' });
     },
+    sendGoList: async function (listId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'goList', params: { listId: listId } });
+    },
+    sendGoFrom: async function (cueId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'goFrom', params: cueId });
+    },
+    sendCursorNext: async function (listId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'cursorNext', params: { listId: listId } });
+    },
+    sendCursorPrevious: async function (listId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'cursorPrevious', params: { listId: listId } });
+    },
+    sendCursorToFirst: async function (listId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'cursorToFirst', params: { listId: listId } });
+    },
+    fireCartSlot: async function (index: number): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'fireCartSlot', params: index });
+    },
     sendLoad: async function (cueId: string): Promise<void> {
       this.sendCommand({ type: 'control', command: 'load', params: cueId });
     },
@@ -348,6 +399,9 @@ export function useWebsocketApi(): IBackendAdapter {
     sendStopAll: async function (): Promise<void> {
       this.sendCommand({ type: 'control', command: 'stopAll' });
     },
+    sendPanic: async function (): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'panic' });
+    },
     sendSeekTo: async function (cueId: string, position: number): Promise<void> {
       this.sendCommand({ type: 'control', command: 'seekTo', params: [cueId, position] });
     },
@@ -368,6 +422,64 @@ export function useWebsocketApi(): IBackendAdapter {
         params: [cueId, { type: 'audio', action: 'setVolume', params: volume }],
       });
     },
+    sendSetPan: async function (cueId: string, pan: number): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'performAction',
+        params: [cueId, { type: 'audio', action: 'setPan', params: pan }],
+      });
+    },
+    sendSetRate: async function (cueId: string, rate: number): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'performAction',
+        params: [cueId, { type: 'audio', action: 'setRate', params: rate }],
+      });
+    },
+    sendSetStartEnd: async function (
+      cueId: string,
+      startTime: number | null,
+      endTime: number | null,
+    ): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'performAction',
+        params: [cueId, { type: 'audio', action: 'setStartEnd', params: { startTime, endTime } }],
+      });
+    },
+    sendSetFadeOut: async function (cueId: string, fadeParam: FadeParam | null): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'performAction',
+        params: [cueId, { type: 'audio', action: 'setFadeOut', params: fadeParam }],
+      });
+    },
+    sendReplaceEnvelope: async function (
+      cueId: string,
+      envelope: EnvelopeSegment[],
+    ): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'performAction',
+        params: [cueId, { type: 'audio', action: 'replaceEnvelope', params: envelope }],
+      });
+    },
+    sendSetMasterVolume: async function (
+      volume: number,
+      fadeParam: FadeParam | null,
+    ): Promise<void> {
+      this.sendCommand({
+        type: 'control',
+        command: 'setMasterVolume',
+        params: { volume: volume, fadeParam: fadeParam },
+      });
+    },
+    sendToggleArmed: async function (cueId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'toggleArmed', params: cueId });
+    },
+    sendStoreLiveState: async function (cueId: string): Promise<void> {
+      this.sendCommand({ type: 'control', command: 'storeLiveState', params: cueId });
+    },
 
     isModified: async function (): Promise<boolean> {
       return websocketApiState.projectStatus?.status !== 'saved';
@@ -375,7 +487,12 @@ export function useWebsocketApi(): IBackendAdapter {
     updateCue: async function (cue: Cue): Promise<void> {
       this.sendCommand({ type: 'model', command: 'updateCue', params: cue });
     },
-    addCue: async function (cue: Cue, targetId: string | null, toBefore: boolean): Promise<string> {
+    addCue: async function (
+      cue: Cue,
+      targetId: string | null,
+      toBefore: boolean,
+      listId: string | null = null,
+    ): Promise<string> {
       cue.id = v4();
       if (targetId != null) {
         if (toBefore) {
@@ -395,7 +512,13 @@ export function useWebsocketApi(): IBackendAdapter {
         this.sendCommand({
           type: 'model',
           command: 'addCue',
-          params: { cue: cue, position: { type: 'inside', target: null, index: null } },
+          params: {
+            cue: cue,
+            position:
+              listId != null
+                ? { type: 'list', listId: listId, index: null }
+                : { type: 'inside', target: null, index: null },
+          },
         });
       }
       return cue.id;
@@ -404,6 +527,7 @@ export function useWebsocketApi(): IBackendAdapter {
       cues: Cue[],
       targetId: string | null,
       toBefore: boolean,
+      listId: string | null = null,
     ): Promise<string[]> {
       const cueIds = cues.map((cue) => {
         cue.id = v4();
@@ -427,7 +551,13 @@ export function useWebsocketApi(): IBackendAdapter {
         this.sendCommand({
           type: 'model',
           command: 'addCues',
-          params: { cues: cues, position: { type: 'inside', target: null, index: null } },
+          params: {
+            cues: cues,
+            position:
+              listId != null
+                ? { type: 'list', listId: listId, index: null }
+                : { type: 'inside', target: null, index: null },
+          },
         });
       }
       return cueIds;
@@ -467,6 +597,32 @@ export function useWebsocketApi(): IBackendAdapter {
         params: { cueIds, position: position },
       });
     },
+    addCueList: async function (name: string): Promise<void> {
+      this.sendCommand({ type: 'model', command: 'addCueList', params: { name: name } });
+    },
+    renameCueList: async function (listId: string, name: string): Promise<void> {
+      this.sendCommand({
+        type: 'model',
+        command: 'renameCueList',
+        params: { listId: listId, name: name },
+      });
+    },
+    removeCueList: async function (listId: string, confirm_remove: boolean = true): Promise<void> {
+      if (confirm_remove) {
+        const removeOk = confirm(t('dialog.message.removeCueList'));
+        if (!removeOk) {
+          return;
+        }
+      }
+      this.sendCommand({ type: 'model', command: 'removeCueList', params: { listId: listId } });
+    },
+    setCartSlot: async function (index: number, slot: CartSlot | null): Promise<void> {
+      this.sendCommand({
+        type: 'model',
+        command: 'setCartSlot',
+        params: { index: index, slot: slot },
+      });
+    },
     renumberCues: async function (
       cues: string[],
       startFrom: number,
@@ -480,6 +636,12 @@ export function useWebsocketApi(): IBackendAdapter {
         params: { cues, startFrom, increment, prefix, suffix },
       });
     },
+    undo: async function (): Promise<void> {
+      this.sendCommand({ type: 'model', command: 'undo' });
+    },
+    redo: async function (): Promise<void> {
+      this.sendCommand({ type: 'model', command: 'redo' });
+    },
     updateModelName: async function (newName: string): Promise<void> {
       this.sendCommand({ type: 'model', command: 'updateModelName', params: newName });
     },
@@ -24,3 +24,24 @@ export const useHotkey = (key: MaybeRefOrGetter<string | null>, listener: Hotkey
     });
   });
 };
+
+// Binds a list of keys, passing the index of the pressed one to the listener.
+export const useHotkeyList = (
+  keys: MaybeRefOrGetter<(string | null)[]>,
+  listener: (index: number, event: KeyboardEvent) => void,
+) => {
+  watchEffect((onCleanup) => {
+    const bindings: { [key: string]: (event: KeyboardEvent) => void } = {};
+    toValue(keys).forEach((key, index) => {
+      if (key == null) return;
+      bindings[normalize(key)] = (event) => {
+        if (isUserTyping(event)) return;
+        listener(index, event);
+      };
+    });
+    const unlisten = tinykeys(window, bindings);
+    onCleanup(() => {
+      unlisten();
+    });
+  });
+};
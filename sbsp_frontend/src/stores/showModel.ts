@@ -10,6 +10,7 @@ import { useUiSettings } from './uiSettings';
 import { toRaw } from 'vue';
 import type { CueChain } from '../types/CueChain';
 import { useApi } from '../api';
+import { MAIN_CUE_LIST_ID } from '../utils';
 
 export type FlatCueEntry = {
   cue: Cue;
@@ -45,12 +46,12 @@ const recursiveCueCheck = (
       if (parent.params.mode.type === 'playlist') {
         if (index + 1 === list.length) {
           if (parent.params.mode.repeat) {
-            chain = { type: 'afterComplete', targetId: list[0] || null }; // targetId will not null
+            chain = { type: 'afterComplete', targetId: list[0] || null, postWait: 0 }; // targetId will not null
           } else {
             chain = { type: 'doNotChain' };
           }
         } else {
-          chain = { type: 'afterComplete', targetId: null };
+          chain = { type: 'afterComplete', targetId: null, postWait: 0 };
         }
       } else if (parent.params.mode.type === 'concurrency') {
         chain = { type: 'doNotChain' };
@@ -103,13 +104,19 @@ export const useShowModel = defineStore('showModel', {
       name: 'Untitled',
       cues: {},
       rootIds: [],
+      lists: [],
+      cart: [],
       settings: {
         general: {
           copyAssetsDestination: '.',
+          referencePolicy: 'clear',
+          goLockout: 0,
+          confirmRepeatGo: false,
         },
         audio: {
           monoOutput: false,
           lufsTarget: -14,
+          panicFadeDuration: 3,
         },
         remote: {
           lockCursorToSelection: false,
@@ -119,7 +126,7 @@ export const useShowModel = defineStore('showModel', {
   getters: {
     getCueById() {
       return (cue_id: string): Cue | undefined => {
-        return this.flatCueList.find((entry) => entry.cue.id === cue_id)?.cue;
+        return this.cues[cue_id];
       };
     },
     getSelectedCues(): Cue[] {
@@ -128,9 +135,16 @@ export const useShowModel = defineStore('showModel', {
         .filter((entry) => uiState.selectedRows.has(entry.cue.id))
         .map((entry) => entry.cue);
     },
-    flatCueList(state) {
+    activeRootIds(state): string[] {
       const uiState = useUiState();
-      return recursiveCueCheck(state.rootIds, state.cues, uiState.expandedRows);
+      if (uiState.activeListId === MAIN_CUE_LIST_ID) {
+        return state.rootIds;
+      }
+      return state.lists.find((list) => list.id === uiState.activeListId)?.rootIds ?? [];
+    },
+    flatCueList(state): FlatCueEntry[] {
+      const uiState = useUiState();
+      return recursiveCueCheck(this.activeRootIds, state.cues, uiState.expandedRows);
     },
     cueCount(state) {
       return Object.keys(state.cues).length;
@@ -141,7 +155,9 @@ export const useShowModel = defineStore('showModel', {
       this.name = newModel.name;
       this.cues = newModel.cues;
       this.rootIds = newModel.rootIds;
+      this.lists = newModel.lists;
       this.settings = newModel.settings;
+      this.cart = newModel.cart;
     },
     addEmptyAudioCue() {
       const uiState = useUiState();
@@ -156,7 +172,9 @@ export const useShowModel = defineStore('showModel', {
             if (newCue.params.type === 'audio' && target != null) {
               newCue.params.target = target;
             }
-            api.addCue(newCue, uiState.selected, false).catch((e) => console.error(e));
+            api
+              .addCue(newCue, uiState.selected, false, uiState.activeListId)
+              .catch((e) => console.error(e));
           } else if (assets.length > 1) {
             const newCues = [] as Cue[];
             for (const asset_path of assets) {
@@ -166,7 +184,9 @@ export const useShowModel = defineStore('showModel', {
               }
               newCues.push(newCue);
             }
-            api.addCues(newCues, uiState.selected, false).catch((e) => console.error(e));
+            api
+              .addCues(newCues, uiState.selected, false, uiState.activeListId)
+              .catch((e) => console.error(e));
           }
         })
         .catch((e) => console.error(e));
@@ -176,7 +196,9 @@ export const useShowModel = defineStore('showModel', {
       const uiSettings = useUiSettings();
       const api = useApi();
       const newCue = structuredClone(toRaw(uiSettings.settings.template.wait)) as Cue;
-      api.addCue(newCue, uiState.selected, false).catch((e) => console.error(e));
+      api
+        .addCue(newCue, uiState.selected, false, uiState.activeListId)
+        .catch((e) => console.error(e));
     },
     addEmptyFadeCue() {
       const uiState = useUiState();
@@ -190,7 +212,9 @@ export const useShowModel = defineStore('showModel', {
           (targetCue.params.type === 'audio' || targetCue.params.type === 'group')
         ) {
           newCue.params.target = uiState.selected;
-          api.addCue(newCue, uiState.selected, false).catch((e) => console.error(e));
+          api
+            .addCue(newCue, uiState.selected, false, uiState.activeListId)
+            .catch((e) => console.error(e));
         }
       }
     },
@@ -226,7 +250,12 @@ export const useShowModel = defineStore('showModel', {
       ) {
         newCue.params.target = uiState.selected;
         api
-          .addCue(newCue, uiState.selected, type === 'load' || type === 'start')
+          .addCue(
+            newCue,
+            uiState.selected,
+            type === 'load' || type === 'start',
+            uiState.activeListId,
+          )
           .catch((e) => console.error(e));
       }
     },
@@ -237,7 +266,7 @@ export const useShowModel = defineStore('showModel', {
       const newCue = structuredClone(toRaw(uiSettings.settings.template.group)) as Cue;
       if (newCue.params.type === 'group') {
         api
-          .addCue(newCue, uiState.selected, false)
+          .addCue(newCue, uiState.selected, false, uiState.activeListId)
           .then((id) => {
             if (uiState.selectedRows.size > 0) {
               api
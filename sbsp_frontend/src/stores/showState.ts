@@ -2,20 +2,27 @@
 // Copyright (c) 2025 Keinsleif (https://github.com/Keinsleif)
 
 import { defineStore } from 'pinia';
-import { ref } from 'vue';
+import { computed, ref } from 'vue';
 import type { SyncData } from '../types/SyncData';
 import type { ActiveCue } from '../types/ActiveCue';
 import type { PlaybackStatus } from '../types/PlaybackStatus';
 import type { CueStatusEventParam } from '../types/CueStatusEventParam';
 import type { ShowState } from '../types/ShowState';
+import { useUiState } from './uiState';
+import { MAIN_CUE_LIST_ID } from '../utils';
 
 export const useShowState = defineStore('showState', () => {
-  const playbackCursor = ref<string | null>(null);
+  const uiState = useUiState();
+  const mainCursor = ref<string | null>(null);
+  const listCursors = ref<{ [listId in string]?: string }>({});
+  // cursor of the cue list shown in the UI
+  const playbackCursor = computed(() => cursorOf(uiState.activeListId));
   const activeCues = ref<{ [id: string]: ActiveCue }>({});
   const syncedData = ref<{
     [cueId in string]: { position: number; status: PlaybackStatus; lastSyncedAt: number };
   }>({});
   const latency = ref<number>(0);
+  const masterVolume = ref<number>(0);
 
   const handleSyncEvent = (data: SyncData) => {
     const lastSyncedAt = performance.now();
@@ -42,6 +49,8 @@ export const useShowState = defineStore('showState', () => {
     const lastSyncedAt = performance.now();
 
     updatePlaybackCursor(state.playbackCursor);
+    listCursors.value = { ...state.listCursors };
+    masterVolume.value = state.masterVolume;
 
     const newSyncedData: {
       [cueId in string]: { position: number; status: PlaybackStatus; lastSyncedAt: number };
@@ -62,7 +71,21 @@ export const useShowState = defineStore('showState', () => {
   };
 
   const updatePlaybackCursor = (cursor: string | null) => {
-    playbackCursor.value = cursor;
+    mainCursor.value = cursor;
+  };
+
+  const updateListCursor = (listId: string, cursor: string | null) => {
+    if (listId === MAIN_CUE_LIST_ID) {
+      mainCursor.value = cursor;
+    } else if (cursor != null) {
+      listCursors.value[listId] = cursor;
+    } else {
+      delete listCursors.value[listId];
+    }
+  };
+
+  const cursorOf = (listId: string): string | null => {
+    return listId === MAIN_CUE_LIST_ID ? mainCursor.value : (listCursors.value[listId] ?? null);
   };
 
   const handleCueStateEvent = (data: CueStatusEventParam) => {
@@ -123,6 +146,29 @@ export const useShowState = defineStore('showState', () => {
       case 'preWaitCompleted':
         // start cue will automatically triggered in backend.
         break;
+      case 'postWaitStarted':
+        // cue may be still playing when chained after start.
+        if (syncedData.value[data.cueId] == null) {
+          syncedData.value[data.cueId] = {
+            position: 0.0,
+            status: 'postWaiting',
+            lastSyncedAt,
+          };
+          activeCues.value[data.cueId] = {
+            cueId: data.cueId,
+            position: 0.0,
+            duration: data.duration,
+            status: 'postWaiting',
+            params: { type: 'none' },
+          };
+        }
+        break;
+      case 'postWaitCompleted':
+        if (syncedData.value[data.cueId]?.status === 'postWaiting') {
+          delete syncedData.value[data.cueId];
+          delete activeCues.value[data.cueId];
+        }
+        break;
       case 'started': {
         syncedData.value[data.cueId] = {
           position: data.position,
@@ -216,10 +262,13 @@ export const useShowState = defineStore('showState', () => {
       let position;
 
       if (
-        (['preWaiting', 'playing', 'stopping'] as PlaybackStatus[]).includes(lastSyncCue.status) &&
+        (
+          ['preWaiting', 'postWaiting', 'playing', 'stopping'] as PlaybackStatus[]
+        ).includes(lastSyncCue.status) &&
         activeCue.duration > 0
       ) {
-        const elapsed = (performance.now() - lastSyncCue.lastSyncedAt) / 1000;
+        const rate = activeCue.params.type === 'audio' ? activeCue.params.rate : 1;
+        const elapsed = ((performance.now() - lastSyncCue.lastSyncedAt) / 1000) * rate;
         if (activeCue.params.type === 'audio' && activeCue.params.repeating) {
           position = (lastSyncCue.position + latency.value / 2 + elapsed) % activeCue.duration;
         } else {
@@ -249,10 +298,13 @@ export const useShowState = defineStore('showState', () => {
       return null;
     }
     if (
-      (['preWaiting', 'playing', 'stopping'] as PlaybackStatus[]).includes(lastSyncCue.status) &&
+      (
+        ['preWaiting', 'postWaiting', 'playing', 'stopping'] as PlaybackStatus[]
+      ).includes(lastSyncCue.status) &&
       activeCue.duration > 0
     ) {
-      const elapsed = (performance.now() - lastSyncCue.lastSyncedAt) / 1000;
+      const rate = activeCue.params.type === 'audio' ? activeCue.params.rate : 1;
+      const elapsed = ((performance.now() - lastSyncCue.lastSyncedAt) / 1000) * rate;
       if (activeCue.params.type === 'audio' && activeCue.params.repeating) {
         return (lastSyncCue.position + latency.value / 2 + elapsed) % activeCue.duration;
       } else {
@@ -266,9 +318,12 @@ export const useShowState = defineStore('showState', () => {
   return {
     playbackCursor,
     activeCues,
+    masterVolume,
     update,
     handleSyncEvent,
     updatePlaybackCursor,
+    updateListCursor,
+    cursorOf,
     calculatePosition,
     handleCueStateEvent,
     getPosition,
@@ -13,6 +13,8 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
     lockCursorToSelection: true,
     copyAssetsWhenAdd: false,
     seekAmount: 5,
+    backupCount: 5,
+    autosaveInterval: 60,
   },
   appearance: {
     language: null,
@@ -28,8 +30,13 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       resumeAll: ']',
       stop: 'S',
       stopAll: 'Escape',
+      panic: null,
+      cursorNext: null,
+      cursorPrevious: null,
+      cursorToFirst: null,
       seekForward: null,
       seekBackward: null,
+      cart: [],
     },
     audioAction: {
       toggleRepeat: 'R',
@@ -42,7 +49,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -59,6 +68,7 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
         repeat: false,
         soundType: 'streaming',
         envelope: [],
+        originalPath: null,
       },
     },
     wait: {
@@ -67,7 +77,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -83,7 +95,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -107,7 +121,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -123,7 +139,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -140,7 +158,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -156,7 +176,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -172,7 +194,9 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
       name: null,
       notes: '',
       color: 'none',
+      armed: true,
       preWait: 0,
+      timelineOffset: 0,
       chain: {
         type: 'doNotChain',
       },
@@ -184,6 +208,7 @@ export const DEFAULT_SETTINGS: GlobalHostSettings | GlobalRemoteSettings = {
           repeat: true,
         },
         children: [],
+        volume: 0,
       },
     },
   },
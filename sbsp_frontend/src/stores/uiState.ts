@@ -3,7 +3,7 @@
 
 import { defineStore } from 'pinia';
 import { ref } from 'vue';
-import { getLockCursorToSelection, PERMISSIONS } from '../utils.ts';
+import { getLockCursorToSelection, MAIN_CUE_LIST_ID, PERMISSIONS } from '../utils.ts';
 import { useApi } from '../api/index.ts';
 import { useShowModel } from './showModel.ts';
 import type { Permissions } from '../types/Permissions.ts';
@@ -13,12 +13,13 @@ export const useUiState = defineStore(
   () => {
     const permission = ref<Permissions>(0b0111);
     const mode = ref<'edit' | 'run' | 'view'>('edit');
+    const activeListId = ref<string>(MAIN_CUE_LIST_ID);
     const selected = ref<string | null>(null);
     const selectedRows = ref<Set<string>>(new Set());
     const expandedRows = ref<string[]>([]);
     const preWaitDisplayMode = ref<'elapsed' | 'remain'>('elapsed');
     const durationDisplayMode = ref<'elapsed' | 'remain'>('elapsed');
-    const sideBarTab = ref<'activeCues' | 'meter'>('activeCues');
+    const sideBarTab = ref<'activeCues' | 'meter' | 'cart'>('activeCues');
     const isRightSidebarOpen = ref(true);
     const isRenumberCueDialogOpen = ref(false);
     const isUpdateDialogOpen = ref(false);
@@ -32,16 +33,25 @@ export const useUiState = defineStore(
     const isEnvelopeVisible = ref(false);
     const scaleWaveform = ref(true);
     const lastUpdateCheckDate = ref<number>(0);
+    const canUndo = ref(false);
+    const canRedo = ref(false);
 
     const setPlaybackCursor = (id: string | null) => {
       const api = useApi();
       if (getLockCursorToSelection()) {
-        api.setPlaybackCursor(id).catch((e) => {
+        api.setListCursor(activeListId.value, id).catch((e) => {
           console.error('Failed to set cursor. ' + e);
         });
       }
     };
 
+    const setActiveList = (listId: string) => {
+      if (activeListId.value !== listId) {
+        activeListId.value = listId;
+        resetSelected();
+      }
+    };
+
     const resetSelected = () => {
       selected.value = null;
       selectedRows.value.clear();
@@ -155,6 +165,7 @@ export const useUiState = defineStore(
     return {
       permission,
       mode,
+      activeListId,
       selected,
       selectedRows,
       expandedRows,
@@ -174,8 +185,11 @@ export const useUiState = defineStore(
       isEnvelopeVisible,
       scaleWaveform,
       lastUpdateCheckDate,
+      canUndo,
+      canRedo,
       setPermission,
       setPlaybackCursor,
+      setActiveList,
       resetSelected,
       clearSelected,
       setSelected,
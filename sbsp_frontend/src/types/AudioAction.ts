@@ -1,4 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Decibels } from "./Decibels";
+import type { EnvelopeSegment } from "./EnvelopeSegment";
+import type { FadeParam } from "./FadeParam";
 
-export type AudioAction = { "action": "toggleRepeat" } | { "action": "setVolume", "params": Decibels };
+export type AudioAction = { "action": "toggleRepeat" } | { "action": "setVolume", "params": Decibels } | { "action": "setPan", "params": number } | { "action": "setRate", "params": number } | { "action": "setStartEnd", "params": { startTime: number | null, endTime: number | null, } } | { "action": "setFadeOut", "params": FadeParam | null } | { "action": "replaceEnvelope", "params": Array<EnvelopeSegment> };
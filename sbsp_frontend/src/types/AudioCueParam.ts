@@ -4,4 +4,8 @@ import type { EnvelopeSegment } from "./EnvelopeSegment";
 import type { FadeParam } from "./FadeParam";
 import type { SoundType } from "./SoundType";
 
-export type AudioCueParam = { target: string, startTime: number | null, fadeInParam: FadeParam | null, endTime: number | null, fadeOutParam: FadeParam | null, volume: Decibels, pan: number, repeat: boolean, soundType: SoundType, envelope: Array<EnvelopeSegment>, };
+export type AudioCueParam = { target: string, startTime: number | null, fadeInParam: FadeParam | null, endTime: number | null, fadeOutParam: FadeParam | null, volume: Decibels, pan: number, repeat: boolean, soundType: SoundType, envelope: Array<EnvelopeSegment>, 
+/**
+ * Where the file was imported from, when it was copied into the project.
+ */
+originalPath: string | null, };
@@ -1,4 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Decibels } from "./Decibels";
+import type { EnvelopeSegment } from "./EnvelopeSegment";
+import type { FadeParam } from "./FadeParam";
 
-export type AudioStateParam = { repeating: boolean, volume: Decibels, };
+export type AudioStateParam = { repeating: boolean, volume: Decibels, pan: number, rate: number, startTime: number | null, endTime: number | null, fadeOutParam: FadeParam | null, envelope: Array<EnvelopeSegment>, };
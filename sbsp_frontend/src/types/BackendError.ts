@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type BackendError = { "type": "saveToFile", path: string, message: string, } | { "type": "loadFromFile", path: string, message: string, } | { "type": "exportToFolder", path: string, message: string, } | { "type": "cueEdit", message: string, } | { "type": "custom", id: number, message: string, };
+export type BackendError = { "type": "saveToFile", path: string, message: string, } | { "type": "loadFromFile", path: string, message: string, } | { "type": "exportToFolder", path: string, message: string, } | { "type": "importCueSheet", path: string, message: string, } | { "type": "exportCueSheet", path: string, message: string, } | { "type": "exportBundle", path: string, message: string, } | { "type": "relinkAssets", path: string, message: string, } | { "type": "cueEdit", message: string, } | { "type": "custom", id: number, message: string, };
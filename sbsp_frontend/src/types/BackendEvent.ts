@@ -1,12 +1,28 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AssetData } from "./AssetData";
+import type { AssetImportEntry } from "./AssetImportEntry";
 import type { AssetMetadata } from "./AssetMetadata";
+import type { AssetRelink } from "./AssetRelink";
 import type { BackendError } from "./BackendError";
+import type { CartSlot } from "./CartSlot";
 import type { Cue } from "./Cue";
 import type { CueStatusEventParam } from "./CueStatusEventParam";
+import type { Decibels } from "./Decibels";
+import type { GoSuppressedReason } from "./GoSuppressedReason";
+import type { MissingAsset } from "./MissingAsset";
+import type { NamedCueList } from "./NamedCueList";
+import type { PreflightWarning } from "./PreflightWarning";
 import type { ProjectType } from "./ProjectType";
 import type { ShowModel } from "./ShowModel";
 import type { ShowSettings } from "./ShowSettings";
 import type { SyncData } from "./SyncData";
 
-export type BackendEvent = { "type": "cueStatus", "param": CueStatusEventParam } | { "type": "playbackCursorMoved", "param": { cueId: string | null, } } | { "type": "syncState", "param": SyncData } | { "type": "showModelLoaded", "param": { model: ShowModel, projectType: ProjectType, path: string, } } | { "type": "showModelSaved", "param": { projectType: ProjectType, path: string, } } | { "type": "showModelReset", "param": { model: ShowModel, } } | { "type": "cueRemoved", "param": { cueIds: Array<string>, } } | { "type": "cueListUpdated", "param": { cues: { [key in string]: Cue }, rootIds: Array<string>, } } | { "type": "settingsUpdated", "param": { newSettings: ShowSettings, } } | { "type": "modelNameUpdated", "param": { newName: string, } } | { "type": "assetMetadata", "param": { path: string, data: AssetMetadata, } } | { "type": "assetResult", "param": { path: string, data: { Ok : AssetData } | { Err : string }, } } | { "type": "operationFailed", "param": { error: BackendError, } };
+export type BackendEvent = { "type": "cueStatus", "param": CueStatusEventParam } | { "type": "playbackCursorMoved", "param": { cueId: string | null, } } | { "type": "listCursorMoved", "param": { listId: string, cueId: string | null, } } | { "type": "syncState", "param": SyncData } | { "type": "masterVolumeChanged", "param": { volume: Decibels, } } | { "type": "goSuppressed", "param": { listId: string, cueId: string, reason: GoSuppressedReason, } } | { "type": "showModelLoaded", "param": { model: ShowModel, projectType: ProjectType, path: string, } } | { "type": "showModelSaved", "param": { projectType: ProjectType, path: string, } } | { "type": "showModelReset", "param": { model: ShowModel, } } | { "type": "cueRemoved", "param": { cueIds: Array<string>, 
+/**
+ * Remaining cues whose references to a removed cue were cleared.
+ */
+affectedCueIds: Array<string>, } } | { "type": "cueListUpdated", "param": { cues: { [key in string]: Cue }, rootIds: Array<string>, 
+/**
+ * Cue lists besides the main one. Their cues are stored in `cues` too.
+ */
+lists: Array<NamedCueList>, } } | { "type": "settingsUpdated", "param": { newSettings: ShowSettings, } } | { "type": "modelNameUpdated", "param": { newName: string, } } | { "type": "cartUpdated", "param": { cart: Array<CartSlot | null>, } } | { "type": "recoveryAvailable", "param": { path: string, projectPath: string | null, } } | { "type": "historyUpdated", "param": { canUndo: boolean, canRedo: boolean, } } | { "type": "assetsImported", "param": { entries: Array<AssetImportEntry>, } } | { "type": "assetsMissing", "param": { missing: Array<MissingAsset>, } } | { "type": "assetsRelinked", "param": { relinked: Array<AssetRelink>, missing: Array<MissingAsset>, } } | { "type": "preflightReport", "param": { warnings: Array<PreflightWarning>, } } | { "type": "bundleExported", "param": { path: string, } } | { "type": "cueSheetExported", "param": { path: string, } } | { "type": "cueSheetImported", "param": { path: string, cueCount: number, unresolvedFiles: Array<string>, warnings: Array<string>, } } | { "type": "assetMetadata", "param": { path: string, data: AssetMetadata, } } | { "type": "assetResult", "param": { path: string, data: { Ok : AssetData } | { Err : string }, } } | { "type": "operationFailed", "param": { error: BackendError, } };
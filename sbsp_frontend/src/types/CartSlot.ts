@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CartSlotMode } from "./CartSlotMode";
+
+/**
+ * A cart slot, firing its cue without touching the playback cursor.
+ */
+export type CartSlot = { cueId: string, mode: CartSlotMode, };
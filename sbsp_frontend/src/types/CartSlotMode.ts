@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What firing a slot does while its cue is already running.
+ */
+export type CartSlotMode = "toggle" | "restart" | "latch";
@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { CueAction } from "./CueAction";
+import type { CueRef } from "./CueRef";
+import type { Decibels } from "./Decibels";
+import type { FadeParam } from "./FadeParam";
 
-export type ControllerCommand = { "command": "go" } | { "command": "load", "params": string } | { "command": "pause", "params": string } | { "command": "resume", "params": string } | { "command": "stop", "params": string } | { "command": "seekTo", "params": [string, number] } | { "command": "seekBy", "params": [string, number] } | { "command": "pauseAll" } | { "command": "resumeAll" } | { "command": "stopAll" } | { "command": "performAction", "params": [string, CueAction] } | { "command": "setPlaybackCursor", "params": { cueId: string | null, } };
+export type ControllerCommand = { "command": "go" } | { "command": "goList", "params": { listId: string, } } | { "command": "load", "params": CueRef } | { "command": "pause", "params": CueRef } | { "command": "resume", "params": CueRef } | { "command": "stop", "params": CueRef } | { "command": "seekTo", "params": [CueRef, number] } | { "command": "seekBy", "params": [CueRef, number] } | { "command": "pauseAll" } | { "command": "resumeAll" } | { "command": "stopAll" } | { "command": "panic" } | { "command": "performAction", "params": [CueRef, CueAction] } | { "command": "fireCartSlot", "params": number } | { "command": "setPlaybackCursor", "params": { cueId: CueRef | null, } } | { "command": "setListCursor", "params": { listId: string, cueId: CueRef | null, } } | { "command": "setArmed", "params": { cueId: CueRef, armed: boolean, } } | { "command": "toggleArmed", "params": CueRef } | { "command": "storeLiveState", "params": CueRef } | { "command": "cursorNext", "params": { listId: string | null, } } | { "command": "cursorPrevious", "params": { listId: string | null, } } | { "command": "cursorToFirst", "params": { listId: string | null, } } | { "command": "goFrom", "params": CueRef } | { "command": "setMasterVolume", "params": { volume: Decibels, fadeParam: FadeParam | null, } };
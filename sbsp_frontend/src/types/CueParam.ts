@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AudioCueParam } from "./AudioCueParam";
+import type { Decibels } from "./Decibels";
 import type { FadeCueParam } from "./FadeCueParam";
 import type { GroupMode } from "./GroupMode";
 import type { LoadCueParam } from "./LoadCueParam";
@@ -8,4 +9,8 @@ import type { StartCueParam } from "./StartCueParam";
 import type { StopCueParam } from "./StopCueParam";
 import type { WaitCueParam } from "./WaitCueParam";
 
-export type CueParam = { "type": "audio" } & AudioCueParam | { "type": "wait" } & WaitCueParam | { "type": "fade" } & FadeCueParam | { "type": "start" } & StartCueParam | { "type": "stop" } & StopCueParam | { "type": "pause" } & PauseCueParam | { "type": "load" } & LoadCueParam | { "type": "group", children: Array<string>, mode: GroupMode, };
+export type CueParam = { "type": "audio" } & AudioCueParam | { "type": "wait" } & WaitCueParam | { "type": "fade" } & FadeCueParam | { "type": "start" } & StartCueParam | { "type": "stop" } & StopCueParam | { "type": "pause" } & PauseCueParam | { "type": "load" } & LoadCueParam | { "type": "group", children: Array<string>, mode: GroupMode, 
+/**
+ * Trim added to the volume of every audio cue inside the group, nested groups included.
+ */
+volume: Decibels, };
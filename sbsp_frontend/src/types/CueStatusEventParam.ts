@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { StateParam } from "./StateParam";
 
-export type CueStatusEventParam = { "type": "triggered", cueId: string, } | { "type": "loaded", cueId: string, position: number, duration: number, } | { "type": "preWaitStarted", cueId: string, duration: number, } | { "type": "preWaitPaused", cueId: string, position: number, } | { "type": "preWaitResumed", cueId: string, } | { "type": "preWaitCompleted", cueId: string, } | { "type": "started", cueId: string, position: number, duration: number, params: StateParam, } | { "type": "paused", cueId: string, position: number, } | { "type": "resumed", cueId: string, } | { "type": "stopping", cueId: string, } | { "type": "stopped", cueId: string, } | { "type": "seeked", cueId: string, position: number, } | { "type": "completed", cueId: string, } | { "type": "error", cueId: string, error: string, } | { "type": "stateParamUpdated", cueId: string, params: StateParam, };
+export type CueStatusEventParam = { "type": "triggered", cueId: string, } | { "type": "loaded", cueId: string, position: number, duration: number, } | { "type": "preWaitStarted", cueId: string, duration: number, } | { "type": "preWaitPaused", cueId: string, position: number, } | { "type": "preWaitResumed", cueId: string, } | { "type": "preWaitCompleted", cueId: string, } | { "type": "postWaitStarted", cueId: string, duration: number, } | { "type": "postWaitCompleted", cueId: string, } | { "type": "started", cueId: string, position: number, duration: number, params: StateParam, } | { "type": "paused", cueId: string, position: number, } | { "type": "resumed", cueId: string, } | { "type": "stopping", cueId: string, } | { "type": "stopped", cueId: string, } | { "type": "seeked", cueId: string, position: number, } | { "type": "completed", cueId: string, } | { "type": "error", cueId: string, error: string, } | { "type": "stateParamUpdated", cueId: string, params: StateParam, };
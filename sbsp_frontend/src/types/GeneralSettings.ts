@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type GeneralSettings = { advanceCursorWhenGo: boolean, lockCursorToSelection: boolean, copyAssetsWhenAdd: boolean, seekAmount: number, };
+export type GeneralSettings = { advanceCursorWhenGo: boolean, lockCursorToSelection: boolean, copyAssetsWhenAdd: boolean, seekAmount: number, backupCount: number, autosaveInterval: number, };
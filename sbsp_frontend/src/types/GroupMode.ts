@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type GroupMode = { "type": "playlist", repeat: boolean, } | { "type": "concurrency" } | { "type": "startFirst", enter: boolean, };
+export type GroupMode = { "type": "playlist", repeat: boolean, } | { "type": "concurrency" } | { "type": "startFirst", enter: boolean, } | { "type": "timeline" };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type InsertPosition = { "type": "before", target: string, } | { "type": "after", target: string, } | { "type": "inside", target: string | null, index: number | null, };
+export type InsertPosition = { "type": "before", target: string, } | { "type": "after", target: string, } | { "type": "inside", target: string | null, index: number | null, } | { "type": "list", listId: string, index: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JournalEntryKind } from "./JournalEntryKind";
+
+export type JournalEntry = { 
+/**
+ * Local wall-clock time in RFC 3339.
+ */
+timestamp: string, kind: JournalEntryKind, cueId: string | null, cueNumber: string | null, message: string | null, };
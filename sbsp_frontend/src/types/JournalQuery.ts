@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JournalEntryKind } from "./JournalEntryKind";
+
+export type JournalQuery = { 
+/**
+ * Kinds to include. Empty includes every kind.
+ */
+kinds: Array<JournalEntryKind>, cueId: string | null, 
+/**
+ * Keeps only the most recent entries.
+ */
+limit: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An audio cue whose target file cannot be found.
+ */
+export type MissingAsset = { cueId: string, target: string, };
@@ -1,6 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CartSlot } from "./CartSlot";
 import type { Cue } from "./Cue";
+import type { CueSheetFormat } from "./CueSheetFormat";
 import type { InsertPosition } from "./InsertPosition";
 import type { ShowSettings } from "./ShowSettings";
 
-export type ModelCommand = { "command": "updateCue", "params": Cue } | { "command": "addCue", "params": { cue: Cue, position: InsertPosition, } } | { "command": "addCues", "params": { cues: Array<Cue>, position: InsertPosition, } } | { "command": "removeCue", "params": { cueId: string, } } | { "command": "removeCues", "params": { cueIds: Array<string>, } } | { "command": "moveCue", "params": { cueId: string, position: InsertPosition, } } | { "command": "moveCues", "params": { cueIds: Array<string>, position: InsertPosition, } } | { "command": "renumberCues", "params": { cues: Array<string>, startFrom: number, increment: number, prefix: string | null, suffix: string | null, } } | { "command": "updateModelName", "params": string } | { "command": "updateSettings", "params": ShowSettings } | { "command": "reset" } | { "command": "save" } | { "command": "saveToFile", "params": string } | { "command": "exportToFolder", "params": string } | { "command": "loadFromFile", "params": string };
+export type ModelCommand = { "command": "updateCue", "params": Cue } | { "command": "addCue", "params": { cue: Cue, position: InsertPosition, } } | { "command": "addCues", "params": { cues: Array<Cue>, position: InsertPosition, } } | { "command": "removeCue", "params": { cueId: string, } } | { "command": "removeCues", "params": { cueIds: Array<string>, } } | { "command": "moveCue", "params": { cueId: string, position: InsertPosition, } } | { "command": "moveCues", "params": { cueIds: Array<string>, position: InsertPosition, } } | { "command": "addCueList", "params": { name: string, } } | { "command": "renameCueList", "params": { listId: string, name: string, } } | { "command": "removeCueList", "params": { listId: string, } } | { "command": "setCartSlot", "params": { index: number, slot: CartSlot | null, } } | { "command": "renumberCues", "params": { cues: Array<string>, startFrom: number, increment: number, prefix: string | null, suffix: string | null, } } | { "command": "importCueSheet", "params": { path: string, position: InsertPosition, } } | { "command": "relinkAssets", "params": { folder: string, } } | { "command": "updateModelName", "params": string } | { "command": "updateSettings", "params": ShowSettings } | { "command": "undo" } | { "command": "redo" } | { "command": "checkRecovery" } | { "command": "recover" } | { "command": "discardRecovery" } | { "command": "reset" } | { "command": "save" } | { "command": "saveToFile", "params": string } | { "command": "exportToFolder", "params": string } | { "command": "exportCueSheet", "params": { path: string, format: CueSheetFormat, } } | { "command": "loadFromFile", "params": string } | { "command": "exportBundle", "params": string } | { "command": "openBundle", "params": { path: string, destination: string | null, } };
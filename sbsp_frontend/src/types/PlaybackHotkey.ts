@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type PlaybackHotkey = { go: string | null, load: string | null, pauseAndResume: string | null, pauseAll: string | null, resumeAll: string | null, stop: string | null, stopAll: string | null, seekForward: string | null, seekBackward: string | null, };
+export type PlaybackHotkey = { go: string | null, load: string | null, pauseAndResume: string | null, pauseAll: string | null, resumeAll: string | null, stop: string | null, stopAll: string | null, panic: string | null, cursorNext: string | null, cursorPrevious: string | null, cursorToFirst: string | null, seekForward: string | null, seekBackward: string | null, 
+/**
+ * Keys firing cart slots, by slot index.
+ */
+cart: Array<string | null>, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PreflightIssue = { "type": "missingAsset", target: string, } | { "type": "danglingTarget", target: string, } | { "type": "danglingChainTarget", target: string, } | { "type": "chainLoop", cueIds: Array<string>, } | { "type": "emptyNumber" } | { "type": "duplicateNumber", number: string, } | { "type": "fadeLongerThanTarget", fadeDuration: number, targetDuration: number, } | { "type": "clipping", level: number, };
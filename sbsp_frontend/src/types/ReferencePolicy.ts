@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What removing a cue does to other cues that target it.
+ */
+export type ReferencePolicy = "clear" | "refuse";
@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ShowAudioSettings = { monoOutput: boolean, lufsTarget: number, };
+export type ShowAudioSettings = { monoOutput: boolean, lufsTarget: number, 
+/**
+ * Seconds `Panic` takes to fade all audio out.
+ */
+panicFadeDuration: number, };
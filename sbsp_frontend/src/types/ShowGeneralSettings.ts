@@ -1,3 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReferencePolicy } from "./ReferencePolicy";
 
-export type ShowGeneralSettings = { copyAssetsDestination: string, };
+export type ShowGeneralSettings = { copyAssetsDestination: string, referencePolicy: ReferencePolicy, 
+/**
+ * Seconds after a GO during which further GO presses are ignored. 0 disables the lockout.
+ */
+goLockout: number, 
+/**
+ * Ask for a second GO before restarting a cue that is still running.
+ */
+confirmRepeatGo: boolean, };
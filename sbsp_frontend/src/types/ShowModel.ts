@@ -1,5 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CartSlot } from "./CartSlot";
 import type { Cue } from "./Cue";
+import type { NamedCueList } from "./NamedCueList";
 import type { ShowSettings } from "./ShowSettings";
 
-export type ShowModel = { name: string, settings: ShowSettings, cues: { [key in string]: Cue }, rootIds: Array<string>, };
+export type ShowModel = { name: string, settings: ShowSettings, 
+/**
+ * Cart slots by index. `None` is an empty slot.
+ */
+cart: Array<CartSlot | null>, cues: { [key in string]: Cue }, rootIds: Array<string>, 
+/**
+ * Cue lists besides the main one. Their cues are stored in `cues` too.
+ */
+lists: Array<NamedCueList>, };
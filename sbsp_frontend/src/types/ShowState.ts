@@ -1,4 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ActiveCue } from "./ActiveCue";
+import type { Decibels } from "./Decibels";
 
-export type ShowState = { playbackCursor: string | null, activeCues: { [key in string]: ActiveCue }, };
+export type ShowState = { 
+/**
+ * Cursor of the main cue list.
+ */
+playbackCursor: string | null, 
+/**
+ * Cursors of the other cue lists, keyed by list id.
+ */
+listCursors: { [key in string]: string }, activeCues: { [key in string]: ActiveCue }, masterVolume: Decibels, };
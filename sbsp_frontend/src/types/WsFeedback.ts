@@ -3,7 +3,8 @@ import type { AuthInfo } from "./AuthInfo";
 import type { BackendEvent } from "./BackendEvent";
 import type { FileList } from "./FileList";
 import type { FullShowState } from "./FullShowState";
+import type { JournalEntry } from "./JournalEntry";
 import type { Permissions } from "./Permissions";
 import type { WsError } from "./WsError";
 
-export type WsFeedback = { "type": "hello", "data": { auth: AuthInfo, } } | { "type": "authenticated", "data": { perm: Permissions, } } | { "type": "event", "data": BackendEvent } | { "type": "assetList", "data": Array<FileList> } | { "type": "fullShowState", "data": FullShowState } | { "type": "error", "data": WsError };
+export type WsFeedback = { "type": "hello", "data": { auth: AuthInfo, } } | { "type": "authenticated", "data": { perm: Permissions, } } | { "type": "event", "data": BackendEvent } | { "type": "assetList", "data": Array<FileList> } | { "type": "fullShowState", "data": FullShowState } | { "type": "journal", "data": Array<JournalEntry> } | { "type": "error", "data": WsError };
@@ -22,6 +22,12 @@ import {
 } from '@mdi/js';
 import type { Permissions } from './types/Permissions';
 
+// Id addressing the main cue list in cursor and go commands.
+export const MAIN_CUE_LIST_ID = '00000000-0000-0000-0000-000000000000';
+
+// Slots shown in the cart grid and the hotkey settings.
+export const CART_SLOT_COUNT = 16;
+
 export const secondsToFormat = (source_seconds: number | null): string => {
   if (source_seconds == null || isNaN(source_seconds)) {
     return '--:--.--';
@@ -13,6 +13,15 @@ import { openPath } from '@tauri-apps/plugin-opener';
 
 type MenuItemHolder = MenuItem | PredefinedMenuItem | null;
 
+const isTextInputFocused = () => {
+  const element = document.activeElement;
+  return (
+    element instanceof HTMLTextAreaElement ||
+    (element instanceof HTMLInputElement && element.type !== 'checkbox') ||
+    (element instanceof HTMLElement && element.isContentEditable)
+  );
+};
+
 export const createWindowMenu = () => {
   const api = useApi();
   if (__IS_WEBSOCKET__) return;
@@ -26,12 +35,20 @@ export const createWindowMenu = () => {
     file: {
       new: null as MenuItemHolder,
       open: null as MenuItemHolder,
+      import: null as MenuItemHolder,
+      relinkAssets: null as MenuItemHolder,
+      openBundle: null as MenuItemHolder,
       save: null as MenuItemHolder,
       saveAs: null as MenuItemHolder,
       exportToFolder: null as MenuItemHolder,
+      exportCueSheet: null as MenuItemHolder,
+      exportJournal: null as MenuItemHolder,
+      exportBundle: null as MenuItemHolder,
       disconnect: null as MenuItemHolder,
     },
     edit: {
+      undo: null as MenuItemHolder,
+      redo: null as MenuItemHolder,
       cut: null as MenuItemHolder,
       copy: null as MenuItemHolder,
       paste: null as MenuItemHolder,
@@ -52,6 +69,7 @@ export const createWindowMenu = () => {
     },
     tools: {
       renumber: null as MenuItemHolder,
+      preflight: null as MenuItemHolder,
     },
     help: {
       credits: null as MenuItemHolder,
@@ -93,8 +111,23 @@ export const createWindowMenu = () => {
     updateEditMenuItemStats();
   };
 
+  let canUndo = false;
+  let canRedo = false;
+  const updateHistoryStatus = (newCanUndo: boolean, newCanRedo: boolean) => {
+    canUndo = newCanUndo;
+    canRedo = newCanRedo;
+    updateHistoryMenuItemStats();
+  };
+
+  const updateHistoryMenuItemStats = () => {
+    const enabled = connected && mode === 'edit';
+    (items.edit.undo as MenuItem | null)?.setEnabled(enabled && canUndo);
+    (items.edit.redo as MenuItem | null)?.setEnabled(enabled && canRedo);
+  };
+
   let lastEditEnableStats = connected && mode === 'edit';
   const updateEditMenuItemStats = () => {
+    updateHistoryMenuItemStats();
     const enabled = connected && mode === 'edit';
     if (lastEditEnableStats === enabled) return;
     lastEditEnableStats = enabled;
@@ -177,6 +210,33 @@ export const createWindowMenu = () => {
       },
     });
 
+    items.file.openBundle = await MenuItem.new({
+      id: 'id_open_bundle',
+      text: t('menu.file.openBundle'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.openBundle();
+      },
+    });
+
+    items.file.import = await MenuItem.new({
+      id: 'id_import',
+      text: t('menu.file.import'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.fileImport();
+      },
+    });
+
+    items.file.relinkAssets = await MenuItem.new({
+      id: 'id_relink_assets',
+      text: t('menu.file.relinkAssets'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.relinkAssets();
+      },
+    });
+
     items.file.save = await MenuItem.new({
       id: 'id_save',
       text: t('menu.file.save'),
@@ -206,18 +266,81 @@ export const createWindowMenu = () => {
       },
     });
 
+    items.file.exportBundle = await MenuItem.new({
+      id: 'id_export_bundle',
+      text: t('menu.file.exportBundle'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.exportBundle();
+      },
+    });
+
+    items.file.exportCueSheet = await MenuItem.new({
+      id: 'id_export_cue_sheet',
+      text: t('menu.file.exportCueSheet'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.exportCueSheet();
+      },
+    });
+
+    items.file.exportJournal = await MenuItem.new({
+      id: 'id_export_journal',
+      text: t('menu.file.exportJournal'),
+      enabled: __IS_HOST__,
+      action: () => {
+        api.host?.exportJournal();
+      },
+    });
+
     submenues.file = await Submenu.new({
       text: t('menu.file.title'),
       items: [
         items.file.new,
         items.file.open,
+        items.file.openBundle,
+        items.file.import,
+        items.file.relinkAssets,
         items.file.save,
         items.file.saveAs,
         items.file.exportToFolder,
+        items.file.exportBundle,
+        items.file.exportCueSheet,
+        items.file.exportJournal,
         ...remoteFileMenuItem,
       ],
     });
 
+    items.edit.undo = await MenuItem.new({
+      id: 'id_undo',
+      text: t('menu.edit.undo'),
+      enabled: lastEditEnableStats && canUndo,
+      accelerator: isMacOs ? '⌘ + Z' : 'Ctrl + Z',
+      action: () => {
+        // keep native text editing history for focused inputs
+        if (isTextInputFocused()) {
+          document.execCommand('undo');
+        } else {
+          api.undo();
+        }
+      },
+    });
+
+    items.edit.redo = await MenuItem.new({
+      id: 'id_redo',
+      text: t('menu.edit.redo'),
+      enabled: lastEditEnableStats && canRedo,
+      accelerator: isMacOs ? '⌘ + Shift + Z' : 'Ctrl + Y',
+      action: () => {
+        // keep native text editing history for focused inputs
+        if (isTextInputFocused()) {
+          document.execCommand('redo');
+        } else {
+          api.redo();
+        }
+      },
+    });
+
     items.edit.cut = await PredefinedMenuItem.new({
       item: 'Cut',
       text: t('menu.edit.cut'),
@@ -281,6 +404,11 @@ export const createWindowMenu = () => {
     submenues.edit = await Submenu.new({
       text: t('menu.edit.title'),
       items: [
+        items.edit.undo,
+        items.edit.redo,
+        await PredefinedMenuItem.new({
+          item: 'Separator',
+        }),
         items.edit.cut,
         items.edit.copy,
         items.edit.paste,
@@ -399,9 +527,17 @@ export const createWindowMenu = () => {
       },
     });
 
+    items.tools.preflight = await MenuItem.new({
+      id: 'id_preflight',
+      text: t('menu.tools.preflight'),
+      action: () => {
+        api.runPreflight().catch((e) => console.error(e));
+      },
+    });
+
     submenues.tools = await Submenu.new({
       text: t('menu.tools.title'),
-      items: [items.tools.renumber],
+      items: [items.tools.renumber, items.tools.preflight],
     });
 
     let mainHelpMenu: (MenuItem | PredefinedMenuItem)[] = [];
@@ -456,5 +592,5 @@ export const createWindowMenu = () => {
     menu.setAsAppMenu();
   };
 
-  return { init, updateLocale, updateConnectionStatus, updateEditMode };
+  return { init, updateLocale, updateConnectionStatus, updateEditMode, updateHistoryStatus };
 };
@@ -4,7 +4,11 @@
 use std::path::PathBuf;
 
 use super::AppState;
-use sbsp_backend::{FullShowState, event::BackendEvent};
+use sbsp_backend::{
+    FullShowState,
+    event::BackendEvent,
+    journal::{JournalEntry, JournalQuery},
+};
 use tauri::{Manager, ipc::Channel, path::BaseDirectory};
 
 pub mod client;
@@ -46,6 +50,22 @@ pub async fn get_full_state(state: tauri::State<'_, AppState>) -> Result<FullSho
     }
 }
 
+#[tauri::command]
+pub async fn query_journal(
+    state: tauri::State<'_, AppState>,
+    query: JournalQuery,
+) -> Result<Vec<JournalEntry>, String> {
+    if let Some(handle) = state.get_handle().await {
+        handle
+            .journal_handle
+            .query(query)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
 #[tauri::command]
 pub async fn get_third_party_notices(app_handle: tauri::AppHandle) -> Result<String, String> {
     let resource_path = app_handle
@@ -70,6 +90,16 @@ pub async fn process_asset(state: tauri::State<'_, AppState>, path: PathBuf) ->
     }
 }
 
+#[tauri::command]
+pub async fn run_preflight(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle.asset_processor_handle.request_preflight().await;
+        Ok(())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
 #[tauri::command]
 pub async fn listen_level_meter(
     _state: tauri::State<'_, AppState>,
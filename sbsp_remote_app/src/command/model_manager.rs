@@ -8,6 +8,7 @@ use sbsp_backend::{
     manager::InsertPosition,
     model::{
         ShowModel,
+        cart::CartSlot,
         cue::{Cue, Uuid},
         settings::ShowSettings,
     },
@@ -41,6 +42,7 @@ pub async fn add_cue(
     cue: Cue,
     target_id: Option<Uuid>,
     to_before: bool,
+    list_id: Option<Uuid>,
 ) -> Result<(), String> {
     if let Some(handle) = state.get_handle().await {
         if let Some(target) = target_id {
@@ -60,7 +62,13 @@ pub async fn add_cue(
         } else {
             handle
                 .model_handle
-                .add_cue(cue, InsertPosition::LAST)
+                .add_cue(
+                    cue,
+                    list_id.map_or(InsertPosition::LAST, |list_id| InsertPosition::List {
+                        list_id,
+                        index: None,
+                    }),
+                )
                 .await
                 .map_err(|e| e.to_string())
         }
@@ -75,6 +83,7 @@ pub async fn add_cues(
     cues: Vec<Cue>,
     target_id: Option<Uuid>,
     to_before: bool,
+    list_id: Option<Uuid>,
 ) -> Result<(), String> {
     if let Some(handle) = state.get_handle().await {
         if let Some(target) = target_id {
@@ -94,7 +103,13 @@ pub async fn add_cues(
         } else {
             handle
                 .model_handle
-                .add_cues(cues, InsertPosition::LAST)
+                .add_cues(
+                    cues,
+                    list_id.map_or(InsertPosition::LAST, |list_id| InsertPosition::List {
+                        list_id,
+                        index: None,
+                    }),
+                )
                 .await
                 .map_err(|e| e.to_string())
         }
@@ -103,6 +118,69 @@ pub async fn add_cues(
     }
 }
 
+#[tauri::command]
+pub async fn add_cue_list(state: tauri::State<'_, AppState>, name: String) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle
+            .model_handle
+            .add_cue_list(name)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
+#[tauri::command]
+pub async fn rename_cue_list(
+    state: tauri::State<'_, AppState>,
+    list_id: Uuid,
+    name: String,
+) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle
+            .model_handle
+            .rename_cue_list(list_id, name)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
+#[tauri::command]
+pub async fn remove_cue_list(
+    state: tauri::State<'_, AppState>,
+    list_id: Uuid,
+) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle
+            .model_handle
+            .remove_cue_list(list_id)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
+#[tauri::command]
+pub async fn set_cart_slot(
+    state: tauri::State<'_, AppState>,
+    index: usize,
+    slot: Option<CartSlot>,
+) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle
+            .model_handle
+            .set_cart_slot(index, slot)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
 #[tauri::command]
 pub async fn remove_cue(state: tauri::State<'_, AppState>, cue_id: Uuid) -> Result<(), String> {
     if let Some(handle) = state.get_handle().await {
@@ -186,6 +264,24 @@ pub async fn renumber_cues(
     }
 }
 
+#[tauri::command]
+pub async fn undo(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle.model_handle.undo().await.map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
+#[tauri::command]
+pub async fn redo(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    if let Some(handle) = state.get_handle().await {
+        handle.model_handle.redo().await.map_err(|e| e.to_string())
+    } else {
+        Err("Not connected.".into())
+    }
+}
+
 #[tauri::command]
 pub async fn update_model_name(
     state: tauri::State<'_, AppState>,
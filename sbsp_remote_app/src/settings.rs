@@ -34,6 +34,8 @@ pub struct GeneralSettings {
     pub lock_cursor_to_selection: bool,
     pub copy_assets_when_add: bool,
     pub seek_amount: f64,
+    pub backup_count: usize,
+    pub autosave_interval: u32,
 }
 
 impl Default for GeneralSettings {
@@ -43,6 +45,8 @@ impl Default for GeneralSettings {
             lock_cursor_to_selection: true,
             copy_assets_when_add: false,
             seek_amount: 5.0,
+            backup_count: 5,
+            autosave_interval: 60,
         }
     }
 }
@@ -86,7 +90,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Audio(AudioCueParam {
@@ -100,6 +106,7 @@ impl Default for TemplateSettings {
                     repeat: false,
                     sound_type: SoundType::Streaming,
                     envelope: Vec::new(),
+                    original_path: None,
                 }),
             },
             wait: Cue {
@@ -108,7 +115,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Wait(WaitCueParam { duration: 5.0 }),
@@ -119,7 +128,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Fade(FadeCueParam {
@@ -137,7 +148,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Start(StartCueParam {
@@ -150,7 +163,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Stop(StopCueParam {
@@ -164,7 +179,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Pause(PauseCueParam {
@@ -177,7 +194,9 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Load(LoadCueParam {
@@ -190,12 +209,15 @@ impl Default for TemplateSettings {
                 name: None,
                 notes: "".to_string(),
                 color: CueColor::None,
+                armed: true,
                 pre_wait: 0.0,
+                timeline_offset: 0.0,
                 chain: CueChain::DoNotChain,
                 parent_id: None,
                 params: CueParam::Group {
                     base: GroupCueParamBase {
                         mode: GroupMode::Playlist { repeat: true },
+                        volume: Decibels::IDENTITY,
                     },
                     children: Vec::new(),
                 },
@@ -21,8 +21,14 @@ pub struct PlaybackHotkey {
     pub resume_all: Option<String>,
     pub stop: Option<String>,
     pub stop_all: Option<String>,
+    pub panic: Option<String>,
+    pub cursor_next: Option<String>,
+    pub cursor_previous: Option<String>,
+    pub cursor_to_first: Option<String>,
     pub seek_forward: Option<String>,
     pub seek_backward: Option<String>,
+    /// Keys firing cart slots, by slot index.
+    pub cart: Vec<Option<String>>,
 }
 
 impl Default for PlaybackHotkey {
@@ -35,8 +41,13 @@ impl Default for PlaybackHotkey {
             resume_all: Some("]".to_string()),
             stop: Some("Backspace".to_string()),
             stop_all: Some("Escape".to_string()),
+            panic: None,
+            cursor_next: None,
+            cursor_previous: None,
+            cursor_to_first: None,
             seek_forward: Some("ArrowRight".to_string()),
             seek_backward: Some("ArrowLeft".to_string()),
+            cart: Vec::new(),
         }
     }
 }